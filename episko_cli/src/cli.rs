@@ -7,6 +7,9 @@
 
 use camino::Utf8PathBuf;
 use clap::{ArgAction, Args, Parser, Subcommand};
+use episko_lib::{
+    export::ExportFormat, files::check::ReportFormat, import::ImportFormat, statistics::Bucket,
+};
 
 pub mod prompts;
 
@@ -15,20 +18,89 @@ pub mod prompts;
 #[command(version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// Overrides of the config, taking precedence over the environment.
+#[derive(Args, Clone, Default)]
+pub struct GlobalArgs {
+    /// Config file to use instead of the one set by `EPISKO_CONFIG` or the default one
+    #[arg(long, global = true)]
+    pub config: Option<Utf8PathBuf>,
+    /// Profile to use instead of the one set by `EPISKO_PROFILE` or the default one
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    /// Database to use instead of the one set by `EPISKO_DB` or the one of the profile
+    #[arg(long, global = true)]
+    pub db: Option<Utf8PathBuf>,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Create a new manifest file
-    Create(CreateArgs),
+    Create(Box<CreateArgs>),
     /// Remove a given file from the filesystem and the program
     Remove { file: Utf8PathBuf },
     /// Cache an unknown manifest file for future use
     Cache { file: Utf8PathBuf },
-    /// Validate a manually changed or potentially corrupted file
-    Validate { file: Utf8PathBuf },
+    /// Validate manually changed or potentially corrupted manifests
+    Validate(ValidateArgs),
+    /// Print the JSON Schema of the manifest format
+    Schema {
+        /// File to write the schema to, printed to stdout if omitted
+        #[arg(short, long)]
+        output: Option<Utf8PathBuf>,
+    },
+    /// Show the links of a project or open one of them
+    Links {
+        /// Manifest or directory of the project
+        file: Utf8PathBuf,
+        /// Open the first link of the given kind or with the given label
+        #[arg(short, long)]
+        open: Option<String>,
+    },
+    /// List all cached projects
+    List {
+        /// Display sub-projects nested below their parent project
+        #[arg(short, long, action=ArgAction::SetTrue)]
+        tree: bool,
+    },
+    /// Export all cached projects
+    Export(ExportArgs),
+    /// Import projects from the file of another project manager
+    Import(ImportArgs),
+    /// Generate a static HTML site of all cached projects
+    Site {
+        /// Directory to write the site to
+        #[arg(short, long)]
+        out: Utf8PathBuf,
+    },
+    /// Show statistics about all cached projects
+    Stats(StatsArgs),
+    /// Rename or merge categories across all cached projects
+    Category(PropertyArgs),
+    /// Rename or merge languages across all cached projects
+    Language(PropertyArgs),
+    /// Rename or merge build systems across all cached projects
+    BuildSystem(PropertyArgs),
+    /// Rename or merge IDEs across all cached projects
+    Ide(PropertyArgs),
+    /// Rename or merge licenses across all cached projects
+    License(PropertyArgs),
+    /// Rename or merge frameworks across all cached projects
+    Framework(PropertyArgs),
+    /// Rename or merge people across all cached projects
+    Person(PropertyArgs),
+    /// Replace aliases of properties with their canonical names in manifests
+    Normalise {
+        /// Manifests to rewrite, all saved manifests if omitted
+        files: Vec<Utf8PathBuf>,
+    },
+    /// Show, change or validate the config
+    Config(ConfigArgs),
 }
 
 #[derive(Args, Clone, Default)]
@@ -54,16 +126,191 @@ pub struct CreateArgs {
     /// Build systems of the project (space seperated), syntax: <NAME>:<VERSION> ...
     #[arg(short, long, value_parser, num_args = 1..)]
     pub build_systems: Vec<String>,
+    /// Licenses of the project as SPDX expressions (space seperated), syntax: <LICENSE> ...
+    #[arg(short = 'L', long, value_parser, num_args = 1..)]
+    pub licenses: Vec<String>,
+    /// Frameworks of the project (space seperated), syntax: <NAME>:<VERSION> ...
+    #[arg(short, long, value_parser, num_args = 1..)]
+    pub frameworks: Vec<String>,
+    /// People involved in the project (space seperated), syntax: <NAME>:<EMAIL>:<ROLE> ...
+    #[arg(short = 'P', long, value_parser, num_args = 1..)]
+    pub people: Vec<String>,
     /// Description of the project
     #[arg(short = 'D', long)]
     pub description: Option<String>,
     /// Repository URL of the project
     #[arg(short, long)]
     pub repository_url: Option<String>,
+    /// Further links of the project (space seperated), syntax: <KIND>:<LABEL>=<URL> ...
+    ///
+    /// Kinds are `repository`, `documentation`, `ci`, `issue_tracker`, `deployment`, `homepage` and `other`
+    #[arg(short = 'k', long, value_parser, num_args = 1..)]
+    pub links: Vec<String>,
+}
+
+#[derive(Args, Clone)]
+pub struct ValidateArgs {
+    /// Manifests, directories or globs to validate
+    #[arg(required = true, num_args = 1..)]
+    pub paths: Vec<String>,
+    /// Only check the manifests, without caching them or changing the config.
+    /// Exits with a non-zero code if any manifest is invalid
+    #[arg(short, long, action=ArgAction::SetTrue)]
+    pub check: bool,
+    /// Format of the report when checking: text, json or junit
+    #[arg(short, long, default_value_t, requires = "check")]
+    pub format: ReportFormat,
+    /// File to write the report to when checking, printed to stdout if omitted
+    #[arg(short, long, requires = "check")]
+    pub output: Option<Utf8PathBuf>,
+}
+
+#[derive(Args, Clone)]
+pub struct ExportArgs {
+    /// Format of the export: json, csv or markdown
+    #[arg(short, long)]
+    pub format: ExportFormat,
+    /// Only export matching projects, syntax: `<query|category|language|build_system|license|framework|person|updated_since>=<VALUE>` ...
+    ///
+    /// Languages, build systems and frameworks accept a version constraint, e.g. `language=Rust:>=1.80`
+    #[arg(short = 'F', long, value_parser, num_args = 1..)]
+    pub filter: Vec<String>,
+    /// File to write the export to, printed to stdout if omitted
+    #[arg(short, long)]
+    pub output: Option<Utf8PathBuf>,
+}
+
+#[derive(Args, Clone)]
+pub struct ImportArgs {
+    /// File listing the projects to import
+    pub file: Utf8PathBuf,
+    /// Format of the file: vscode, csv or directories
+    #[arg(short, long)]
+    pub format: ImportFormat,
+    /// Detect languages, build systems and the repository of each project
+    #[arg(short, long, action=ArgAction::SetTrue)]
+    pub detect: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct StatsArgs {
+    /// Period to group projects by over time: day, week, month or year
+    #[arg(short, long, default_value_t = Bucket::Month)]
+    pub bucket: Bucket,
+    /// Amount of days after which a project without updates is stale
+    #[arg(short, long, default_value_t = 180)]
+    pub stale_days: u32,
+    /// Only include matching projects, syntax: `<query|category|language|build_system|license|framework|person|updated_since>=<VALUE>` ...
+    ///
+    /// Languages, build systems and frameworks accept a version constraint, e.g. `language=Rust:>=1.80`
+    #[arg(short = 'F', long, value_parser, num_args = 1..)]
+    pub filter: Vec<String>,
+    /// Only show the most frequent entries of each chart, in their usual order
+    #[arg(short = 'n', long)]
+    pub top: Option<usize>,
+    /// Print the statistics as JSON
+    #[arg(short, long, action=ArgAction::SetTrue)]
+    pub json: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct PropertyArgs {
+    #[command(subcommand)]
+    pub action: PropertyAction,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum PropertyAction {
+    /// Rename a property in all manifests and the cache
+    Rename {
+        /// Current name of the property
+        from: String,
+        /// New name of the property
+        to: String,
+        /// Only show the affected projects without changing anything
+        #[arg(short = 'n', long, action=ArgAction::SetTrue)]
+        dry_run: bool,
+    },
+    /// Merge several properties into one in all manifests and the cache
+    Merge {
+        /// Names of the properties to merge
+        #[arg(required = true, num_args = 1..)]
+        from: Vec<String>,
+        /// Name of the merged property
+        #[arg(short, long)]
+        into: String,
+        /// Only show the affected projects without changing anything
+        #[arg(short = 'n', long, action=ArgAction::SetTrue)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Args, Clone)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum ConfigAction {
+    /// Show the path and the effective values of the config
+    Show,
+    /// Add a directory whose manifests are loaded automatically
+    AddDir { dir: Utf8PathBuf },
+    /// Remove a saved directory
+    RemoveDir { dir: Utf8PathBuf },
+    /// Add a manifest which is loaded automatically
+    AddFile { file: Utf8PathBuf },
+    /// Remove a saved manifest
+    RemoveFile { file: Utf8PathBuf },
+    /// Set the path of the cache database
+    SetDb {
+        path: Utf8PathBuf,
+        /// Move the existing database to the new path
+        #[arg(short, long, action=ArgAction::SetTrue)]
+        migrate: bool,
+    },
+    /// Check the config for invalid values and missing paths
+    Validate,
+    /// List all profiles, marking the one in use
+    Profiles,
+    /// Add a profile with its own database and saved paths
+    AddProfile {
+        name: String,
+        /// Path of the database, placed next to the default one if omitted
+        #[arg(short, long)]
+        db: Option<Utf8PathBuf>,
+    },
+    /// Remove a profile, keeping its database
+    RemoveProfile { name: String },
+    /// Set the profile used when none is selected
+    DefaultProfile { name: String },
 }
 
 #[cfg(test)]
 pub mod tests {
+    use episko_lib::config::{
+        config_handler::Overrides, ConfigHandler, CONFIG_FILE_NAME, DB_FILE_NAME, DEFAULT_PROFILE,
+    };
+
+    /// Load a [`ConfigHandler`] using a new config file and database within
+    /// the temporary directory, leaving the config and cache of the user
+    /// untouched.
+    ///
+    /// # Panics
+    /// When the config can't be created.
+    #[must_use]
+    pub fn config_handler() -> ConfigHandler {
+        let dir = std::env::temp_dir().join(format!("episko-{}", uuid::Uuid::new_v4()));
+        let overrides = Overrides {
+            profile: Some(DEFAULT_PROFILE.to_string()),
+            database: Some(dir.join(DB_FILE_NAME)),
+        };
+
+        ConfigHandler::load_with(&dir.join(CONFIG_FILE_NAME), &overrides)
+            .expect("load config for test")
+    }
+
     /// Skip a test if stdout is used.
     /// See test documentation in [`cli::prompts`] for more information
     ///
@@ -11,7 +11,11 @@ use crate::ComplexArg;
 use camino::Utf8PathBuf;
 use color_eyre::Result;
 use dialoguer::{theme::ColorfulTheme, Input};
-use episko_lib::metadata::{BuildSystem, Category, Ide, Language};
+use episko_lib::metadata::{
+    builder::validation::{validate_email, validate_link, validate_name, validate_title},
+    property::PropertyKind,
+    BuildSystem, Category, Framework, Ide, Language, License, Link, LinkKind, Person,
+};
 
 /// Maximum number of input prompts for vec data
 const MAX_ROUNDS: i8 = 25;
@@ -29,13 +33,15 @@ pub fn directory_prompt(default: Option<Utf8PathBuf>) -> Result<Utf8PathBuf> {
 
 /// Specific prompt for the title
 ///
+/// Inputs are validated, an invalid input is prompted again.
+///
 /// # Errors
-/// - Propogates errors from [`text_prompt`]
+/// - Propogates errors from [`validated_prompt`]
 pub fn title_prompt(default: Option<String>) -> Result<String> {
     if let Some(title) = default {
         return Ok(title);
     }
-    text_prompt("Title", false, default)
+    validated_prompt("Title", false, validate_title)
 }
 
 /// Specific prompt for the description
@@ -52,13 +58,12 @@ pub fn description_prompt(default: Option<String>) -> Result<Option<String>> {
 /// Specific prompt for the categories
 ///
 /// # Errors
-/// - Propogates errors from [`text_prompt`]
+/// - Propogates errors from [`validated_prompt`]
 /// - [`color_eyre::Report`] when creating a [`Category`] fails.
 pub fn categories_prompt(defaults: &[String]) -> Result<Vec<Category>> {
     let mut categories = Vec::with_capacity(defaults.len());
-    let mut defaults = defaults.iter();
 
-    if defaults.len() > 0 {
+    if !defaults.is_empty() {
         for default in defaults {
             categories.push(Category::from_str(default)?);
         }
@@ -66,8 +71,9 @@ pub fn categories_prompt(defaults: &[String]) -> Result<Vec<Category>> {
     }
 
     for i in 1..MAX_ROUNDS {
-        let default = defaults.next().map(ToString::to_string);
-        let input: String = text_prompt(&format!("Category {i}"), true, default)?;
+        let input = validated_prompt(&format!("Category {i}"), true, |input| {
+            validate_name(PropertyKind::Category, input)
+        })?;
 
         if input.is_empty() {
             break;
@@ -94,6 +100,85 @@ pub fn build_systems_prompt(defaults: &[String]) -> Result<Vec<BuildSystem>> {
     looping_prompt_with_version("Build System", defaults)
 }
 
+/// Specific prompt for the frameworks
+///
+/// # Errors
+/// - Propogates errors from [`looping_prompt_with_version`]
+pub fn frameworks_prompt(defaults: &[String]) -> Result<Vec<Framework>> {
+    looping_prompt_with_version("Framework", defaults)
+}
+
+/// Specific prompt for the people involved, syntax: `<NAME>:<EMAIL>:<ROLE>`
+///
+/// Emails are validated, an invalid input is prompted again.
+///
+/// # Errors
+/// - Propogates errors from [`validated_prompt`]
+/// - [`color_eyre::Report`] when creating a [`Person`] fails.
+pub fn people_prompt(defaults: &[String]) -> Result<Vec<Person>> {
+    if !defaults.is_empty() {
+        return defaults
+            .iter()
+            .map(|el| Ok(Person::from_str(el)?))
+            .collect();
+    }
+
+    let mut people = vec![];
+    for i in 1..MAX_ROUNDS {
+        let input = validated_prompt(&format!("Person {i} (Name:Email:Role)"), true, |input| {
+            let person = Person::from_str(input).map_err(|err| err.to_string())?;
+            person
+                .email
+                .map_or(Ok(()), |email| validate_email(&email))
+                .map_err(|err| err.to_string())
+        })?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        people.push(Person::from_str(&input)?);
+    }
+    Ok(people)
+}
+
+/// Specific prompt for the licenses
+///
+/// Inputs are validated as SPDX expressions, an invalid input is prompted again.
+///
+/// # Errors
+/// - Propogates errors from [`text_prompt`]
+/// - [`color_eyre::Report`] when a default value isn't a valid [`License`].
+pub fn licenses_prompt(defaults: &[String]) -> Result<Vec<License>> {
+    if !defaults.is_empty() {
+        return defaults
+            .iter()
+            .map(|el| Ok(License::from_str(el)?))
+            .collect();
+    }
+
+    let mut licenses = vec![];
+    for i in 1..MAX_ROUNDS {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("License {i}"))
+            .allow_empty(true)
+            .validate_with(|input: &String| {
+                if input.is_empty() {
+                    return Ok(());
+                }
+                License::from_str(input).map(|_| ())
+            })
+            .interact_text()?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        licenses.push(License::from_str(&input)?);
+    }
+    Ok(licenses)
+}
+
 /// Specific prompt for the ide
 ///
 /// # Errors
@@ -109,13 +194,53 @@ pub fn ide_prompt(default: Option<String>) -> Result<Option<Ide>> {
 
 /// Specific prompt for the repository url
 ///
+/// Inputs are validated, an invalid input is prompted again.
+///
 /// # Errors
-/// - Propogates errors from [`optional_text_prompt`]
+/// - Propogates errors from [`validated_prompt`]
 pub fn repository_url_prompt(default: Option<String>) -> Result<Option<String>> {
     if let Some(url) = default {
         return Ok(Some(url));
     }
-    optional_text_prompt("Repository Url", default)
+    let url = validated_prompt("Repository Url", true, |input| {
+        validate_link(&Link::new(LinkKind::Repository, input))
+    })?;
+
+    Ok(Some(url).filter(|el| !el.is_empty()))
+}
+
+/// Specific prompt for further links, syntax: `<KIND>:<LABEL>=<URL>`
+///
+/// Inputs are validated, an invalid input is prompted again.
+///
+/// # Errors
+/// - Propogates errors from [`Input::interact_text`]
+/// - [`color_eyre::Report`] when a default value isn't a valid [`Link`].
+pub fn links_prompt(defaults: &[String]) -> Result<Vec<Link>> {
+    if !defaults.is_empty() {
+        return defaults.iter().map(|el| Ok(Link::from_str(el)?)).collect();
+    }
+
+    let mut links = vec![];
+    for i in 1..MAX_ROUNDS {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Link {i} (Kind:Label=Url)"))
+            .allow_empty(true)
+            .validate_with(|input: &String| {
+                if input.is_empty() {
+                    return Ok(());
+                }
+                Link::from_str(input).map(|_| ())
+            })
+            .interact_text()?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        links.push(Link::from_str(&input)?);
+    }
+    Ok(links)
 }
 
 /// Universal prompt for standard text
@@ -140,6 +265,31 @@ where
     Ok(input.interact_text()?)
 }
 
+/// Universal prompt for text checked by the given validation, an invalid
+/// input is prompted again. Empty inputs are not validated if allowed.
+///
+/// # Errors
+/// - [`color_eyre::Report`] when [`Input::interact_text`] fails
+fn validated_prompt<E>(
+    prompt: &str,
+    allow_empty: bool,
+    validate: impl Fn(&str) -> Result<(), E>,
+) -> Result<String>
+where
+    E: std::fmt::Display,
+{
+    Ok(Input::<String>::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .allow_empty(allow_empty)
+        .validate_with(|input: &String| {
+            if allow_empty && input.is_empty() {
+                return Ok(());
+            }
+            validate(input)
+        })
+        .interact_text()?)
+}
+
 /// Universal prompt for optional standard text
 ///
 /// # Errors
@@ -350,6 +500,47 @@ mod tests {
         looping_with_version(&bss, build_systems_prompt);
     }
 
+    #[test]
+    fn test_frameworks_with_default() {
+        let frameworks = vec![
+            ("Axum".to_string(), "0.8".to_string()),
+            ("Svelte".to_string(), String::new()),
+        ];
+
+        looping_with_version(&frameworks, frameworks_prompt);
+    }
+
+    #[test]
+    fn test_people_with_default() {
+        let people = vec!["Jane Doe:jane@example.com:maintainer".to_string()];
+
+        let result = people_prompt(&people).unwrap();
+
+        assert_eq!(result[0].name, "Jane Doe");
+        assert_eq!(result[0].role.as_deref(), Some("maintainer"));
+        assert!(people_prompt(&[":jane@example.com".to_string()]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "IO error: not a terminal")]
+    fn test_licenses_starts_prompt() {
+        skip_if_stdout();
+
+        let result = licenses_prompt(&[]);
+        result.unwrap();
+    }
+
+    #[test]
+    fn test_licenses_with_default() {
+        let licenses = vec!["MIT".to_string(), "mit OR Apache-2.0".to_string()];
+
+        let result = licenses_prompt(&licenses).unwrap();
+
+        assert_eq!(result.len(), licenses.len());
+        assert_eq!(result[0], License::new("MIT"));
+        assert!(licenses_prompt(&["Proprietary".to_string()]).is_err());
+    }
+
     #[test]
     #[should_panic(expected = "IO error: not a terminal")]
     fn test_ide_starts_prompt() {
@@ -395,6 +586,20 @@ mod tests {
         assert_eq!(result, url);
     }
 
+    #[test]
+    fn test_links_with_default() {
+        let links = vec![
+            "docs=https://docs.rs/episko".to_string(),
+            "ci:Pipelines=https://ci.episko.de".to_string(),
+        ];
+
+        let result = links_prompt(&links).unwrap();
+
+        assert_eq!(result.len(), links.len());
+        assert_eq!(result[1].title(), "Pipelines");
+        assert!(links_prompt(&["docs=episko.de".to_string()]).is_err());
+    }
+
     #[test]
     #[should_panic(expected = "IO error: not a terminal")]
     fn text_prompt_starts() {
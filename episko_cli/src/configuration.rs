@@ -0,0 +1,201 @@
+//! # Management of the config
+//!
+//! This module contains the code for showing, changing and validating the config and is directly used by the config command.
+
+use std::path::{self, PathBuf};
+
+use camino::Utf8PathBuf;
+use color_eyre::{eyre::eyre, Result};
+use episko_lib::config::{self, Config, ConfigHandler};
+
+use crate::cli::ConfigAction;
+
+/// Run the given action on the config, saving it if it has been changed.
+///
+/// # Errors
+/// - Error report when a path to add does not exist
+/// - Error report when a path to remove is not saved
+/// - Error report when migrating the database fails
+/// - Error report when the config is invalid, listing all problems
+/// - Error report when saving the config fails
+pub fn manage_config(action: ConfigAction, config_handler: &mut ConfigHandler) -> Result<()> {
+    if apply_action(action, config_handler)? {
+        config_handler.save_config()?;
+    }
+    Ok(())
+}
+
+/// Apply the action to the config, returning whether it has been changed.
+fn apply_action(action: ConfigAction, config_handler: &mut ConfigHandler) -> Result<bool> {
+    match action {
+        ConfigAction::Show => {
+            println!("# {}", config_handler.config_file().display());
+            println!("# profile: {}", config_handler.config().active_profile());
+            println!(
+                "# database: {}",
+                config_handler.config().database_path().display()
+            );
+            print!("{}", render_config(config_handler.config())?);
+            return Ok(false);
+        }
+        ConfigAction::Validate => {
+            config_handler
+                .config()
+                .validate()
+                .map_err(|err| eyre!("config is invalid:\n{}", render_problems(&err)))?;
+            println!("{} is valid", config_handler.config_file().display());
+            return Ok(false);
+        }
+        ConfigAction::Profiles => {
+            println!("{}", render_profiles(config_handler.config()));
+            return Ok(false);
+        }
+        ConfigAction::AddDir { dir } => {
+            if !dir.is_dir() {
+                return Err(eyre!("{dir} is not a directory"));
+            }
+            config_handler.add_saved_directory(&dir.canonicalize()?);
+        }
+        ConfigAction::RemoveDir { dir } => {
+            if !config_handler.remove_saved_directory(&saved_path(&dir)) {
+                return Err(eyre!("{dir} is not a saved directory"));
+            }
+        }
+        ConfigAction::AddFile { file } => {
+            if !file.is_file() {
+                return Err(eyre!("{file} is not a file"));
+            }
+            config_handler.add_saved_file(&file.canonicalize()?);
+        }
+        ConfigAction::RemoveFile { file } => {
+            if !config_handler.remove_saved_file(&saved_path(&file)) {
+                return Err(eyre!("{file} is not a saved file"));
+            }
+        }
+        ConfigAction::SetDb { path, migrate } => {
+            config_handler.set_database_path(&path::absolute(&path)?, migrate)?;
+        }
+        ConfigAction::AddProfile { name, db } => {
+            let db = db.map(path::absolute).transpose()?;
+            config_handler.add_profile(&name, db.as_deref())?;
+        }
+        ConfigAction::RemoveProfile { name } => {
+            if !config_handler.remove_profile(&name) {
+                return Err(eyre!("{name} is not a removable profile"));
+            }
+        }
+        ConfigAction::DefaultProfile { name } => {
+            config_handler.set_default_profile(&name)?;
+        }
+    }
+    Ok(true)
+}
+
+/// Render the config as it would be saved, including default values.
+///
+/// # Errors
+/// - Error report when the config can't be serialized
+pub fn render_config(config: &Config) -> Result<String> {
+    Ok(toml::to_string_pretty(config)?)
+}
+
+/// Render the names of all profiles along with their database,
+/// marking the active profile with `*`.
+#[must_use]
+pub fn render_profiles(config: &Config) -> String {
+    config
+        .profile_names()
+        .into_iter()
+        .map(|name| {
+            let marker = if name == config.active_profile() {
+                '*'
+            } else {
+                ' '
+            };
+            let profile = config.profiles.get(name).unwrap_or(&config.base);
+            format!("{marker} {name} ({})", profile.database_path.display())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render every problem of an invalid config on its own line.
+#[must_use]
+pub fn render_problems(err: &config::Error) -> String {
+    match err {
+        config::Error::Invalid(problems) => problems
+            .iter()
+            .map(|problem| format!("  - {problem}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        err => format!("  - {err}"),
+    }
+}
+
+/// Saved paths are canonical, paths which no longer exist are used as given.
+fn saved_path(path: &Utf8PathBuf) -> PathBuf {
+    path.canonicalize()
+        .unwrap_or_else(|_| path.as_std_path().to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::cli::tests::config_handler;
+
+    #[test]
+    fn test_add_and_remove_paths() {
+        let dir = std::env::temp_dir().join(format!("episko-config-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let dir = Utf8PathBuf::try_from(dir.canonicalize().unwrap()).unwrap();
+        let mut ch = config_handler();
+
+        let added = apply_action(ConfigAction::AddDir { dir: dir.clone() }, &mut ch).unwrap();
+        assert!(added);
+        assert!(ch.dirs().contains(dir.as_std_path()));
+
+        apply_action(ConfigAction::RemoveDir { dir: dir.clone() }, &mut ch).unwrap();
+        assert!(!ch.dirs().contains(dir.as_std_path()));
+        assert!(apply_action(ConfigAction::RemoveDir { dir: dir.clone() }, &mut ch).is_err());
+
+        let file = dir.join("missing.toml");
+        assert!(apply_action(ConfigAction::AddFile { file }, &mut ch).is_err());
+    }
+
+    #[test]
+    fn test_profiles() {
+        let mut ch = config_handler();
+        let db = std::env::temp_dir().join("episko-work.db");
+        let action = ConfigAction::AddProfile {
+            name: "work".to_string(),
+            db: Some(Utf8PathBuf::try_from(db.clone()).unwrap()),
+        };
+
+        apply_action(action, &mut ch).unwrap();
+        ch.select_profile("work").unwrap();
+
+        let rendered = render_profiles(ch.config());
+        assert!(rendered.contains(&format!("* work ({})", db.display())));
+        assert!(rendered.starts_with("  default ("));
+
+        let action = ConfigAction::RemoveProfile {
+            name: "default".to_string(),
+        };
+        assert!(apply_action(action, &mut ch).is_err());
+    }
+
+    #[test]
+    fn test_render_problems() {
+        let err = config::Error::Invalid(vec![
+            "no manifest names configured".to_string(),
+            "invalid glob pattern: [".to_string(),
+        ]);
+
+        assert_eq!(
+            render_problems(&err),
+            "  - no manifest names configured\n  - invalid glob pattern: ["
+        );
+    }
+}
@@ -8,8 +8,9 @@ use crate::connect_to_db;
 
 use super::cli::{
     prompts::{
-        build_systems_prompt, categories_prompt, description_prompt, directory_prompt, ide_prompt,
-        languages_prompt, repository_url_prompt, title_prompt,
+        build_systems_prompt, categories_prompt, description_prompt, directory_prompt,
+        frameworks_prompt, ide_prompt, languages_prompt, licenses_prompt, links_prompt,
+        people_prompt, repository_url_prompt, title_prompt,
     },
     CreateArgs,
 };
@@ -19,8 +20,8 @@ use color_eyre::Result;
 use episko_lib::{
     config::ConfigHandler,
     metadata::{
-        builder::ApplyIf, metadata_handler::MetadataHandler, BuildSystem, Category, Ide, Language,
-        Metadata, MetadataBuilder,
+        builder::ApplyIf, metadata_handler::MetadataHandler, BuildSystem, Category, Framework, Ide,
+        Language, License, Link, Metadata, MetadataBuilder, Person,
     },
 };
 
@@ -45,7 +46,11 @@ pub async fn create_manifest(
         run_interactive_creation(args, builder)?
     };
 
-    let metadata = builder.build()?;
+    let config = config_handler.config();
+    let metadata = builder
+        .manifest_name(config.discovery.manifest_name())
+        .aliases(&config.aliases)
+        .build()?;
 
     let db = connect_to_db(config_handler.config()).await?;
     MetadataHandler::save_metadata(&metadata, &db, config_handler).await?;
@@ -63,8 +68,12 @@ pub async fn create_manifest(
 ///     - [`categories_prompt`]
 ///     - [`languages_prompt`]
 ///     - [`build_systems_prompt`]
+///     - [`frameworks_prompt`]
+///     - [`licenses_prompt`]
+///     - [`people_prompt`]
 ///     - [`ide_prompt`]
 ///     - [`repository_url_prompt`]
+///     - [`links_prompt`]
 fn run_interactive_creation(args: CreateArgs, builder: MetadataBuilder) -> Result<MetadataBuilder> {
     println!("Flag passed values will not be prompted!");
     let directory = directory_prompt(args.directory)?;
@@ -73,8 +82,12 @@ fn run_interactive_creation(args: CreateArgs, builder: MetadataBuilder) -> Resul
     let categories = categories_prompt(&args.categories)?;
     let languages = languages_prompt(&args.languages)?;
     let build_systems = build_systems_prompt(&args.build_systems)?;
+    let frameworks = frameworks_prompt(&args.frameworks)?;
+    let licenses = licenses_prompt(&args.licenses)?;
+    let people = people_prompt(&args.people)?;
     let preferred_ide = ide_prompt(args.preferred_ide)?;
     let repository_url = repository_url_prompt(args.repository_url)?;
+    let links = links_prompt(&args.links)?;
 
     Ok(builder
         .directory_path(directory.as_std_path())
@@ -82,6 +95,10 @@ fn run_interactive_creation(args: CreateArgs, builder: MetadataBuilder) -> Resul
         .categories(categories)
         .languages(languages)
         .build_systems(build_systems)
+        .frameworks(frameworks)
+        .licenses(licenses)
+        .people(people)
+        .links(links)
         .apply_if(description.as_deref(), MetadataBuilder::description)
         .apply_if(preferred_ide, MetadataBuilder::preferred_ide)
         .apply_if(repository_url.as_deref(), MetadataBuilder::repository_url))
@@ -91,8 +108,10 @@ fn run_interactive_creation(args: CreateArgs, builder: MetadataBuilder) -> Resul
 ///
 /// # Errors
 /// - [`color_eyre::Report`] when [`Ide::from_str`] fails
+/// - [`color_eyre::Report`] when [`License::from_str`], [`Person::from_str`]
+///   or [`Link::from_str`] fails
 /// - [`color_eyre::Report`] when [`ComplexArg::parse_tuple`] fails
-///     - This is called for [`Language`] and [`BuildSystem`]
+///     - This is called for [`Language`], [`BuildSystem`] and [`Framework`]
 fn run_non_interactive_creation(
     args: CreateArgs,
     builder: MetadataBuilder,
@@ -104,8 +123,15 @@ fn run_non_interactive_creation(
         .map(Ide::from_str)
         .transpose()?;
 
+    let links: Vec<Link> = args
+        .links
+        .iter()
+        .map(|el| Link::from_str(el))
+        .collect::<Result<_, _>>()?;
+
     // Assign non-vec properties
     let mut builder = builder
+        .links(links)
         .apply_if(
             args.directory.as_deref().map(Utf8Path::as_std_path),
             MetadataBuilder::directory_path,
@@ -145,11 +171,35 @@ fn run_non_interactive_creation(
         })
         .collect::<Result<_>>()?;
 
+    let frameworks: Vec<Framework> = args
+        .frameworks
+        .into_iter()
+        .map(|el| {
+            let tuple = el.parse_tuple()?;
+            Ok(tuple.try_into()?)
+        })
+        .collect::<Result<_>>()?;
+
+    let people: Vec<Person> = args
+        .people
+        .iter()
+        .map(|el| Person::from_str(el))
+        .collect::<Result<_, _>>()?;
+
+    let licenses: Vec<License> = args
+        .licenses
+        .iter()
+        .map(|el| License::from_str(el))
+        .collect::<Result<_, _>>()?;
+
     // Assign vec properties
     builder = builder
         .categories(categories)
         .languages(languages)
-        .build_systems(build_systems);
+        .build_systems(build_systems)
+        .frameworks(frameworks)
+        .licenses(licenses)
+        .people(people);
 
     Ok(builder)
 }
@@ -157,9 +207,11 @@ fn run_non_interactive_creation(
 #[cfg(test)]
 mod tests {
     use camino::Utf8PathBuf;
-    use episko_lib::config::ConfigHandler;
 
-    use crate::cli::{tests::skip_if_stdout, CreateArgs};
+    use crate::cli::{
+        tests::{config_handler, skip_if_stdout},
+        CreateArgs,
+    };
 
     use super::*;
 
@@ -216,7 +268,7 @@ mod tests {
     }
 
     async fn run_and_unwrap(args: CreateArgs) {
-        let mut ch = ConfigHandler::in_place();
+        let mut ch = config_handler();
 
         create_manifest(args, &mut ch).await.unwrap();
     }
@@ -230,7 +282,7 @@ mod tests {
             ..Default::default()
         };
 
-        let mut ch = ConfigHandler::in_place();
+        let mut ch = config_handler();
 
         create_manifest(args, &mut ch)
             .await
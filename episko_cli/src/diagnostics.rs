@@ -0,0 +1,119 @@
+//! # Rendering of errors
+//!
+//! This module contains the code to render errors of manifests which couldn't
+//! be parsed along with the offending source line, e.g.
+//! ```text
+//! invalid type: integer `1`, expected a string
+//!  --> manifest.toml:2:11
+//!   |
+//! 2 | version = 1
+//!   |           ^
+//! ```
+
+use color_eyre::{Report, Section};
+use episko_lib::files::parse_error::ParseError;
+
+/// Attach the rendered source of a parse error to the report, if the
+/// report was caused by one.
+pub fn with_diagnostics(report: Report) -> Report {
+    let Some(rendered) = find_parse_error(&report).map(render_parse_error) else {
+        return report;
+    };
+
+    report.section(rendered)
+}
+
+/// Find the parse error which caused the report, if any.
+#[must_use]
+pub fn find_parse_error(report: &Report) -> Option<&ParseError> {
+    report
+        .chain()
+        .find_map(|err| err.downcast_ref::<ParseError>())
+}
+
+/// Render the parse error with the offending source line, pointing to the
+/// offending value.
+#[must_use]
+pub fn render_parse_error(err: &ParseError) -> String {
+    let path = err.path.display();
+    let Some(location) = &err.location else {
+        return format!("{}\n --> {path}", err.message);
+    };
+
+    let line = location.line.to_string();
+    let padding = " ".repeat(line.len());
+    let marker = " ".repeat(location.column - 1);
+
+    // The span may reach beyond the line, e.g. for tables
+    let mut length = 0;
+    let mut bytes = 0;
+    for char in location.source_line.chars().skip(location.column - 1) {
+        if bytes >= location.span.len() {
+            break;
+        }
+        bytes += char.len_utf8();
+        length += 1;
+    }
+
+    format!(
+        "{message}\n{padding}--> {path}:{line}:{column}\n{padding} |\n{line} | {source}\n{padding} | {marker}{carets}",
+        message = err.message,
+        column = location.column,
+        source = location.source_line,
+        carets = "^".repeat(length.max(1)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use episko_lib::{files, files::parse_error::Location, metadata};
+
+    use super::*;
+
+    #[test]
+    fn test_render_with_location() {
+        let content = "title = \"Episko\"\nversion = 12\n";
+        let err = ParseError {
+            path: PathBuf::from("manifest.toml"),
+            message: "invalid type: integer `12`, expected a string".to_string(),
+            location: Some(Location::from_span(content, 27..29)),
+        };
+
+        assert_eq!(
+            render_parse_error(&err),
+            "invalid type: integer `12`, expected a string\n --> manifest.toml:2:11\n  |\n2 | version = 12\n  |           ^^"
+        );
+    }
+
+    #[test]
+    fn test_render_without_location() {
+        let err = ParseError {
+            path: PathBuf::from("Cargo.toml"),
+            message: "missing field `title`".to_string(),
+            location: None,
+        };
+
+        assert_eq!(
+            render_parse_error(&err),
+            "missing field `title`\n --> Cargo.toml"
+        );
+    }
+
+    #[test]
+    fn test_find_nested_parse_error() {
+        let err = ParseError {
+            path: PathBuf::from("manifest.toml"),
+            message: "expected `=`".to_string(),
+            location: Some(Location::from_span("title \"Episko\"", 6..7)),
+        };
+        let nested = metadata::Error::Manifest(Box::new(files::Error::Parse(err.clone())));
+
+        let report = Report::new(nested);
+        assert_eq!(find_parse_error(&report), Some(&err));
+
+        let report = Report::new(metadata::Error::EmptyName);
+        assert_eq!(find_parse_error(&report), None);
+    }
+}
@@ -0,0 +1,154 @@
+//! # Export of cached projects
+//!
+//! This module contains the code for exporting the cached projects and is directly used by the export command.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use color_eyre::{eyre::eyre, Result};
+use episko_lib::{
+    config::ConfigHandler, database::Filter, export::ExportHandler,
+    metadata::version::VersionConstraint,
+};
+
+use crate::{cli::ExportArgs, connect_to_db};
+
+/// Export all cached projects matching the given filters, either to
+/// the given file or to stdout.
+///
+/// # Errors
+/// - Error report when a filter is invalid
+/// - Error report when [`ExportHandler::export`] fails
+/// - Error report when the output file cannot be written
+pub async fn export_projects(args: ExportArgs, config_handler: &ConfigHandler) -> Result<()> {
+    let filter = parse_filter(&args.filter)?;
+    let db = connect_to_db(config_handler.config()).await?;
+
+    match args.output {
+        Some(output) => {
+            let amount =
+                ExportHandler::export_to_file(args.format, filter, output.as_std_path(), &db)
+                    .await?;
+            println!("Exported {amount} project(s) to {output}");
+        }
+        None => print!("{}", ExportHandler::export(args.format, filter, &db).await?),
+    }
+
+    Ok(())
+}
+
+/// Parse filters given as `<KEY>=<VALUE>` into a [`Filter`].
+///
+/// `updated_since` accepts either a date (`2025-01-01`) or a RFC 3339 timestamp.
+///
+/// `language`, `build_system` and `framework` accept an optional version constraint,
+/// syntax: `<NAME>:<CONSTRAINT>` (e.g. `Rust:>=1.80`), the name may be omitted.
+///
+/// # Errors
+/// - [`color_eyre::Report`] when a filter has no value or an unknown key
+/// - [`color_eyre::Report`] when `updated_since` isn't a valid date
+/// - [`color_eyre::Report`] when a version constraint is invalid
+pub fn parse_filter(filters: &[String]) -> Result<Filter> {
+    let mut filter = Filter::default();
+
+    for el in filters {
+        let (key, value) = el
+            .split_once('=')
+            .ok_or(eyre!("invalid filter '{el}', expected <KEY>=<VALUE>"))?;
+        match key {
+            "query" | "title" => filter.query = Some(value.to_string()),
+            "category" => filter.category = Some(value.to_string()),
+            "language" => {
+                (filter.language, filter.language_version) = parse_versioned(value)?;
+            }
+            "build_system" => {
+                (filter.build_system, filter.build_system_version) = parse_versioned(value)?;
+            }
+            "license" => filter.license = Some(value.to_string()),
+            "framework" => {
+                (filter.framework, filter.framework_version) = parse_versioned(value)?;
+            }
+            "person" | "maintainer" => filter.person = Some(value.to_string()),
+            "updated_since" => filter.updated_since = Some(parse_date(value)?),
+            _ => return Err(eyre!("unknown filter '{key}'")),
+        }
+    }
+
+    Ok(filter)
+}
+
+/// Split a filter value into its name and optional version constraint.
+fn parse_versioned(value: &str) -> Result<(Option<String>, Option<VersionConstraint>)> {
+    let Some((name, constraint)) = value.split_once(':') else {
+        return Ok((Some(value.to_string()), None));
+    };
+
+    let name = (!name.is_empty()).then(|| name.to_string());
+    Ok((name, Some(constraint.parse()?)))
+}
+
+fn parse_date(value: &str) -> Result<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|el| el.with_timezone(&Utc))
+        .map_err(|_| eyre!("invalid date '{value}', expected YYYY-MM-DD or RFC 3339"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = parse_filter(&[
+            "category=Web".to_string(),
+            "language=Rust".to_string(),
+            "license=MIT".to_string(),
+            "maintainer=Jane Doe".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(filter.query, None);
+        assert_eq!(filter.category.as_deref(), Some("Web"));
+        assert_eq!(filter.language.as_deref(), Some("Rust"));
+        assert_eq!(filter.license.as_deref(), Some("MIT"));
+        assert_eq!(filter.person.as_deref(), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_parse_invalid_filter() {
+        assert!(parse_filter(&["Web".to_string()]).is_err());
+        assert!(parse_filter(&["ide=Vim".to_string()]).is_err());
+        assert!(parse_filter(&["updated_since=yesterday".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_version_constraint() {
+        let filter = parse_filter(&[
+            "language=Rust:>=1.80".to_string(),
+            "build_system=:^3.10".to_string(),
+            "framework=Axum:0.8".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(filter.language.as_deref(), Some("Rust"));
+        assert!(filter.language_version.unwrap().matches("1.84"));
+        assert_eq!(filter.build_system, None);
+        assert!(!filter.build_system_version.unwrap().matches("4.0"));
+        assert_eq!(filter.framework.as_deref(), Some("Axum"));
+        assert!(!filter.framework_version.unwrap().matches("0.9"));
+
+        assert!(parse_filter(&["language=Rust:>=one".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_updated_since() {
+        let date = parse_filter(&["updated_since=2025-01-01".to_string()]).unwrap();
+        let timestamp =
+            parse_filter(&["updated_since=2025-01-01T00:00:00+00:00".to_string()]).unwrap();
+
+        assert!(date.updated_since.is_some());
+        assert_eq!(date.updated_since, timestamp.updated_since);
+    }
+}
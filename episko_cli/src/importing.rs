@@ -0,0 +1,83 @@
+//! # Import of projects
+//!
+//! This module contains the code for importing projects from other formats and is directly used by the import command.
+
+use color_eyre::Result;
+use episko_lib::{
+    config::ConfigHandler,
+    import::{ImportHandler, ImportReport},
+};
+
+use crate::{cli::ImportArgs, connect_to_db};
+
+/// Create and cache manifests for all projects listed in the given file.
+///
+/// # Errors
+/// - Error report when [`ImportHandler::import_file`] fails
+pub async fn import_projects(
+    args: ImportArgs,
+    config_handler: &mut ConfigHandler,
+) -> Result<ImportReport> {
+    let db = connect_to_db(config_handler.config()).await?;
+
+    let report = ImportHandler::import_file(
+        args.file.as_std_path(),
+        args.format,
+        args.detect,
+        &db,
+        config_handler,
+    )
+    .await?;
+
+    for path in &report.imported {
+        println!("Imported {}", path.display());
+    }
+    for (path, reason) in &report.skipped {
+        eprintln!("Skipped {}: {reason}", path.display());
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use camino::Utf8PathBuf;
+    use episko_lib::import::ImportFormat;
+
+    use super::*;
+    use crate::cli::tests::config_handler;
+
+    #[tokio::test]
+    async fn test_import_directory_list() {
+        let dir = std::env::temp_dir().join(format!("episko-import-{}", uuid::Uuid::new_v4()));
+        let project = dir.join("project");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(project.join("Cargo.toml"), "").unwrap();
+        let list = dir.join("projects.txt");
+        fs::write(
+            &list,
+            format!("{}\n{}\n", project.display(), dir.join("missing").display()),
+        )
+        .unwrap();
+
+        let args = ImportArgs {
+            file: Utf8PathBuf::from_path_buf(list).unwrap(),
+            format: ImportFormat::DirectoryList,
+            detect: true,
+        };
+        let mut ch = config_handler();
+
+        let report = import_projects(args, &mut ch).await.unwrap();
+
+        assert_eq!(report.imported.len(), 1);
+        assert_eq!(report.skipped.len(), 1);
+        let manifest = fs::read_to_string(project.join("manifest.toml")).unwrap();
+        assert!(manifest.contains("Cargo"));
+
+        ch.remove_saved_file(&report.imported[0]);
+        ch.save_config().unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
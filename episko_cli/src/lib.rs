@@ -6,14 +6,35 @@
 use color_eyre::{eyre::eyre, Result};
 
 pub mod cli;
+pub mod configuration;
 pub mod creation;
+pub mod diagnostics;
+pub mod export;
+pub mod importing;
+pub mod links;
+pub mod listing;
+pub mod normalisation;
 pub mod removal;
+pub mod renaming;
+pub mod site;
+pub mod statistics;
 pub mod validation;
 
+pub use configuration::manage_config;
 pub use creation::create_manifest;
 use episko_lib::{config::Config, database::DatabaseHandler};
+pub use export::export_projects;
+pub use importing::import_projects;
+pub use links::show_links;
+pub use listing::list_projects;
+pub use normalisation::normalise_manifests;
 pub use removal::remove_manifest;
-pub use validation::{cache_manifest, validate_manifest};
+pub use renaming::rename_property;
+pub use site::generate_site;
+pub use statistics::show_statistics;
+pub use validation::{
+    cache_manifest, check_manifests, print_schema, validate_manifest, validate_manifests,
+};
 
 pub trait ComplexArg {
     /// Parse a ":" seperated, two parted argument into
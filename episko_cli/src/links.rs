@@ -0,0 +1,141 @@
+//! # Links of a project
+//!
+//! This module contains the code for showing and opening the links of a project and is directly used by the links command.
+
+use std::fmt::Write as _;
+
+use camino::Utf8PathBuf;
+use color_eyre::{eyre::eyre, Result};
+use episko_lib::{
+    config::ConfigHandler,
+    metadata::{Link, LinkKind, Metadata},
+};
+
+/// Print the links of the project at the given path, or open one of them
+/// in the default application if a kind or label is given.
+///
+/// Only web links are opened, see [`Link::is_web`].
+///
+/// # Errors
+/// - Error report when the manifest can't be read
+/// - Error report when no link matches the given kind or label
+/// - Error report when the link isn't a web link
+/// - Error report when opening the link fails
+pub fn show_links(
+    file: &Utf8PathBuf,
+    open: Option<&str>,
+    config_handler: &ConfigHandler,
+) -> Result<()> {
+    let config = config_handler.config();
+    let metadata =
+        Metadata::from_file_with(file.as_std_path(), &config.discovery, &config.aliases)?;
+
+    let Some(query) = open else {
+        print!("{}", render_links(&metadata.links));
+        return Ok(());
+    };
+
+    let link = find_link(&metadata.links, query)
+        .ok_or_else(|| eyre!("no link matching '{query}' in {}", metadata.title))?;
+
+    if !link.is_web() {
+        return Err(eyre!(
+            "only http and https links can be opened: {}",
+            link.url
+        ));
+    }
+
+    println!("Opening {}", link.url);
+    open::that_detached(&link.url)?;
+    Ok(())
+}
+
+/// Find the first link with the given kind, or else with the given label.
+#[must_use]
+pub fn find_link<'a>(links: &'a [Link], query: &str) -> Option<&'a Link> {
+    let kind = query.parse::<LinkKind>().ok();
+
+    links
+        .iter()
+        .find(|link| Some(link.kind) == kind)
+        .or_else(|| {
+            links.iter().find(|link| {
+                link.label
+                    .as_deref()
+                    .is_some_and(|label| label.eq_ignore_ascii_case(query))
+            })
+        })
+}
+
+/// Render the links as aligned rows of title and url.
+#[must_use]
+pub fn render_links(links: &[Link]) -> String {
+    let width = links
+        .iter()
+        .map(|link| link.title().chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut output = String::new();
+    for link in links {
+        let _ = writeln!(output, "{:<width$}  {}", link.title(), link.url);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use episko_lib::files::File as _;
+
+    use super::*;
+    use crate::cli::tests::config_handler;
+
+    fn links() -> Vec<Link> {
+        vec![
+            Link::new(LinkKind::Repository, "https://github.com/episko/episko"),
+            Link::new(LinkKind::Documentation, "https://docs.rs/episko").with_label("API"),
+        ]
+    }
+
+    #[test]
+    fn test_find_link() {
+        let links = links();
+
+        assert_eq!(find_link(&links, "repo"), Some(&links[0]));
+        assert_eq!(find_link(&links, "docs"), Some(&links[1]));
+        assert_eq!(find_link(&links, "api"), Some(&links[1]));
+        assert_eq!(find_link(&links, "ci"), None);
+    }
+
+    #[test]
+    fn test_open_non_web_link() {
+        let dir = std::env::temp_dir().join(format!("episko-links-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let metadata = Metadata::builder()
+            .title("Links")
+            .directory_path(&dir)
+            .add_link(Link::new(
+                LinkKind::Repository,
+                "ssh://git@example.com/episko",
+            ))
+            .build()
+            .unwrap();
+        metadata.write_file(metadata.directory()).unwrap();
+
+        let path = Utf8PathBuf::from_path_buf(metadata.directory().to_path_buf()).unwrap();
+        let err = show_links(&path, Some("repo"), &config_handler()).unwrap_err();
+        assert!(err.to_string().contains("only http and https"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_links() {
+        assert_eq!(
+            render_links(&links()),
+            "Repository  https://github.com/episko/episko\n\
+             API         https://docs.rs/episko\n"
+        );
+        assert_eq!(render_links(&[]), "");
+    }
+}
@@ -0,0 +1,106 @@
+//! # Listing of cached projects
+//!
+//! This module contains the code for listing all cached projects and is directly used by the list command.
+
+use std::fmt::Write as _;
+
+use color_eyre::Result;
+use episko_lib::{
+    config::ConfigHandler,
+    database::Filter,
+    metadata::{Metadata, ProjectTree},
+};
+
+use crate::connect_to_db;
+
+/// Print all cached projects, optionally as a tree of projects
+/// and their sub-projects.
+///
+/// # Errors
+/// - Error report when the projects cannot be retrieved from the cache
+pub async fn list_projects(tree: bool, config_handler: &ConfigHandler) -> Result<()> {
+    let db = connect_to_db(config_handler.config()).await?;
+
+    let projects = Metadata::all_preview_from_db(None, Filter::default(), &db).await?;
+
+    if tree {
+        print!("{}", render_trees(&ProjectTree::build(projects)));
+    } else {
+        for project in projects {
+            println!("{}", project.title);
+        }
+    }
+
+    Ok(())
+}
+
+/// Render the given trees, drawing the branches of nested sub-projects.
+#[must_use]
+pub fn render_trees(trees: &[ProjectTree]) -> String {
+    let mut output = String::new();
+
+    for tree in trees {
+        let _ = writeln!(output, "{}", tree.project.title);
+        render_children(&tree.children, "", &mut output);
+    }
+
+    output
+}
+
+fn render_children(children: &[ProjectTree], prefix: &str, output: &mut String) {
+    for (index, child) in children.iter().enumerate() {
+        let last = index + 1 == children.len();
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        let _ = writeln!(output, "{prefix}{branch}{}", child.project.title);
+        render_children(&child.children, &format!("{prefix}{indent}"), output);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use episko_lib::metadata::MetadataPreview;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn tree(title: &str, children: Vec<ProjectTree>) -> ProjectTree {
+        ProjectTree {
+            project: MetadataPreview {
+                id: Uuid::new_v4(),
+                parent: None,
+                title: title.to_string(),
+                description: None,
+                categories: vec![],
+                languages: vec![],
+                created: Utc::now(),
+                updated: Utc::now(),
+            },
+            children,
+        }
+    }
+
+    #[test]
+    fn test_render_trees() {
+        let trees = vec![
+            tree(
+                "monorepo",
+                vec![
+                    tree("app", vec![tree("plugin", vec![])]),
+                    tree("lib", vec![]),
+                ],
+            ),
+            tree("single", vec![]),
+        ];
+
+        assert_eq!(
+            render_trees(&trees),
+            "monorepo\n├── app\n│   └── plugin\n└── lib\nsingle\n"
+        );
+    }
+}
@@ -6,27 +6,71 @@
 //! ## Structure
 //! The library is structured into the following modules:
 //! - cli
+//! - configuration
 //! - creation
+//! - diagnostics
+//! - export
+//! - importing
+//! - links
+//! - listing
+//! - normalisation
+//! - renaming
 //! - removal
+//! - site
+//! - statistics
 //! - validation
 //!
 //! Detailed documentation can be found within each module.
 
+use camino::Utf8PathBuf;
 use clap::Parser;
 use color_eyre::Result;
-use episko_cli::cli;
-use episko_lib::config::config_handler::ConfigHandler;
+use episko_cli::{cli, diagnostics::with_diagnostics};
+use episko_lib::{
+    config::config_handler::{ConfigHandler, Overrides},
+    metadata::property::PropertyKind,
+};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     color_eyre::install()?;
 
     let args = cli::Cli::parse();
-    let mut config_handler = ConfigHandler::load()?;
 
-    match args.command {
+    // Errors of unparsable files are shown along with their source
+    run(args.command, &args.global)
+        .await
+        .map_err(with_diagnostics)
+}
+
+async fn run(command: cli::Commands, global: &cli::GlobalArgs) -> Result<()> {
+    let config_file = match &global.config {
+        Some(config_file) => config_file.clone().into_std_path_buf(),
+        None => ConfigHandler::default_config_file()?,
+    };
+
+    // Checks must neither create nor change the config
+    if let cli::Commands::Validate(args) = &command
+        && args.check
+    {
+        if !episko_cli::check_manifests(args, &config_file)? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let overrides = Overrides {
+        profile: global.profile.clone(),
+        database: global.db.clone().map(Utf8PathBuf::into_std_path_buf),
+    };
+    let mut config_handler = ConfigHandler::load_with(&config_file, &overrides)?;
+    for warning in config_handler.warnings() {
+        eprintln!("WARNING: {warning}");
+    }
+
+    match command {
         cli::Commands::Create(create_args) => {
-            episko_cli::create_manifest(create_args, &mut config_handler).await?;
+            episko_cli::create_manifest(*create_args, &mut config_handler).await?;
         }
         cli::Commands::Remove { file } => {
             episko_cli::remove_manifest(&file, &mut config_handler).await?;
@@ -34,8 +78,56 @@ async fn main() -> Result<()> {
         cli::Commands::Cache { file } => {
             episko_cli::cache_manifest(&file, &mut config_handler).await?;
         }
-        cli::Commands::Validate { file } => {
-            episko_cli::validate_manifest(&file, &mut config_handler).await?;
+        cli::Commands::Validate(validate_args) => {
+            episko_cli::validate_manifests(&validate_args, &mut config_handler).await?;
+        }
+        cli::Commands::Schema { output } => {
+            episko_cli::print_schema(output.as_ref())?;
+        }
+        cli::Commands::Links { file, open } => {
+            episko_cli::show_links(&file, open.as_deref(), &config_handler)?;
+        }
+        cli::Commands::List { tree } => {
+            episko_cli::list_projects(tree, &config_handler).await?;
+        }
+        cli::Commands::Export(export_args) => {
+            episko_cli::export_projects(export_args, &config_handler).await?;
+        }
+        cli::Commands::Import(import_args) => {
+            episko_cli::import_projects(import_args, &mut config_handler).await?;
+        }
+        cli::Commands::Site { out } => {
+            episko_cli::generate_site(&out, &config_handler).await?;
+        }
+        cli::Commands::Stats(stats_args) => {
+            episko_cli::show_statistics(stats_args, &config_handler).await?;
+        }
+        cli::Commands::Category(args) => {
+            episko_cli::rename_property(PropertyKind::Category, args, &config_handler).await?;
+        }
+        cli::Commands::Language(args) => {
+            episko_cli::rename_property(PropertyKind::Language, args, &config_handler).await?;
+        }
+        cli::Commands::BuildSystem(args) => {
+            episko_cli::rename_property(PropertyKind::BuildSystem, args, &config_handler).await?;
+        }
+        cli::Commands::Ide(args) => {
+            episko_cli::rename_property(PropertyKind::Ide, args, &config_handler).await?;
+        }
+        cli::Commands::License(args) => {
+            episko_cli::rename_property(PropertyKind::License, args, &config_handler).await?;
+        }
+        cli::Commands::Framework(args) => {
+            episko_cli::rename_property(PropertyKind::Framework, args, &config_handler).await?;
+        }
+        cli::Commands::Person(args) => {
+            episko_cli::rename_property(PropertyKind::Person, args, &config_handler).await?;
+        }
+        cli::Commands::Normalise { files } => {
+            episko_cli::normalise_manifests(&files, &config_handler).await?;
+        }
+        cli::Commands::Config(config_args) => {
+            episko_cli::manage_config(config_args.action, &mut config_handler)?;
         }
     }
     Ok(())
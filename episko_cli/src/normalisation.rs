@@ -0,0 +1,97 @@
+//! # Normalisation of manifests
+//!
+//! This module contains the code for replacing aliases of properties with their canonical names and is directly used by the normalise command.
+
+use std::path::PathBuf;
+
+use camino::Utf8PathBuf;
+use color_eyre::Result;
+use episko_lib::{config::ConfigHandler, metadata::Metadata};
+
+use crate::connect_to_db;
+
+/// Rewrite the given manifests using the canonical names of all properties.
+///
+/// If no files are given, all files saved in the config are rewritten.
+/// Cached projects are updated in the cache as well.
+///
+/// Returns the paths of all rewritten manifests.
+///
+/// # Errors
+/// - Error report when a manifest can't be read or written
+/// - Error report when updating the cache fails
+pub async fn normalise_manifests(
+    files: &[Utf8PathBuf],
+    config_handler: &ConfigHandler,
+) -> Result<Vec<PathBuf>> {
+    let config = config_handler.config();
+    let db = connect_to_db(config).await?;
+
+    let files: Vec<PathBuf> = if files.is_empty() {
+        config_handler.files().iter().cloned().collect()
+    } else {
+        files
+            .iter()
+            .map(|el| el.as_std_path().to_path_buf())
+            .collect()
+    };
+
+    let mut rewritten = vec![];
+    for file in files {
+        if !Metadata::normalise_file(&file, &config.discovery, &config.aliases)? {
+            continue;
+        }
+
+        let metadata = Metadata::from_file_with(&file, &config.discovery, &config.aliases)?;
+        if metadata.is_cached(&db).await? {
+            metadata.update_in_db(&db).await?;
+        }
+
+        println!("Normalised {}", metadata.directory().display());
+        rewritten.push(metadata.directory().to_path_buf());
+    }
+
+    Ok(rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::cli::tests::config_handler;
+
+    #[tokio::test]
+    async fn test_normalise_manifests() {
+        let dir = std::env::temp_dir().join(format!("episko-normalise-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let manifest = dir.join("manifest.toml");
+        fs::write(
+            &manifest,
+            r#"id = "0b6c8d36-7d4c-4b35-bb51-2a6c35b4e0b9"
+title = "Aliases"
+created = "2025-03-05T15:23:33Z"
+updated = "2025-03-05T15:23:33Z"
+category = []
+build_system = []
+
+[[language]]
+name = "py"
+"#,
+        )
+        .unwrap();
+        let files = [Utf8PathBuf::from_path_buf(manifest.clone()).unwrap()];
+        let ch = config_handler();
+
+        let rewritten = normalise_manifests(&files, &ch).await.unwrap();
+        let unchanged = normalise_manifests(&files, &ch).await.unwrap();
+
+        assert_eq!(rewritten.len(), 1);
+        assert!(unchanged.is_empty());
+        assert!(fs::read_to_string(&manifest)
+            .unwrap()
+            .contains(r#"name = "Python""#));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
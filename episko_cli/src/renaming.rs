@@ -0,0 +1,98 @@
+//! # Renaming and merging of properties
+//!
+//! This module contains the code for renaming or merging properties across all cached projects and is directly used by the category, language, build-system and ide commands.
+
+use color_eyre::Result;
+use episko_lib::{
+    config::ConfigHandler,
+    metadata::property::PropertyKind,
+    rename::{Rename, RenameHandler, RenamedProject},
+};
+
+use crate::{
+    cli::{PropertyAction, PropertyArgs},
+    connect_to_db,
+};
+
+/// Rename or merge properties of the given kind in all manifests and the cache.
+///
+/// Returns the affected projects, which are only listed on a dry run.
+///
+/// # Errors
+/// - Error report when [`RenameHandler::preview`] or [`RenameHandler::apply`] fails
+pub async fn rename_property(
+    kind: PropertyKind,
+    args: PropertyArgs,
+    config_handler: &ConfigHandler,
+) -> Result<Vec<RenamedProject>> {
+    let (rename, dry_run) = match args.action {
+        PropertyAction::Rename { from, to, dry_run } => (
+            Rename {
+                kind,
+                from: vec![from],
+                to,
+            },
+            dry_run,
+        ),
+        PropertyAction::Merge {
+            from,
+            into,
+            dry_run,
+        } => (
+            Rename {
+                kind,
+                from,
+                to: into,
+            },
+            dry_run,
+        ),
+    };
+
+    let db = connect_to_db(config_handler.config()).await?;
+
+    let config = config_handler.config();
+    let projects = if dry_run {
+        RenameHandler::preview(&rename, &config.discovery, &config.aliases, &db).await?
+    } else {
+        RenameHandler::apply(&rename, &config.discovery, &config.aliases, &db).await?
+    };
+
+    let verb = if dry_run { "Would rename" } else { "Renamed" };
+    for project in &projects {
+        let source = if project.manifest_changed {
+            project.manifest.display().to_string()
+        } else {
+            "inherited".to_string()
+        };
+        println!("{verb} {kind} in {} ({source})", project.title);
+    }
+    if projects.is_empty() {
+        println!("No project uses the given {kind}");
+    }
+
+    Ok(projects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::tests::config_handler;
+
+    #[tokio::test]
+    async fn test_rename_dry_run_without_projects() {
+        let args = PropertyArgs {
+            action: PropertyAction::Merge {
+                from: vec!["uni".to_string(), "university".to_string()],
+                into: "Studies".to_string(),
+                dry_run: true,
+            },
+        };
+        let ch = config_handler();
+
+        let projects = rename_property(PropertyKind::Category, args, &ch)
+            .await
+            .unwrap();
+
+        assert!(projects.is_empty());
+    }
+}
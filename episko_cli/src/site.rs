@@ -0,0 +1,22 @@
+//! # Generation of a static site
+//!
+//! This module contains the code for generating a static site of all cached projects and is directly used by the site command.
+
+use camino::Utf8PathBuf;
+use color_eyre::Result;
+use episko_lib::{config::ConfigHandler, site::SiteHandler};
+
+use crate::connect_to_db;
+
+/// Generate a static HTML site of all cached projects into the given directory.
+///
+/// # Errors
+/// - Error report when [`SiteHandler::generate`] fails
+pub async fn generate_site(out: &Utf8PathBuf, config_handler: &ConfigHandler) -> Result<()> {
+    let db = connect_to_db(config_handler.config()).await?;
+
+    let amount = SiteHandler::generate(out.as_std_path(), &db).await?;
+    println!("Generated site with {amount} project(s) in {out}");
+
+    Ok(())
+}
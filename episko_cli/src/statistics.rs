@@ -0,0 +1,379 @@
+//! # Statistics about cached projects
+//!
+//! This module contains the code for displaying statistics about all cached projects and is directly used by the stats command.
+//!
+//! Counts are printed as aligned tables with horizontal bar charts,
+//! scaled to the largest count of each table.
+
+use std::{collections::HashMap, fmt::Write as _};
+
+use color_eyre::Result;
+use episko_lib::{
+    config::ConfigHandler,
+    statistics::{
+        statistic_handler::StatisticHandler, CoOccurrence, Statistic, StatisticOptions,
+        TimelineOptions,
+    },
+};
+
+use crate::{cli::StatsArgs, connect_to_db, export::parse_filter};
+
+/// Width of the bar representing the largest count of a chart.
+static BAR_WIDTH: usize = 30;
+static BAR: char = '█';
+
+/// Print statistics about all cached projects matching the given filters.
+///
+/// Minimum versions are checked against the version policy of the config.
+///
+/// # Errors
+/// - Error report when a filter is invalid
+/// - Error report when [`StatisticHandler::generate_statistics_with`] fails
+/// - Error report when the statistics can't be serialized to json
+pub async fn show_statistics(args: StatsArgs, config_handler: &ConfigHandler) -> Result<()> {
+    let options = StatisticOptions {
+        timeline: TimelineOptions {
+            bucket: args.bucket,
+            stale_after_days: args.stale_days,
+        },
+        version_policy: config_handler.config().version_policy.clone(),
+        filter: parse_filter(&args.filter)?,
+    };
+
+    let db = connect_to_db(config_handler.config()).await?;
+    let statistic = StatisticHandler::generate_statistics_with(&db, &options).await?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&statistic)?);
+    } else {
+        print!("{}", render_statistic(&statistic, &options, args.top));
+    }
+
+    Ok(())
+}
+
+/// Render the given statistics as tables with bar charts.
+///
+/// If `top` is given, only the most frequent entries of each chart are
+/// included, in their usual order. Tables of projects are never truncated.
+#[must_use]
+pub fn render_statistic(
+    statistic: &Statistic,
+    options: &StatisticOptions,
+    top: Option<usize>,
+) -> String {
+    let mut output = format!(
+        "Projects: {}\nLanguages: {}\n",
+        statistic.number_of_projects, statistic.number_of_languages
+    );
+
+    for (title, counts) in [
+        ("Projects by language", &statistic.projects_by_language),
+        ("Projects by category", &statistic.projects_by_category),
+        (
+            "Projects by build system",
+            &statistic.projects_by_build_system,
+        ),
+        ("Projects by IDE", &statistic.projects_by_ide),
+        ("Projects by license", &statistic.projects_by_license),
+        ("Projects by framework", &statistic.projects_by_framework),
+        ("Projects per maintainer", &statistic.projects_by_maintainer),
+    ] {
+        render_chart(&mut output, title, sorted_counts(counts), top);
+    }
+
+    for (title, versions) in [
+        ("Language versions", &statistic.language_versions),
+        ("Build system versions", &statistic.build_system_versions),
+    ] {
+        let rows = versions
+            .iter()
+            .map(|el| {
+                let version = el.version.as_deref().unwrap_or("unversioned");
+                (format!("{} {version}", el.name), el.count)
+            })
+            .collect();
+        render_chart(&mut output, title, rows, top);
+    }
+
+    for (title, pairs) in [
+        (
+            "Languages used together",
+            &statistic.languages_used_together,
+        ),
+        ("Preferred IDE per language", &statistic.ide_per_language),
+        (
+            "Build systems per category",
+            &statistic.build_systems_per_category,
+        ),
+    ] {
+        render_chart(&mut output, title, pair_rows(pairs), top);
+    }
+
+    let timeline = &statistic.timeline;
+    let bucket = timeline.bucket;
+    for (title, periods) in [
+        (format!("Created per {bucket}"), &timeline.created),
+        (format!("Last updated per {bucket}"), &timeline.updated),
+    ] {
+        let rows = periods
+            .iter()
+            .map(|el| (el.period.clone(), el.count))
+            .collect();
+        render_chart(&mut output, &title, rows, top);
+    }
+
+    let rows = timeline
+        .language_adoption
+        .iter()
+        .map(|el| (format!("{} {}", el.period, el.language), el.count))
+        .collect();
+    render_chart(
+        &mut output,
+        &format!("Language adoption per {bucket}"),
+        rows,
+        top,
+    );
+
+    let rows: Vec<_> = timeline
+        .stale_projects
+        .iter()
+        .map(|el| vec![el.title.clone(), format!("{} days", el.days_since_update)])
+        .collect();
+    render_table(
+        &mut output,
+        &format!(
+            "Stale projects (not updated for {} days)",
+            options.timeline.stale_after_days
+        ),
+        &rows,
+    );
+
+    if !options.version_policy.is_empty() {
+        let rows: Vec<_> = statistic
+            .below_minimum_version
+            .iter()
+            .map(|el| {
+                vec![
+                    el.title.clone(),
+                    format!("{} {}", el.name, el.version),
+                    format!("< {}", el.minimum),
+                ]
+            })
+            .collect();
+        render_table(&mut output, "Projects below minimum version", &rows);
+    }
+
+    output
+}
+
+/// Render a table of labels with their count and a bar scaled to the largest count.
+///
+/// If `top` is given, only the rows with the largest counts are rendered.
+fn render_chart(output: &mut String, title: &str, rows: Vec<(String, u32)>, top: Option<usize>) {
+    let _ = writeln!(output, "\n{title}");
+    let rows = most_frequent(rows, top);
+
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or_default();
+    let count_width = rows
+        .iter()
+        .map(|(_, count)| count.to_string().len())
+        .max()
+        .unwrap_or_default();
+    let max = rows
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or_default();
+
+    for (label, count) in rows {
+        let bar = BAR.to_string().repeat(bar_length(count, max));
+        let _ = writeln!(
+            output,
+            "  {label:<label_width$}  {count:>count_width$}  {bar}"
+        );
+    }
+}
+
+/// Render a table with left aligned columns.
+fn render_table(output: &mut String, title: &str, rows: &[Vec<String>]) {
+    let _ = writeln!(output, "\n{title}");
+
+    let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|el| el.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(el, width)| format!("{el:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        let _ = writeln!(output, "  {}", line.trim_end());
+    }
+}
+
+/// Length of the bar for the given count, at least one for non-zero counts.
+fn bar_length(count: u32, max: u32) -> usize {
+    if max == 0 || count == 0 {
+        return 0;
+    }
+
+    let length = (u64::from(count) * BAR_WIDTH as u64).div_ceil(u64::from(max));
+    usize::try_from(length).unwrap_or(BAR_WIDTH)
+}
+
+/// Counts sorted by descending count and name.
+fn sorted_counts(counts: &HashMap<String, u32>) -> Vec<(String, u32)> {
+    let mut counts: Vec<_> = counts
+        .iter()
+        .map(|(name, count)| (name.clone(), *count))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+fn pair_rows(pairs: &[CoOccurrence]) -> Vec<(String, u32)> {
+    pairs
+        .iter()
+        .map(|el| (format!("{} + {}", el.first, el.second), el.count))
+        .collect()
+}
+
+/// Keep the given amount of rows with the largest counts, in their original order.
+///
+/// Of rows with the same count the first ones are kept.
+fn most_frequent(rows: Vec<(String, u32)>, top: Option<usize>) -> Vec<(String, u32)> {
+    let Some(top) = top.filter(|top| *top < rows.len()) else {
+        return rows;
+    };
+
+    let mut kept: Vec<usize> = (0..rows.len()).collect();
+    kept.sort_by(|a, b| rows[*b].1.cmp(&rows[*a].1));
+    kept.truncate(top);
+    kept.sort_unstable();
+
+    rows.into_iter()
+        .enumerate()
+        .filter(|(i, _)| kept.binary_search(i).is_ok())
+        .map(|(_, row)| row)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use episko_lib::statistics::{timeline::PeriodCount, Bucket, Timeline, VersionCount};
+
+    use super::*;
+
+    fn statistic() -> Statistic {
+        Statistic {
+            projects_by_language: HashMap::from([
+                ("Rust".to_string(), 1),
+                ("Go".to_string(), 2),
+                ("TypeScript".to_string(), 1),
+            ]),
+            projects_by_ide: HashMap::new(),
+            projects_by_category: HashMap::new(),
+            projects_by_build_system: HashMap::new(),
+            projects_by_license: HashMap::new(),
+            projects_by_framework: HashMap::new(),
+            projects_by_maintainer: HashMap::new(),
+            number_of_projects: 3,
+            number_of_languages: 3,
+            timeline: Timeline {
+                bucket: Bucket::Year,
+                created: vec![PeriodCount {
+                    period: "2024".to_string(),
+                    count: 3,
+                }],
+                updated: vec![],
+                language_adoption: vec![],
+                stale_projects: vec![],
+            },
+            language_versions: vec![
+                VersionCount {
+                    name: "Rust".to_string(),
+                    version: Some("1.70".to_string()),
+                    count: 1,
+                },
+                VersionCount {
+                    name: "Rust".to_string(),
+                    version: None,
+                    count: 2,
+                },
+            ],
+            build_system_versions: vec![],
+            below_minimum_version: vec![],
+            languages_used_together: vec![CoOccurrence {
+                first: "Go".to_string(),
+                second: "Rust".to_string(),
+                count: 1,
+            }],
+            ide_per_language: vec![],
+            build_systems_per_category: vec![],
+        }
+    }
+
+    #[test]
+    fn test_render_statistic() {
+        let output = render_statistic(&statistic(), &StatisticOptions::default(), None);
+
+        assert!(output.starts_with("Projects: 3\nLanguages: 3\n"));
+        assert!(output.contains(&format!(
+            "Projects by language\n  Go          2  {}\n  Rust        1  {}\n  TypeScript  1  {}\n",
+            "█".repeat(30),
+            "█".repeat(15),
+            "█".repeat(15)
+        )));
+        assert!(output.contains("  Rust 1.70         1  "));
+        assert!(output.contains("  Rust unversioned  2  "));
+        assert!(output.contains("  Go + Rust  1  "));
+        assert!(output.contains("Created per year\n  2024  3  "));
+        assert!(output.contains("Stale projects (not updated for 180 days)"));
+        assert!(!output.contains("Projects below minimum version"));
+    }
+
+    #[test]
+    fn test_render_statistic_top() {
+        let output = render_statistic(&statistic(), &StatisticOptions::default(), Some(2));
+
+        assert!(output.contains("  Go    2"));
+        assert!(output.contains("  Rust  1"));
+        assert!(!output.contains("TypeScript"));
+
+        let output = render_statistic(&statistic(), &StatisticOptions::default(), Some(1));
+        assert!(output.contains("  Rust unversioned  2"));
+        assert!(!output.contains("Rust 1.70"));
+    }
+
+    #[test]
+    fn test_most_frequent() {
+        let rows = |counts: &[u32]| -> Vec<(String, u32)> {
+            counts.iter().map(|el| (el.to_string(), *el)).collect()
+        };
+
+        assert_eq!(most_frequent(rows(&[1, 3, 2, 3]), Some(2)), rows(&[3, 3]));
+        assert_eq!(most_frequent(rows(&[1, 3, 2]), Some(2)), rows(&[3, 2]));
+        assert_eq!(most_frequent(rows(&[1, 2]), None), rows(&[1, 2]));
+    }
+
+    #[test]
+    fn test_bar_length() {
+        assert_eq!(bar_length(0, 10), 0);
+        assert_eq!(bar_length(1, 100), 1);
+        assert_eq!(bar_length(10, 10), BAR_WIDTH);
+    }
+}
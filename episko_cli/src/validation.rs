@@ -2,23 +2,107 @@
 //!
 //! This module contains the code for the validation and caching of a manifest and is directly used by the the validate and cache command.
 
+use std::path::Path;
+
 use camino::Utf8PathBuf;
-use color_eyre::Result;
-use episko_lib::{config::config_handler::ConfigHandler, files::File, metadata::Metadata};
+use color_eyre::{eyre::eyre, Result};
+use episko_lib::{
+    config::config_handler::ConfigHandler,
+    files::{
+        check::{find_manifests, CheckReport, ReportFormat},
+        schema::{manifest_schema, SchemaError},
+        File,
+    },
+    metadata::{builder, metadata_handler::MetadataHandler, Metadata},
+};
+
+use crate::{cli::ValidateArgs, connect_to_db};
+
+/// Validate and cache all manifests found within the given paths,
+/// which may be manifests, directories or globs.
+///
+/// # Errors
+/// - [`color_eyre::Report`] when no manifest is found for a path
+/// - Propagates errors from [`validate_manifest`] for the first invalid manifest
+pub async fn validate_manifests(
+    args: &ValidateArgs,
+    config_handler: &mut ConfigHandler,
+) -> Result<()> {
+    for path in &args.paths {
+        let manifests = find_manifests(path, &config_handler.config().discovery)?;
+
+        for manifest in manifests {
+            let manifest = Utf8PathBuf::try_from(manifest)?;
+            validate_manifest(&manifest, config_handler).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Check all manifests found within the given paths without caching them
+/// or changing the given config, see [`CheckReport`].
+///
+/// The report is printed, or written to the given output in which case
+/// a summary is printed instead.
+///
+/// Returns whether all manifests are valid.
+///
+/// # Errors
+/// - [`color_eyre::Report`] when the config can't be read
+/// - [`color_eyre::Report`] when no manifest is found for a path
+/// - [`color_eyre::Report`] when the report can't be rendered or written
+pub fn check_manifests(args: &ValidateArgs, config_file: &Path) -> Result<bool> {
+    let discovery = ConfigHandler::read_discovery(config_file)?;
+    let aliases = ConfigHandler::read_aliases(config_file)?;
+    let paths: Vec<&str> = args.paths.iter().map(String::as_str).collect();
+
+    let report = CheckReport::run(&paths, &discovery, &aliases)?;
+    let rendered = report.render(args.format)?;
 
-use crate::connect_to_db;
+    match &args.output {
+        Some(output) => {
+            std::fs::write(output, rendered)?;
+            println!("{}", report.render(ReportFormat::Text)?);
+        }
+        None => println!("{rendered}"),
+    }
+
+    Ok(report.passed())
+}
 
 /// Validates a manifest file and further more validates the cache if
 /// possible.
 ///
+/// The manifest is first checked against the schema, reporting every
+/// offending key, afterwards all problems of its values are reported
+/// at once, see [`Metadata::validate`].
+///
 /// # Errors
+/// - [`color_eyre::Report`] listing all keys violating the schema
 /// - [`color_eyre::Report`] when [`Metadata::validate_file`] fails
+/// - [`color_eyre::Report`] listing all problems when [`Metadata::validate`] fails
 pub async fn validate_manifest(
     file: &Utf8PathBuf,
     config_handler: &mut ConfigHandler,
 ) -> Result<()> {
+    let config = config_handler.config();
+
+    let schema_errors = Metadata::schema_errors(file.as_std_path(), &config.discovery)?;
+    if !schema_errors.is_empty() {
+        return Err(eyre!(
+            "{file} does not match the manifest schema:\n{}",
+            render_schema_errors(&schema_errors)
+        ));
+    }
+
     Metadata::validate_file(file.as_std_path())?;
 
+    let metadata =
+        Metadata::from_file_with(file.as_std_path(), &config.discovery, &config.aliases)?;
+    if let Err(err) = metadata.validate() {
+        return Err(eyre!("{file} is invalid:\n{}", render_problems(&err)));
+    }
+
     cache_manifest(file, config_handler).await?;
     Ok(())
 }
@@ -28,23 +112,135 @@ pub async fn validate_manifest(
 /// Currently calls [`Metadata::update_in_db`], but this fails when the
 /// metadata isn't cached in the first place.
 ///
+/// Sub-projects declared by the manifest are cached as well.
+///
 /// # Errors
 /// - Error report when [`Metadata::update_in_db`] fails.
 pub async fn cache_manifest(file: &Utf8PathBuf, config_handler: &mut ConfigHandler) -> Result<()> {
     let db = connect_to_db(config_handler.config()).await?;
 
-    let metadata = Metadata::from_file(file.as_std_path())?;
+    let config = config_handler.config();
+    let projects = MetadataHandler::load_with_subprojects(
+        file.as_std_path(),
+        &config.discovery,
+        &config.aliases,
+    )?;
 
-    if metadata.is_cached(&db).await? {
-        metadata.update_in_db(&db).await?;
-    } else {
-        metadata.write_to_db(&db).await?;
+    for metadata in &projects {
+        if metadata.is_cached(&db).await? {
+            metadata.update_in_db(&db).await?;
+        } else {
+            metadata.write_to_db(&db).await?;
+        }
     }
 
     // Reloading the config_handler / config isn't very pretty,
     // however I think it still that it's the simplest way to do this.
-    config_handler.add_saved_file(metadata.directory());
+    config_handler.add_saved_file(projects[0].directory());
     config_handler.save_config()?;
 
     Ok(())
 }
+
+/// Print the JSON Schema of the manifest format, or write it to the given file.
+///
+/// # Errors
+/// - [`color_eyre::Report`] when serializing the schema fails
+/// - [`color_eyre::Report`] when the output file cannot be written
+pub fn print_schema(output: Option<&Utf8PathBuf>) -> Result<()> {
+    let schema = serde_json::to_string_pretty(&manifest_schema())?;
+
+    match output {
+        Some(output) => {
+            std::fs::write(output, schema)?;
+            println!("Wrote manifest schema to {output}");
+        }
+        None => println!("{schema}"),
+    }
+    Ok(())
+}
+
+/// Render the keys violating the schema, one per line.
+#[must_use]
+pub fn render_schema_errors(errors: &[SchemaError]) -> String {
+    errors
+        .iter()
+        .map(|err| format!("  - {err}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render the problems of a failed validation, one per line.
+#[must_use]
+pub fn render_problems(err: &builder::Error) -> String {
+    err.problems()
+        .iter()
+        .map(|problem| format!("  - {problem}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_problems() {
+        let err = Metadata::builder().title(" ").build().unwrap_err();
+
+        assert_eq!(
+            render_problems(&err),
+            "  - invalid title: \" \"\n  - directory missing"
+        );
+    }
+
+    #[test]
+    fn test_check_manifests() {
+        let dir = std::env::temp_dir().join(format!("episko-check-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let metadata = Metadata::builder()
+            .title("Check")
+            .directory_path(&dir)
+            .build()
+            .unwrap();
+        metadata.write_file(&dir.join("manifest.toml")).unwrap();
+
+        let output = Utf8PathBuf::try_from(dir.join("report.xml")).unwrap();
+        let args = ValidateArgs {
+            paths: vec![dir.display().to_string()],
+            check: true,
+            format: ReportFormat::Junit,
+            output: Some(output.clone()),
+        };
+
+        let config_file = dir.join("config.toml");
+        assert!(check_manifests(&args, &config_file).unwrap());
+        assert!(!config_file.exists());
+        let report = std::fs::read_to_string(&output).unwrap();
+        assert!(report.contains("tests=\"1\" failures=\"0\""));
+
+        std::fs::write(dir.join("manifest.toml"), "id = 1\ntitle = \"Broken\"\n").unwrap();
+        assert!(!check_manifests(&args, &config_file).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_schema_errors() {
+        let errors = [
+            SchemaError {
+                path: String::new(),
+                message: "\"title\" is a required property".to_string(),
+            },
+            SchemaError {
+                path: "language[1].version".to_string(),
+                message: "5 is not of type \"string\"".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            render_schema_errors(&errors),
+            "  - \"title\" is a required property\n  - language[1].version: 5 is not of type \"string\""
+        );
+    }
+}
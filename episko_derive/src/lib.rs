@@ -139,8 +139,7 @@ impl DbObjectMeta {
                 .map(::std::string::String::as_str)
                 .collect::<Vec<_>>()
                 .join(", "),
-            std::iter::repeat("?")
-                .take(columns.len())
+            std::iter::repeat_n("?", columns.len())
                 .collect::<Vec<_>>()
                 .join(", ")
         );
@@ -158,9 +157,21 @@ impl DbObjectMeta {
         let exists_sql_literal = syn::LitStr::new(&exists_sql, proc_macro2::Span::call_site());
         let id_field_ident = self.id_field_ident.clone();
 
+        let all_columns = self
+            .fields
+            .iter()
+            .map(|f| f.attr.col.as_str())
+            .collect::<Vec<_>>();
         let all_sql = format!(
-            "SELECT id, name, null as version FROM {} GROUP BY name",
-            self.table
+            "SELECT {} FROM {} GROUP BY {}",
+            all_columns.join(", "),
+            self.table,
+            all_columns
+                .iter()
+                .filter(|col| **col != "id")
+                .copied()
+                .collect::<Vec<_>>()
+                .join(", ")
         );
         let all_sql_literal = syn::LitStr::new(&all_sql, proc_macro2::Span::call_site());
 
@@ -217,10 +228,11 @@ impl DbObjectMeta {
                 ) -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = crate::database::Result<Vec<Self>>>
                     + Send + 'e>> {
                     Box::pin(async move {
-                        Ok(::sqlx::query_as::<_, Self>(#all_sql_literal)
+                        let mut all = ::sqlx::query_as::<_, Self>(#all_sql_literal)
                                 .fetch_all(executor)
-                                .await?
-                        )
+                                .await?;
+                        all.sort();
+                        Ok(all)
                     })
                 }
 
@@ -251,6 +263,9 @@ impl DbObjectMeta {
 /// - no `#[db(table = "table"]` is assigned
 /// - no `#[db(col = "id")]` is assigned,
 /// - any other invalid statements are set.
+///
+/// The struct has to implement [`Ord`], which is used to sort the
+/// results of `all_names`.
 #[proc_macro_derive(DatabaseObject, attributes(db))]
 pub fn db_object_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = TokenStream::from(input);
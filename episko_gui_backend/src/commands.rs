@@ -4,10 +4,19 @@ use tokio::sync::Mutex;
 use uuid::Uuid;
 
 use episko_lib::{
-    database::{retrieve_metadata::Pagination, DatabaseObject, Filter},
+    config::Config,
+    database::{retrieve_metadata::Pagination, DatabaseHandler, DatabaseObject, Filter},
+    export::{ExportFormat, ExportHandler},
     files::File,
-    metadata::{metadata_handler::MetadataHandler, Category, Language, Metadata, MetadataPreview},
-    statistics::{statistic_handler::StatisticHandler, Statistic},
+    import::{ImportFormat, ImportHandler, ImportReport},
+    metadata::{
+        metadata_handler::MetadataHandler, Category, Language, Link, Metadata, MetadataPreview,
+        ProjectTree,
+    },
+    rename::{Rename, RenameHandler, RenamedProject},
+    statistics::{
+        statistic_handler::StatisticHandler, Statistic, StatisticOptions, TimelineOptions,
+    },
 };
 
 use crate::{model::MetadataDco, model::MetadataDto, AppState, Error};
@@ -22,20 +31,49 @@ pub struct PagedData<T> {
     data: Vec<T>,
 }
 
+#[derive(Serialize, Debug)]
+pub struct Profiles {
+    active: String,
+    profiles: Vec<String>,
+}
+
+impl Profiles {
+    fn of(config: &Config) -> Self {
+        Self {
+            active: config.active_profile().to_string(),
+            profiles: config
+                .profile_names()
+                .into_iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn init_cache(state: tauri::State<'_, Mutex<AppState>>) -> Result<(), Error> {
     let state = state.lock().await;
 
+    let config = state.config_handler.config();
+
     let files = state.config_handler.files();
     for file in files {
-        Metadata::from_file(file)?.write_to_db(&state.db).await?;
+        for metadata in
+            MetadataHandler::load_with_subprojects(file, &config.discovery, &config.aliases)?
+        {
+            metadata.write_to_db(&state.db).await?;
+        }
     }
 
     let dirs = state.config_handler.dirs();
     for dir in dirs {
-        let files = MetadataHandler::search_directory(dir)?;
+        let files = MetadataHandler::search_directory(dir, &config.discovery)?;
         for file in &files {
-            Metadata::from_file(file)?.write_to_db(&state.db).await?;
+            for metadata in
+                MetadataHandler::load_with_subprojects(file, &config.discovery, &config.aliases)?
+            {
+                metadata.write_to_db(&state.db).await?;
+            }
         }
     }
 
@@ -65,6 +103,17 @@ pub async fn get_all(
     })
 }
 
+#[tauri::command]
+pub async fn get_project_tree(
+    state: tauri::State<'_, Mutex<AppState>>,
+) -> Result<Vec<ProjectTree>, Error> {
+    let state = state.lock().await;
+
+    let projects = Metadata::all_preview_from_db(None, Filter::default(), &state.db).await?;
+
+    Ok(ProjectTree::build(projects))
+}
+
 #[tauri::command]
 pub async fn get_with_id(
     id: Uuid,
@@ -90,14 +139,28 @@ pub async fn get_all_languages(
 ) -> Result<Vec<Language>, Error> {
     let state = state.lock().await;
 
-    Ok(Language::all_names(state.db.conn()).await?)
+    // Languages are listed once per version, the frontend only filters by name.
+    let mut languages = Language::all_names(state.db.conn()).await?;
+    languages.dedup_by(|a, b| a.name.eq_ignore_ascii_case(&b.name));
+
+    Ok(languages)
 }
 
 #[tauri::command]
-pub async fn get_statistics(state: tauri::State<'_, Mutex<AppState>>) -> Result<Statistic, Error> {
+pub async fn get_statistics(
+    timeline: Option<TimelineOptions>,
+    filter: Option<Filter>,
+    state: tauri::State<'_, Mutex<AppState>>,
+) -> Result<Statistic, Error> {
     let state = state.lock().await;
 
-    Ok(StatisticHandler::generate_statistics(&state.db).await?)
+    let options = StatisticOptions {
+        timeline: timeline.unwrap_or_default(),
+        version_policy: state.config_handler.config().version_policy.clone(),
+        filter: filter.unwrap_or_default(),
+    };
+
+    Ok(StatisticHandler::generate_statistics_with(&state.db, &options).await?)
 }
 
 #[tauri::command]
@@ -108,12 +171,14 @@ pub async fn update_metadata(
 ) -> Result<MetadataDto, Error> {
     let state = state.lock().await;
 
+    let config = state.config_handler.config();
+
     let metadata = Metadata::from_db(&state.db, id).await?;
 
-    let metadata = updated.update(metadata)?;
+    let metadata = updated.update(metadata, &config.aliases)?;
 
     metadata.update_in_db(&state.db).await?;
-    metadata.write_file(&metadata.directory)?;
+    metadata.write_file_with(&metadata.directory, &config.discovery, &config.aliases)?;
 
     Ok(metadata.into())
 }
@@ -123,13 +188,14 @@ pub async fn create_metadata(
     created: MetadataDco,
     state: tauri::State<'_, Mutex<AppState>>,
 ) -> Result<Uuid, Error> {
-    let metadata = created.create()?;
-
     let mut state = state.lock().await;
 
+    let config = state.config_handler.config();
+    let metadata = created.create(config.discovery.manifest_name(), &config.aliases)?;
+
     metadata.write_to_db(&state.db).await?;
 
-    metadata.write_file(&metadata.directory)?;
+    metadata.write_file_with(&metadata.directory, &config.discovery, &config.aliases)?;
 
     state.config_handler.add_saved_file(&metadata.directory);
     state.config_handler.save_config()?;
@@ -187,7 +253,7 @@ pub async fn load_from_directory(
 
     let mut state = state.lock().await;
 
-    let files = MetadataHandler::search_directory(path)?;
+    let files = MetadataHandler::search_directory(path, &state.config_handler.config().discovery)?;
 
     let mut projects: Vec<Metadata> = Vec::with_capacity(files.len());
     for file in files {
@@ -202,14 +268,84 @@ pub async fn load_from_directory(
     Ok(projects.len())
 }
 
+#[tauri::command]
+pub async fn export_metadata(
+    format: ExportFormat,
+    filter: Filter,
+    path: &Path,
+    state: tauri::State<'_, Mutex<AppState>>,
+) -> Result<usize, Error> {
+    if path.is_dir() {
+        return Err(Error::BadRequest("given path is a directory".to_string()));
+    }
+
+    let state = state.lock().await;
+
+    Ok(ExportHandler::export_to_file(format, filter, path, &state.db).await?)
+}
+
+#[tauri::command]
+pub async fn import_projects(
+    path: &Path,
+    format: ImportFormat,
+    detect: bool,
+    state: tauri::State<'_, Mutex<AppState>>,
+) -> Result<ImportReport, Error> {
+    if !path.is_file() {
+        return Err(Error::BadRequest("given path is not a file".to_string()));
+    }
+
+    let mut state = state.lock().await;
+    let state = &mut *state;
+
+    Ok(
+        ImportHandler::import_file(path, format, detect, &state.db, &mut state.config_handler)
+            .await?,
+    )
+}
+
+#[tauri::command]
+pub async fn rename_property(
+    rename: Rename,
+    dry_run: bool,
+    state: tauri::State<'_, Mutex<AppState>>,
+) -> Result<Vec<RenamedProject>, Error> {
+    let state = state.lock().await;
+    let config = state.config_handler.config();
+
+    if dry_run {
+        Ok(RenameHandler::preview(&rename, &config.discovery, &config.aliases, &state.db).await?)
+    } else {
+        Ok(RenameHandler::apply(&rename, &config.discovery, &config.aliases, &state.db).await?)
+    }
+}
+
+/// Open the given link of a project in the default application of the system.
+///
+/// Only web links are opened, see [`Link::is_web`].
+#[tauri::command]
+pub async fn open_link(link: Link) -> Result<(), Error> {
+    if !link.is_web() {
+        return Err(episko_lib::metadata::Error::InvalidUrl(link.url).into());
+    }
+    open::that_detached(&link.url)?;
+    Ok(())
+}
+
 async fn load_file(
     path: &Path,
     state: &mut AppState,
     save_to_config: bool,
 ) -> Result<Metadata, Error> {
-    let metadata = Metadata::from_file(path)?;
+    let config = state.config_handler.config();
+    let mut projects =
+        MetadataHandler::load_with_subprojects(path, &config.discovery, &config.aliases)?;
 
-    metadata.write_to_db(&state.db).await?;
+    for project in &projects {
+        project.write_to_db(&state.db).await?;
+    }
+
+    let metadata = projects.swap_remove(0);
 
     if save_to_config {
         let ch = &mut state.config_handler;
@@ -219,3 +355,31 @@ async fn load_file(
 
     Ok(metadata)
 }
+
+/// Get the names of all profiles and the one in use.
+#[tauri::command]
+pub async fn get_profiles(state: tauri::State<'_, Mutex<AppState>>) -> Result<Profiles, Error> {
+    let state = state.lock().await;
+
+    Ok(Profiles::of(state.config_handler.config()))
+}
+
+/// Switch to the profile with the given name, re-opening the cache with the
+/// database of that profile. The selection lasts until the application is closed.
+#[tauri::command]
+pub async fn switch_profile(
+    name: String,
+    state: tauri::State<'_, Mutex<AppState>>,
+) -> Result<Profiles, Error> {
+    let mut state = state.lock().await;
+
+    // Connect first, so the current profile stays in use if that fails
+    let mut config = state.config_handler.config().clone();
+    config.select_profile(&name)?;
+    let db = DatabaseHandler::with_config(&config).await?;
+
+    state.config_handler.select_profile(&name)?;
+    state.db = db;
+
+    Ok(Profiles::of(state.config_handler.config()))
+}
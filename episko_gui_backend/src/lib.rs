@@ -1,14 +1,19 @@
 #![deny(clippy::pedantic)]
 #![allow(clippy::used_underscore_binding)]
-use episko_lib::{config::ConfigHandler, database::DatabaseHandler};
+use episko_lib::{
+    config::ConfigHandler, database::DatabaseHandler, files::parse_error::ParseError,
+    metadata::builder,
+};
 use state::AppState;
 use tauri::Manager;
 use tokio::sync::Mutex;
 
 mod commands;
 use commands::{
-    create_metadata, delete_metadata, get_all, get_all_categories, get_all_languages,
-    get_statistics, get_with_id, init_cache, load_from_directory, load_from_file, update_metadata,
+    create_metadata, delete_metadata, export_metadata, get_all, get_all_categories,
+    get_all_languages, get_profiles, get_project_tree, get_statistics, get_with_id,
+    import_projects, init_cache, load_from_directory, load_from_file, open_link, rename_property,
+    switch_profile, update_metadata,
 };
 
 pub mod model;
@@ -25,6 +30,9 @@ pub mod state;
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub async fn run() -> Result<(), Box<dyn std::error::Error>> {
     let config_handler = ConfigHandler::load()?;
+    for warning in config_handler.warnings() {
+        eprintln!("WARNING: {warning}");
+    }
 
     let db = DatabaseHandler::with_config(config_handler.config()).await?;
 
@@ -41,6 +49,7 @@ pub async fn run() -> Result<(), Box<dyn std::error::Error>> {
             init_cache,
             get_all,
             get_with_id,
+            get_project_tree,
             get_all_categories,
             get_all_languages,
             get_statistics,
@@ -49,6 +58,12 @@ pub async fn run() -> Result<(), Box<dyn std::error::Error>> {
             delete_metadata,
             load_from_file,
             load_from_directory,
+            export_metadata,
+            import_projects,
+            rename_property,
+            open_link,
+            get_profiles,
+            switch_profile,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -65,7 +80,7 @@ pub enum Error {
     File(#[from] episko_lib::files::Error),
 
     #[error(transparent)]
-    Builder(#[from] episko_lib::metadata::builder::Error),
+    Builder(#[from] builder::Error),
 
     #[error(transparent)]
     Config(#[from] episko_lib::config::Error),
@@ -76,15 +91,63 @@ pub enum Error {
     #[error(transparent)]
     Statistics(#[from] episko_lib::statistics::Error),
 
+    #[error(transparent)]
+    Export(#[from] episko_lib::export::Error),
+
+    #[error(transparent)]
+    Import(#[from] episko_lib::import::Error),
+
+    #[error(transparent)]
+    Rename(#[from] episko_lib::rename::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
     #[error("bad request: {0}")]
     BadRequest(String),
 }
 
+/// Errors are passed to the frontend with their message and, if caused by a
+/// file which couldn't be parsed, the location within that file.
+/// Invalid metadata is passed with the list of all its problems.
 impl serde::Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(self.to_string().as_ref())
+        use serde::ser::SerializeStruct;
+
+        let problems: Vec<String> = self
+            .builder_error()
+            .map(|err| err.problems().iter().map(ToString::to_string).collect())
+            .unwrap_or_default();
+
+        let mut state = serializer.serialize_struct("Error", 3)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("parse", &self.find_source::<ParseError>())?;
+        state.serialize_field("problems", &problems)?;
+        state.end()
+    }
+}
+
+impl Error {
+    /// Find the error of the given type which caused this error, if any.
+    fn find_source<T: std::error::Error + 'static>(&self) -> Option<&T> {
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(self);
+        while let Some(err) = source {
+            if let Some(found) = err.downcast_ref::<T>() {
+                return Some(found);
+            }
+            source = err.source();
+        }
+        None
+    }
+
+    /// Find the error building the metadata which caused this error, if any.
+    fn builder_error(&self) -> Option<&builder::Error> {
+        match self {
+            Error::Builder(err) => Some(err),
+            _ => self.find_source(),
+        }
     }
 }
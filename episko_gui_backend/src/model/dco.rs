@@ -1,6 +1,8 @@
 use episko_lib::{
+    config::Aliases,
     metadata::{
-        property::Property, BuildSystem, Category, Ide, Language, Metadata, MetadataBuilder,
+        property::Property, BuildSystem, Category, Framework, Ide, Language, License, Link,
+        Metadata, MetadataBuilder, Person,
     },
     ApplyIf as _,
 };
@@ -19,44 +21,66 @@ pub struct MetadataDco {
     languages: Vec<Language>,
     build_systems: Vec<BuildSystem>,
     preferred_ide: Option<Ide>,
-    repository_url: Option<String>,
+    #[serde(default)]
+    licenses: Vec<License>,
+    #[serde(default)]
+    frameworks: Vec<Framework>,
+    #[serde(default)]
+    people: Vec<Person>,
+    #[serde(default)]
+    links: Vec<Link>,
+    #[serde(default)]
+    subprojects: Vec<String>,
 }
 
 impl MetadataDco {
     /// Create a new [`Metadata`] instance from the DCO.
     ///
+    /// When the directory is not a file, the manifest is named `manifest_name`.
+    /// The given aliases are replaced by their canonical names.
+    ///
     /// # Errors
     /// - If the metadata cannot be created
-    pub fn create(mut self) -> Result<Metadata, Error> {
+    pub fn create(mut self, manifest_name: &str, aliases: &Aliases) -> Result<Metadata, Error> {
         self.categories.iter_mut().for_each(Property::update_id);
         self.build_systems.iter_mut().for_each(Property::update_id);
         self.languages.iter_mut().for_each(Property::update_id);
         self.preferred_ide.iter_mut().for_each(Property::update_id);
+        self.licenses.iter_mut().for_each(Property::update_id);
+        self.frameworks.iter_mut().for_each(Property::update_id);
+        self.people.iter_mut().for_each(Property::update_id);
 
         Ok(Metadata::builder()
             .directory_path(&self.directory)
+            .manifest_name(manifest_name)
             .title(&self.title)
             .categories(self.categories)
             .languages(self.languages)
             .build_systems(self.build_systems)
+            .licenses(self.licenses)
+            .frameworks(self.frameworks)
+            .people(self.people)
+            .subprojects(self.subprojects)
             .apply_if(self.preferred_ide, MetadataBuilder::preferred_ide)
+            .links(self.links)
             .apply_if(self.description.as_deref(), MetadataBuilder::description)
-            .apply_if(
-                self.repository_url.as_deref(),
-                MetadataBuilder::repository_url,
-            )
+            .aliases(aliases)
             .build()?)
     }
 
     /// Update an existing [`Metadata`] instance with the DCO.
+    /// The given aliases are replaced by their canonical names.
     ///
     /// # Errors
     /// - If the metadata cannot be updated
-    pub fn update(mut self, metadata: Metadata) -> Result<Metadata, Error> {
+    pub fn update(mut self, metadata: Metadata, aliases: &Aliases) -> Result<Metadata, Error> {
         self.categories.iter_mut().for_each(Property::update_id);
         self.build_systems.iter_mut().for_each(Property::update_id);
         self.languages.iter_mut().for_each(Property::update_id);
         self.preferred_ide.iter_mut().for_each(Property::update_id);
+        self.licenses.iter_mut().for_each(Property::update_id);
+        self.frameworks.iter_mut().for_each(Property::update_id);
+        self.people.iter_mut().for_each(Property::update_id);
 
         Ok(metadata
             .update()
@@ -65,9 +89,14 @@ impl MetadataDco {
             .categories(self.categories)
             .languages(self.languages)
             .build_systems(self.build_systems)
+            .licenses(self.licenses)
+            .frameworks(self.frameworks)
+            .people(self.people)
+            .subprojects(self.subprojects)
             .update_ide(self.preferred_ide)
             .update_description(self.description)
-            .update_repository_url(self.repository_url)
+            .links(self.links)
+            .aliases(aliases)
             .build()?)
     }
 }
@@ -75,7 +104,12 @@ impl MetadataDco {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use episko_lib::metadata::{BuildSystem, Category, Ide, Language, Metadata};
+    use episko_lib::{
+        config::DEFAULT_MANIFEST_FILE_NAME,
+        metadata::{
+            BuildSystem, Category, Framework, Ide, Language, License, LinkKind, Metadata, Person,
+        },
+    };
     use std::path::PathBuf;
 
     #[test]
@@ -94,11 +128,18 @@ mod tests {
             languages: vec![language],
             build_systems: vec![build_system],
             preferred_ide: Some(ide),
-            repository_url: Some(String::from("https://github.com/test/project")),
+            licenses: vec![License::new("mit")],
+            frameworks: vec![Framework::new("Svelte")],
+            people: vec![Person::new("Jane Doe")],
+            links: vec![Link::new(
+                LinkKind::Repository,
+                "https://github.com/test/project",
+            )],
+            subprojects: vec![],
         };
 
         // Act
-        let result = dco.create();
+        let result = dco.create(DEFAULT_MANIFEST_FILE_NAME, &Aliases::default());
 
         // Assert
         assert!(result.is_ok());
@@ -112,9 +153,12 @@ mod tests {
         assert_eq!(metadata.languages[0].name, "Rust");
         assert_eq!(metadata.build_systems[0].name, "Cargo");
         assert_eq!(metadata.preferred_ide.unwrap().name, "Neovim");
+        assert_eq!(metadata.licenses[0].name, "MIT");
+        assert_eq!(metadata.frameworks[0].name, "Svelte");
+        assert_eq!(metadata.people[0].name, "Jane Doe");
         assert_eq!(
-            metadata.repository_url,
-            Some("https://github.com/test/project".to_string())
+            metadata.repository_url(),
+            Some("https://github.com/test/project")
         );
     }
 
@@ -142,10 +186,17 @@ mod tests {
             languages: vec![language],
             build_systems: vec![build_system],
             preferred_ide: Some(ide),
-            repository_url: Some(String::from("https://github.com/updated/project")),
+            licenses: vec![],
+            frameworks: vec![],
+            people: vec![],
+            links: vec![Link::new(
+                LinkKind::Repository,
+                "https://github.com/updated/project",
+            )],
+            subprojects: vec![String::from("packages/*")],
         };
 
-        let result = dco.update(existing_metadata);
+        let result = dco.update(existing_metadata, &Aliases::default());
 
         assert!(result.is_ok());
         let updated_metadata = result.unwrap();
@@ -160,8 +211,9 @@ mod tests {
         assert_eq!(updated_metadata.build_systems[0].name, "Cargo");
         assert_eq!(updated_metadata.preferred_ide.unwrap().name, "Neovim");
         assert_eq!(
-            updated_metadata.repository_url,
-            Some("https://github.com/updated/project".to_string())
+            updated_metadata.repository_url(),
+            Some("https://github.com/updated/project")
         );
+        assert_eq!(updated_metadata.subprojects, vec!["packages/*".to_string()]);
     }
 }
@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
-use episko_lib::metadata::{BuildSystem, Category, Ide, Language, Metadata};
+use episko_lib::metadata::{
+    BuildSystem, Category, Framework, Ide, Language, License, Link, Metadata, Person,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -16,7 +18,16 @@ pub struct MetadataDto {
     languages: Vec<Language>,
     build_systems: Vec<BuildSystem>,
     preferred_ide: Option<Ide>,
-    repository_url: Option<String>,
+    #[serde(default)]
+    licenses: Vec<License>,
+    #[serde(default)]
+    frameworks: Vec<Framework>,
+    #[serde(default)]
+    people: Vec<Person>,
+    #[serde(default)]
+    links: Vec<Link>,
+    subprojects: Vec<String>,
+    parent: Option<Uuid>,
     created: DateTime<Utc>,
     updated: DateTime<Utc>,
 }
@@ -32,7 +43,12 @@ impl From<Metadata> for MetadataDto {
             languages: metadata.languages,
             build_systems: metadata.build_systems,
             preferred_ide: metadata.preferred_ide,
-            repository_url: metadata.repository_url,
+            licenses: metadata.licenses,
+            frameworks: metadata.frameworks,
+            people: metadata.people,
+            links: metadata.links,
+            subprojects: metadata.subprojects,
+            parent: metadata.parent,
             created: metadata.created,
             updated: metadata.updated,
         }
@@ -50,7 +66,12 @@ impl From<MetadataDto> for Metadata {
             languages: val.languages,
             build_systems: val.build_systems,
             preferred_ide: val.preferred_ide,
-            repository_url: val.repository_url,
+            licenses: val.licenses,
+            frameworks: val.frameworks,
+            people: val.people,
+            links: val.links,
+            subprojects: val.subprojects,
+            parent: val.parent,
             created: val.created,
             updated: val.updated,
         }
@@ -62,7 +83,8 @@ mod tests {
     use super::*;
     use chrono::Utc;
     use episko_lib::metadata::{
-        property::Property as _, BuildSystem, Category, Ide, Language, Metadata,
+        property::Property as _, BuildSystem, Category, Framework, Ide, Language, License,
+        LinkKind, Metadata, Person,
     };
     use uuid::Uuid;
 
@@ -85,7 +107,15 @@ mod tests {
             languages: vec![language],
             build_systems: vec![build_system],
             preferred_ide: Some(ide),
-            repository_url: Some(String::from("https://github.com/test/project")),
+            licenses: vec![License::new("MIT")],
+            frameworks: vec![Framework::with_version("Tauri", "2")],
+            people: vec![Person::new("Jane Doe").with_role("maintainer")],
+            links: vec![Link::new(
+                LinkKind::Repository,
+                "https://github.com/test/project",
+            )],
+            subprojects: vec![String::from("packages/*")],
+            parent: None,
             created,
             updated,
         };
@@ -105,7 +135,15 @@ mod tests {
             languages: vec![language],
             build_systems: vec![build_system],
             preferred_ide: Some(ide),
-            repository_url: Some(String::from("https://github.com/test/project")),
+            licenses: vec![License::new("MIT")],
+            frameworks: vec![Framework::with_version("Tauri", "2")],
+            people: vec![Person::new("Jane Doe").with_role("maintainer")],
+            links: vec![Link::new(
+                LinkKind::Repository,
+                "https://github.com/test/project",
+            )],
+            subprojects: vec![String::from("packages/*")],
+            parent: None,
             created,
             updated,
         }
@@ -118,7 +156,12 @@ mod tests {
         assert_eq!(metadata.languages, metadata_dto.languages);
         assert_eq!(metadata.build_systems, metadata_dto.build_systems);
         assert_eq!(metadata.preferred_ide, metadata_dto.preferred_ide);
-        assert_eq!(metadata.repository_url, metadata_dto.repository_url);
+        assert_eq!(metadata.licenses, metadata_dto.licenses);
+        assert_eq!(metadata.frameworks, metadata_dto.frameworks);
+        assert_eq!(metadata.people, metadata_dto.people);
+        assert_eq!(metadata.links, metadata_dto.links);
+        assert_eq!(metadata.subprojects, metadata_dto.subprojects);
+        assert_eq!(metadata.parent, metadata_dto.parent);
         assert_eq!(metadata.created, metadata_dto.created);
         assert_eq!(metadata.updated, metadata_dto.updated);
     }
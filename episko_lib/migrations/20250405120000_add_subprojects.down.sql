@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_metadata_parent_id;
+ALTER TABLE metadata DROP COLUMN subprojects;
+ALTER TABLE metadata DROP COLUMN parent_id;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+-- No foreign key on parent_id, as parents and sub-projects may be cached in any order.
+ALTER TABLE metadata ADD COLUMN parent_id TEXT;
+ALTER TABLE metadata ADD COLUMN subprojects TEXT NOT NULL DEFAULT '[]';
+
+CREATE INDEX IF NOT EXISTS idx_metadata_parent_id ON metadata (parent_id);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS rel_metadata_license;
+DROP TABLE IF EXISTS license;
@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS license (
+  id BLOB PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE IF NOT EXISTS rel_metadata_license (
+  metadata_id BLOB NOT NULL,
+  license_id BLOB NOT NULL,
+  PRIMARY KEY (metadata_id, license_id),
+  FOREIGN KEY (metadata_id) REFERENCES metadata (id) ON DELETE CASCADE,
+  FOREIGN KEY (license_id) REFERENCES license (id) ON DELETE CASCADE
+);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS rel_metadata_person;
+DROP TABLE IF EXISTS rel_metadata_framework;
+DROP TABLE IF EXISTS person;
+DROP TABLE IF EXISTS framework;
@@ -0,0 +1,31 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS framework (
+  id BLOB PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL,
+  version TEXT,
+  -- Theoretically not needed as the id hash ensures uniqueness
+  CONSTRAINT unq UNIQUE (name, version)
+);
+
+CREATE TABLE IF NOT EXISTS person (
+  id BLOB PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL,
+  email TEXT,
+  role TEXT
+);
+
+CREATE TABLE IF NOT EXISTS rel_metadata_framework (
+  metadata_id BLOB NOT NULL,
+  framework_id BLOB NOT NULL,
+  PRIMARY KEY (metadata_id, framework_id),
+  FOREIGN KEY (metadata_id) REFERENCES metadata (id) ON DELETE CASCADE,
+  FOREIGN KEY (framework_id) REFERENCES framework (id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS rel_metadata_person (
+  metadata_id BLOB NOT NULL,
+  person_id BLOB NOT NULL,
+  PRIMARY KEY (metadata_id, person_id),
+  FOREIGN KEY (metadata_id) REFERENCES metadata (id) ON DELETE CASCADE,
+  FOREIGN KEY (person_id) REFERENCES person (id) ON DELETE CASCADE
+);
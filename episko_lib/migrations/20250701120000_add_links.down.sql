@@ -0,0 +1,12 @@
+-- Add down migration script here
+ALTER TABLE metadata ADD COLUMN repository_url TEXT;
+
+UPDATE metadata
+SET repository_url = (
+  SELECT url FROM link
+  WHERE link.metadata_id = metadata.id AND link.kind = 'repository'
+  ORDER BY link.position
+  LIMIT 1
+);
+
+DROP TABLE IF EXISTS link;
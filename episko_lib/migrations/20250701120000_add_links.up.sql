@@ -0,0 +1,41 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS link (
+  metadata_id TEXT NOT NULL,
+  position INTEGER NOT NULL,
+  kind TEXT NOT NULL,
+  url TEXT NOT NULL,
+  label TEXT,
+  PRIMARY KEY (metadata_id, position),
+  FOREIGN KEY (metadata_id) REFERENCES metadata (id) ON DELETE CASCADE
+);
+
+-- Links replace the single repository url. As it wasn't validated, urls
+-- lacking a scheme are completed the same way as by `complete_url`.
+INSERT INTO link (metadata_id, position, kind, url)
+SELECT id, 0, 'repository', CASE
+    WHEN url LIKE '%://%' OR substr(url, 1, 1) IN ('/', '.', '~') THEN url
+    WHEN instr(url, ':') = 0 THEN 'https://' || url
+    WHEN instr(host, '/') = 0 AND substr(rest, 1, 1) != '/' THEN CASE
+        -- hosts with a port, e.g. example.com:8080/repo
+        WHEN port != '' AND port NOT GLOB '*[^0-9]*' THEN 'https://' || url
+        -- scp-like git remotes, e.g. git@github.com:user/repo.git
+        ELSE 'ssh://' || host || '/' || rest
+      END
+    ELSE url
+  END
+FROM (
+  SELECT id, url, host, rest,
+    CASE WHEN instr(rest, '/') > 0 THEN substr(rest, 1, instr(rest, '/') - 1) ELSE rest END AS port
+  FROM (
+    SELECT id, url,
+      substr(url, 1, instr(url, ':') - 1) AS host,
+      substr(url, instr(url, ':') + 1) AS rest
+    FROM (
+      SELECT id, trim(repository_url) AS url
+      FROM metadata
+      WHERE repository_url IS NOT NULL AND trim(repository_url) != ''
+    )
+  )
+);
+
+ALTER TABLE metadata DROP COLUMN repository_url;
@@ -2,35 +2,93 @@
 //! used by the gui and cli application.
 //!
 //! # Config file
-//! The config file is used to define the path of the cache database and
-//! which directories and files should be loaded by the gui application.
+//! The config file is used to define the path of the cache database,
+//! which directories and files should be loaded by the gui application,
+//! optionally separated into named profiles (see [`Profile`]),
+//! how manifests are named and discovered (see [`Discovery`]), which
+//! minimum versions projects are expected to use (see [`VersionPolicy`])
+//! and which aliases are used for property names (see [`Aliases`]).
 //!
 //! It can be edited manually, using the cli or using the gui.
 //!
-//! By default it is placed as defined in [`ConfigHandler::get_config_dir`].
+//! By default it is placed as defined in [`ConfigHandler::default_config_file`].
+//!
+//! # Overrides
+//! The config file, profile and database can be selected without changing
+//! the config, in order of precedence:
+//! - config file: an explicit path, e.g. `--config` of the cli,
+//!   then [`CONFIG_VAR`], then the default location
+//! - profile: an explicit name, e.g. `--profile` of the cli,
+//!   then [`PROFILE_VAR`], then the default profile of the config
+//! - database: an explicit path, e.g. `--db` of the cli,
+//!   then [`DATABASE_VAR`], then the database of the selected profile
+//!
+//! The format of the file is versioned, older configs are migrated when
+//! loaded, see [`migration`].
 //!
 //! The serialiaztion and deserialization of the file is done using
 //! [`serde`] and the [`files::File`] trait.
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, env, fs, io, path::PathBuf};
+use std::{collections::BTreeMap, env, fs, io, path::PathBuf};
 use thiserror::Error;
 
 use crate::files;
 
+pub mod aliases;
 pub mod config_handler;
+pub mod discovery;
+pub mod migration;
+pub mod profile;
+pub mod validation;
+pub mod version_policy;
+pub use aliases::Aliases;
 pub use config_handler::ConfigHandler;
+pub use discovery::{Discovery, DEFAULT_MANIFEST_FILE_NAME};
+pub use migration::CONFIG_VERSION;
+pub use profile::{Profile, DEFAULT_PROFILE, PROFILE_VAR};
+pub use version_policy::VersionPolicy;
 
 pub static DIR_NAME: &str = "episko";
 pub static DB_FILE_NAME: &str = "cache.db";
 pub static CONFIG_FILE_NAME: &str = "config.toml";
 
+/// Environmental variable used to select the config file.
+pub static CONFIG_VAR: &str = "EPISKO_CONFIG";
+/// Environmental variable used to select the database.
+pub static DATABASE_VAR: &str = "EPISKO_DB";
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
-    pub database_path: PathBuf,
-    pub directories_to_load: HashSet<PathBuf>,
-    pub files_to_load: HashSet<PathBuf>,
+    /// Version of the config format, see [`CONFIG_VERSION`].
+    /// Configs written before the format was versioned lack it.
+    #[serde(default)]
+    pub version: u32,
+    /// The default profile, see [`DEFAULT_PROFILE`].
+    #[serde(flatten)]
+    pub base: Profile,
+    /// Profile used when none is selected explicitly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    pub discovery: Discovery,
+    #[serde(default)]
+    pub version_policy: VersionPolicy,
+    #[serde(default)]
+    pub aliases: Aliases,
+    /// Keys unknown to this version, e.g. written by a newer one,
+    /// which are kept when saving the config.
+    #[serde(flatten)]
+    pub unknown: toml::Table,
+    /// Name of the selected profile, the default profile if unset.
+    #[serde(skip)]
+    active_profile: Option<String>,
+    /// Database used instead of the one of the selected profile.
+    #[serde(skip)]
+    database_override: Option<PathBuf>,
 }
 
 impl Config {
@@ -50,9 +108,16 @@ impl Config {
         }
 
         Ok(Self {
-            database_path,
-            directories_to_load: HashSet::default(),
-            files_to_load: HashSet::default(),
+            version: CONFIG_VERSION,
+            base: Profile::new(&database_path),
+            default_profile: None,
+            profiles: BTreeMap::default(),
+            discovery: Discovery::default(),
+            version_policy: VersionPolicy::default(),
+            aliases: Aliases::default(),
+            unknown: toml::Table::default(),
+            active_profile: None,
+            database_override: None,
         })
     }
 
@@ -110,4 +175,16 @@ pub enum Error {
 
     #[error("failed to interact with file")]
     File(#[from] files::Error),
+
+    #[error("database {0} already exists")]
+    DatabaseExists(String),
+
+    #[error("unknown profile: {0}")]
+    UnknownProfile(String),
+
+    #[error("profile {0} already exists")]
+    ProfileExists(String),
+
+    #[error("invalid config: {}", .0.join("; "))]
+    Invalid(Vec<String>),
 }
@@ -0,0 +1,206 @@
+//! Submodule of [`crate::config`] for the [`Aliases`] settings.
+use std::{collections::BTreeMap, sync::LazyLock};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::metadata::property::PropertyKind;
+
+/// Built-in language aliases as `(alias, canonical name)`.
+static LANGUAGES: &[(&str, &str)] = &[
+    ("c#", "C#"),
+    ("c++", "C++"),
+    ("cpp", "C++"),
+    ("csharp", "C#"),
+    ("golang", "Go"),
+    ("js", "JavaScript"),
+    ("kt", "Kotlin"),
+    ("py", "Python"),
+    ("rb", "Ruby"),
+    ("rs", "Rust"),
+    ("ts", "TypeScript"),
+];
+
+/// Built-in build system aliases as `(alias, canonical name)`.
+static BUILD_SYSTEMS: &[(&str, &str)] = &[("gmake", "Make"), ("ninja-build", "Ninja")];
+
+/// Built-in IDE aliases as `(alias, canonical name)`.
+static IDES: &[(&str, &str)] = &[
+    ("code", "VSCode"),
+    ("nvim", "Neovim"),
+    ("vs code", "VSCode"),
+    ("vscode", "VSCode"),
+];
+
+/// Built-in framework aliases as `(alias, canonical name)`.
+static FRAMEWORKS: &[(&str, &str)] = &[
+    ("nextjs", "Next.js"),
+    ("rails", "Ruby on Rails"),
+    ("react.js", "React"),
+    ("reactjs", "React"),
+    ("vue.js", "Vue"),
+    ("vuejs", "Vue"),
+];
+
+/// The built-in aliases, used for names without a configured alias.
+static BUILTIN: LazyLock<Aliases> = LazyLock::new(|| {
+    let table = |aliases: &[(&str, &str)]| {
+        aliases
+            .iter()
+            .map(|(alias, name)| ((*alias).to_string(), (*name).to_string()))
+            .collect()
+    };
+
+    Aliases {
+        categories: BTreeMap::new(),
+        languages: table(LANGUAGES),
+        build_systems: table(BUILD_SYSTEMS),
+        ides: table(IDES),
+        licenses: BTreeMap::new(),
+        frameworks: table(FRAMEWORKS),
+        people: BTreeMap::new(),
+    }
+});
+
+/// Aliases of property names, mapping an alias to its canonical name,
+/// e.g. `TS` to `TypeScript`.
+///
+/// Aliases are matched case-insensitively, so they are stored in lowercase.
+/// The aliases given in the config are applied in addition to the built-in
+/// ones, overriding them if necessary.
+///
+/// ```toml
+/// [aliases.languages]
+/// "ecmascript" = "JavaScript"
+/// ```
+///
+/// Common deviations from SPDX license identifiers are resolved without
+/// aliases, see [`crate::metadata::License`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Aliases {
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub categories: BTreeMap<String, String>,
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub languages: BTreeMap<String, String>,
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub build_systems: BTreeMap<String, String>,
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub ides: BTreeMap<String, String>,
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub licenses: BTreeMap<String, String>,
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub frameworks: BTreeMap<String, String>,
+    #[serde(deserialize_with = "lowercase_keys")]
+    pub people: BTreeMap<String, String>,
+}
+
+impl Aliases {
+    /// Add an alias for the given kind of property.
+    pub fn insert(&mut self, kind: PropertyKind, alias: &str, name: &str) {
+        self.table_mut(kind)
+            .insert(alias.trim().to_lowercase(), name.to_string());
+    }
+
+    /// Canonical name for the given name, if it is an alias.
+    ///
+    /// The configured aliases take precedence over the built-in ones.
+    #[must_use]
+    pub fn resolve(&self, kind: PropertyKind, name: &str) -> Option<&str> {
+        let name = name.trim().to_lowercase();
+
+        self.table(kind)
+            .get(&name)
+            .or_else(|| BUILTIN.table(kind).get(&name))
+            .map(String::as_str)
+    }
+
+    /// Canonical name for the given name of a property.
+    ///
+    /// Returns the name itself if it isn't an alias.
+    #[must_use]
+    pub fn canonical_name(&self, kind: PropertyKind, name: &str) -> String {
+        self.resolve(kind, name)
+            .map_or_else(|| name.to_string(), ToString::to_string)
+    }
+
+    /// Aliases of the given kind of property.
+    #[must_use]
+    pub fn table(&self, kind: PropertyKind) -> &BTreeMap<String, String> {
+        match kind {
+            PropertyKind::Category => &self.categories,
+            PropertyKind::Language => &self.languages,
+            PropertyKind::BuildSystem => &self.build_systems,
+            PropertyKind::Ide => &self.ides,
+            PropertyKind::License => &self.licenses,
+            PropertyKind::Framework => &self.frameworks,
+            PropertyKind::Person => &self.people,
+        }
+    }
+
+    fn table_mut(&mut self, kind: PropertyKind) -> &mut BTreeMap<String, String> {
+        match kind {
+            PropertyKind::Category => &mut self.categories,
+            PropertyKind::Language => &mut self.languages,
+            PropertyKind::BuildSystem => &mut self.build_systems,
+            PropertyKind::Ide => &mut self.ides,
+            PropertyKind::License => &mut self.licenses,
+            PropertyKind::Framework => &mut self.frameworks,
+            PropertyKind::Person => &mut self.people,
+        }
+    }
+}
+
+/// Deserialize a table of aliases, storing the aliases in lowercase.
+fn lowercase_keys<'de, D>(deserializer: D) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(BTreeMap::<String, String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(alias, name)| (alias.trim().to_lowercase(), name))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let aliases = Aliases::default();
+
+        assert_eq!(
+            aliases.resolve(PropertyKind::Language, "TS"),
+            Some("TypeScript")
+        );
+        assert_eq!(aliases.resolve(PropertyKind::Language, "Haskell"), None);
+        assert_eq!(aliases.resolve(PropertyKind::Category, "ts"), None);
+    }
+
+    #[test]
+    fn test_configured_aliases() {
+        let aliases: Aliases = toml::from_str(
+            r#"
+            [languages]
+            TS = "TypeScript 5"
+
+            [categories]
+            " Uni " = "University"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            aliases.resolve(PropertyKind::Language, "ts"),
+            Some("TypeScript 5")
+        );
+        assert_eq!(
+            aliases.resolve(PropertyKind::Language, "py"),
+            Some("Python")
+        );
+        assert_eq!(
+            aliases.resolve(PropertyKind::Category, "UNI"),
+            Some("University")
+        );
+    }
+}
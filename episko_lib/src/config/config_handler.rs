@@ -1,61 +1,289 @@
 use std::collections::HashSet;
 use std::path::Path;
-use std::{env, fs, path::PathBuf};
+use std::{env, fs, io, path::PathBuf};
 
-use crate::{config::CONFIG_FILE_NAME, files::File};
+use crate::files::File;
 
-use super::{Config, Error, Result, DIR_NAME};
+use super::{
+    Aliases, Config, Discovery, Error, Profile, Result, CONFIG_FILE_NAME, CONFIG_VAR, DATABASE_VAR,
+    DEFAULT_PROFILE, DIR_NAME, PROFILE_VAR,
+};
+
+/// Suffix of the backup of the previous config, written when saving.
+pub static BACKUP_SUFFIX: &str = ".bak";
+
+/// Profile and database given explicitly, e.g. on the command line,
+/// taking precedence over [`PROFILE_VAR`] and [`DATABASE_VAR`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides {
+    pub profile: Option<String>,
+    pub database: Option<PathBuf>,
+}
 
 /// The [`ConfigHandler`] is used to load and save the [`Config`] object
-/// to a file as defined in [`ConfigHandler::config_path`].
+/// to a file as defined in [`ConfigHandler::config_file`].
 pub struct ConfigHandler {
-    config_path: PathBuf,
+    config_file: PathBuf,
     config: Config,
+    warnings: Vec<String>,
 }
 
 impl ConfigHandler {
-    /// Create a new [`ConfigHandler`].
+    /// Create a new [`ConfigHandler`] using the config file found as
+    /// described in [`ConfigHandler::default_config_file`].
     ///
     /// # Errors
-    /// - [`Error::Io`] when creating the config directory fails
-    /// - Propogates errors from [`ConfigHandler::get_config_dir`].
+    /// - Propogates errors from [`ConfigHandler::default_config_file`].
+    /// - Propogates errors from [`ConfigHandler::load_from`].
     pub fn load() -> Result<Self> {
-        let config_path = Self::get_config_dir()?;
-        if !config_path.exists() {
-            fs::create_dir_all(&config_path)?;
+        Self::load_from(&Self::default_config_file()?)
+    }
+
+    /// Create a new [`ConfigHandler`] using the given config file.
+    /// If the file doesn't exist, a default config is created.
+    ///
+    /// The profile named by [`PROFILE_VAR`] is selected if set,
+    /// otherwise the default profile of the config. If the default
+    /// profile of the config doesn't exist, the [`DEFAULT_PROFILE`] is
+    /// used and a warning is added, see [`ConfigHandler::warnings`].
+    /// The database given by [`DATABASE_VAR`] is used instead of the one
+    /// of the profile if set, see [`Config::override_database`].
+    ///
+    /// # Errors
+    /// - [`Error::Io`] when creating the config directory fails
+    /// - [`Error::UnknownProfile`] when the profile named by [`PROFILE_VAR`]
+    ///   doesn't exist
+    /// - Propogates errors from [`Config::try_default`]
+    /// - Propogates errors from [`Config::from_file`]
+    pub fn load_from(config_file: &Path) -> Result<Self> {
+        Self::load_with(config_file, &Overrides::default())
+    }
+
+    /// Create a new [`ConfigHandler`] using the given config file, as
+    /// described in [`ConfigHandler::load_from`].
+    ///
+    /// The given [`Overrides`] take precedence over the environment, so
+    /// the profile named by [`PROFILE_VAR`] isn't even looked up if a
+    /// profile is given.
+    ///
+    /// # Errors
+    /// - [`Error::UnknownProfile`] when the given profile, or the one named
+    ///   by [`PROFILE_VAR`], doesn't exist
+    /// - Propogates errors from [`ConfigHandler::load_from`]
+    pub fn load_with(config_file: &Path, overrides: &Overrides) -> Result<Self> {
+        if let Some(config_dir) = config_file.parent()
+            && !config_dir.as_os_str().is_empty()
+            && !config_dir.exists()
+        {
+            fs::create_dir_all(config_dir)?;
         }
 
-        let config = ConfigHandler::load_config(&config_path)?;
+        let mut config = ConfigHandler::load_config(config_file)?;
+
+        // Explicitly selected profiles take precedence over the default one
+        let mut warnings = vec![];
+        let profile = overrides
+            .profile
+            .clone()
+            .or_else(|| non_empty_var(PROFILE_VAR));
+        if let Some(name) = profile {
+            config.select_profile(&name)?;
+        } else if let Some(name) = config.default_profile.clone()
+            && config.select_profile(&name).is_err()
+        {
+            warnings.push(format!(
+                "unknown default profile '{name}', using profile '{DEFAULT_PROFILE}' instead"
+            ));
+        }
+        let database = overrides
+            .database
+            .clone()
+            .or_else(|| non_empty_var(DATABASE_VAR).map(PathBuf::from));
+        if let Some(database) = database {
+            config.override_database(&database);
+        }
 
         Ok(Self {
-            config_path,
+            config_file: config_file.to_path_buf(),
             config,
+            warnings,
         })
     }
 
+    /// Read the [`Discovery`] settings of the given config file without
+    /// creating or changing any files, e.g. for read-only commands.
+    /// The default settings are used if the config doesn't exist yet.
+    ///
+    /// # Errors
+    /// - Propogates errors from [`Config::from_file`] if the config exists
+    pub fn read_discovery(config_file: &Path) -> Result<Discovery> {
+        if !config_file.exists() {
+            return Ok(Discovery::default());
+        }
+
+        Ok(Config::from_file(config_file)?.discovery)
+    }
+
+    /// Read the [`Aliases`] of the given config file without creating
+    /// or changing any files, see [`ConfigHandler::read_discovery`].
+    /// Only the built-in aliases are used if the config doesn't exist yet.
+    ///
+    /// # Errors
+    /// - Propogates errors from [`Config::from_file`] if the config exists
+    pub fn read_aliases(config_file: &Path) -> Result<Aliases> {
+        if !config_file.exists() {
+            return Ok(Aliases::default());
+        }
+
+        Ok(Config::from_file(config_file)?.aliases)
+    }
+
+    /// Retrieve the config file to use if none is given explicitly,
+    /// in order of precedence:
+    /// - the file given by [`CONFIG_VAR`]
+    /// - [`CONFIG_FILE_NAME`] within the directory described in
+    ///   [`ConfigHandler::get_config_dir`]
+    ///
+    /// # Errors
+    /// - Propogates errors from [`ConfigHandler::get_config_dir`].
+    pub fn default_config_file() -> Result<PathBuf> {
+        match non_empty_var(CONFIG_VAR) {
+            Some(config_file) => Ok(PathBuf::from(config_file)),
+            None => Ok(Self::get_config_dir()?.join(CONFIG_FILE_NAME)),
+        }
+    }
+
     #[must_use]
     pub fn config(&self) -> &Config {
         &self.config
     }
 
+    /// Problems found when loading the config which didn't prevent it
+    /// from being loaded, e.g. an unknown default profile.
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     #[must_use]
     pub fn files(&self) -> &HashSet<PathBuf> {
-        &self.config.files_to_load
+        &self.config.profile().files_to_load
     }
 
     #[must_use]
     pub fn dirs(&self) -> &HashSet<PathBuf> {
-        &self.config.directories_to_load
+        &self.config.profile().directories_to_load
     }
 
-    /// Save the current config to the path saved in the receiver instance.
+    /// Use the profile with the given name from now on.
+    ///
+    /// # Errors
+    /// - [`Error::UnknownProfile`] when no profile with the given name exists
+    pub fn select_profile(&mut self, name: &str) -> Result<()> {
+        self.config.select_profile(name)
+    }
+
+    /// Add an empty profile with the given name.
+    ///
+    /// Without a database path, the database is placed next to the one
+    /// of the default profile and named after the profile.
+    ///
+    /// # Errors
+    /// - [`Error::ProfileExists`] when a profile with the given name exists
+    /// - [`Error::Invalid`] when the name is empty
+    pub fn add_profile(&mut self, name: &str, database_path: Option<&Path>) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(Error::Invalid(vec![
+                "profile name can't be empty".to_string()
+            ]));
+        }
+
+        let database_path = database_path.map_or_else(
+            || {
+                self.config
+                    .base
+                    .database_path
+                    .with_file_name(format!("{name}.db"))
+            },
+            Path::to_path_buf,
+        );
+
+        self.config.add_profile(name, Profile::new(&database_path))
+    }
+
+    /// Remove the profile with the given name, see [`Config::remove_profile`].
+    ///
+    /// Returns `false` when the profile can't be removed, implying it
+    /// doesn't exist or is the default profile.
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        self.config.remove_profile(name)
+    }
+
+    /// Use the profile with the given name when none is selected explicitly.
+    ///
+    /// # Errors
+    /// - [`Error::UnknownProfile`] when no profile with the given name exists
+    pub fn set_default_profile(&mut self, name: &str) -> Result<()> {
+        if !self.config.has_profile(name) {
+            return Err(Error::UnknownProfile(name.to_string()));
+        }
+
+        self.config.default_profile = (name != DEFAULT_PROFILE).then(|| name.to_string());
+        Ok(())
+    }
+
+    /// Path of the config file used by the receiver instance.
+    #[must_use]
+    pub fn config_file(&self) -> &Path {
+        &self.config_file
+    }
+
+    /// Use the given database instead of the one of the active profile,
+    /// without saving it, see [`Config::override_database`].
+    pub fn override_database(&mut self, path: &Path) {
+        self.config.override_database(path);
+    }
+
+    /// Use the database at the given path from now on.
+    ///
+    /// If `migrate` is set, the existing database is moved to the given
+    /// path, otherwise a new database is created on the next connection.
+    ///
+    /// # Errors
+    /// - [`Error::DatabaseExists`] when migrating onto an existing database
+    /// - [`Error::Io`] when moving the database fails
+    pub fn set_database_path(&mut self, path: &Path, migrate: bool) -> Result<()> {
+        let current = &self.config.profile().database_path;
+
+        if migrate && current != path && current.exists() {
+            if path.exists() {
+                return Err(Error::DatabaseExists(path.display().to_string()));
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            move_file(current, path)?;
+            // Write-ahead log files of sqlite belong to the database as well
+            for suffix in ["-wal", "-shm"] {
+                let file = with_suffix(current, suffix);
+                if file.exists() {
+                    move_file(&file, &with_suffix(path, suffix))?;
+                }
+            }
+        }
+
+        self.config.profile_mut().database_path = path.to_path_buf();
+        Ok(())
+    }
+
+    /// Save the current config to the path saved in the receiver instance,
+    /// keeping the previous config as a backup, see [`BACKUP_SUFFIX`].
     ///
     /// # Errors
     /// - If the config file cannot be written
     pub fn save_config(&self) -> Result<()> {
-        let config_file = self.config_path.join(CONFIG_FILE_NAME);
-
-        Ok(self.config.write_file(&config_file)?)
+        Self::write_config(&self.config, &self.config_file)
     }
 
     /// Add a file to the config which will automatically be loaded
@@ -63,7 +291,10 @@ impl ConfigHandler {
     ///
     /// Duplicate entries will be ignored.
     pub fn add_saved_file(&mut self, file: &Path) {
-        self.config.files_to_load.insert(file.to_path_buf());
+        self.config
+            .profile_mut()
+            .files_to_load
+            .insert(file.to_path_buf());
     }
 
     /// Remove a file from the config, which will no longer be automatically
@@ -72,7 +303,10 @@ impl ConfigHandler {
     /// Returns `false` when the entry can't be removed, implying it doesn't
     /// exist.
     pub fn remove_saved_file(&mut self, file: &Path) -> bool {
-        self.config.files_to_load.remove(&file.to_path_buf())
+        self.config
+            .profile_mut()
+            .files_to_load
+            .remove(&file.to_path_buf())
     }
 
     /// Add a directory to the config, whose underlying manifest files will automatically be loaded
@@ -80,7 +314,10 @@ impl ConfigHandler {
     ///
     /// Duplicate entries will be ignored.
     pub fn add_saved_directory(&mut self, dir: &Path) {
-        self.config.directories_to_load.insert(dir.to_path_buf());
+        self.config
+            .profile_mut()
+            .directories_to_load
+            .insert(dir.to_path_buf());
     }
 
     /// Remove a directory from the config, which will no longer be automatically
@@ -89,33 +326,56 @@ impl ConfigHandler {
     /// Returns `false` when the entry can't be removed, implying it doesn't
     /// exist.
     pub fn remove_saved_directory(&mut self, dir: &Path) -> bool {
-        self.config.directories_to_load.remove(&dir.to_path_buf())
+        self.config
+            .profile_mut()
+            .directories_to_load
+            .remove(&dir.to_path_buf())
     }
 
-    /// Load a config from the path saved in the receiver instance.
+    /// Load a config from the given file.
     /// If no config file exists a default will be created.
     ///
+    /// Outdated configs are migrated and configs lacking database paths
+    /// are completed, rewriting the file after backing it up.
+    ///
     /// # Errors
     /// - Propogates errors from [`Config::try_default`]
     /// - Propogates errors from [`Config::from_file`]
-    fn load_config(config_path: &Path) -> Result<Config> {
-        let config_file = config_path.join(CONFIG_FILE_NAME);
-
-        let config = if config_file.exists() {
-            Config::from_file(&config_file)?
-        } else {
+    /// - Propogates errors from [`Config::fill_defaults`]
+    fn load_config(config_file: &Path) -> Result<Config> {
+        if !config_file.exists() {
             let config = Config::try_default()?;
-            config.write_file(&config_file)?;
-            config
-        };
+            config.write_file(config_file)?;
+            return Ok(config);
+        }
+
+        let mut config = Config::from_file(config_file)?;
+        let migrated = config.migrate();
+        if config.fill_defaults()? || migrated {
+            Self::write_config(&config, config_file)?;
+        }
 
         Ok(config)
     }
 
+    /// Write the config to the given file, keeping the previous
+    /// content as a backup, see [`BACKUP_SUFFIX`].
+    ///
+    /// # Errors
+    /// - [`Error::Io`] when the backup can't be written
+    /// - Propogates errors from [`Config::write_file`]
+    fn write_config(config: &Config, config_file: &Path) -> Result<()> {
+        if config_file.exists() {
+            fs::copy(config_file, with_suffix(config_file, BACKUP_SUFFIX))?;
+        }
+
+        Ok(config.write_file(config_file)?)
+    }
+
     /// Retrieve the default directory for the config location.
     ///
     /// On Unix-like systems the directory is located at:
-    /// - `$XDG_CONFIG_DIR/episko` if `$XDG_CONFIG_DIR` is set
+    /// - `$XDG_CONFIG_HOME/episko` if `$XDG_CONFIG_HOME` is set
     /// - `$HOME/.config/episko` otherwise
     ///
     /// On Windows systems the config directory is located at:
@@ -127,15 +387,11 @@ impl ConfigHandler {
     fn get_config_dir() -> Result<PathBuf> {
         #[cfg(unix)]
         {
-            env::var("XDG_CONFIG_DIR")
+            non_empty_var("XDG_CONFIG_HOME")
                 .map(PathBuf::from)
+                .or_else(|| non_empty_var("HOME").map(|p| PathBuf::from(p).join(".config")))
                 .map(|p| p.join(DIR_NAME))
-                .or_else(|_| {
-                    env::var("HOME")
-                        .map(PathBuf::from)
-                        .map(|p| p.join(".config").join(DIR_NAME))
-                })
-                .map_err(|_| Error::Directory)
+                .ok_or(Error::Directory)
         }
 
         #[cfg(windows)]
@@ -151,18 +407,189 @@ impl ConfigHandler {
             Err(Error::UnknownOs(env::consts::OS.to_string()))
         }
     }
+}
 
-    /// for tests only
-    #[doc(hidden)]
-    #[must_use]
-    pub fn in_place() -> Self {
-        let config_path = PathBuf::from(".");
+/// Read the given environmental variable, ignoring it if empty.
+fn non_empty_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
 
-        let config = Self::load_config(&config_path).expect("load config for test");
+/// Move a file, copying it if it can't be renamed, e.g. across file systems.
+fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    fs::copy(from, to)?;
+    fs::remove_file(from)
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::CONFIG_VERSION, files::files_test::temp_dir};
 
-        Self {
-            config_path,
+    fn handler(dir: &Path) -> ConfigHandler {
+        let mut config = Config::try_default().unwrap();
+        config.base = Profile::new(&dir.join("cache.db"));
+
+        ConfigHandler {
+            config_file: dir.join(CONFIG_FILE_NAME),
             config,
+            warnings: vec![],
         }
     }
+
+    #[test]
+    fn test_load_from_explicit_file() {
+        let config_file = temp_dir().join("nested").join(CONFIG_FILE_NAME);
+
+        assert_eq!(
+            ConfigHandler::read_discovery(&config_file).unwrap(),
+            Discovery::default()
+        );
+        assert!(!config_file.exists());
+
+        let mut handler = ConfigHandler::load_from(&config_file).unwrap();
+        assert!(config_file.exists());
+        assert_eq!(handler.config_file(), config_file);
+
+        let database = config_file.with_file_name("test.db");
+        handler.override_database(&database);
+        handler.save_config().unwrap();
+
+        let reloaded = ConfigHandler::load_from(&config_file).unwrap();
+        assert_eq!(handler.config().database_path(), database);
+        assert_ne!(reloaded.config().database_path(), database);
+    }
+
+    #[test]
+    fn test_migrate_on_load() {
+        let config_file = temp_dir().join(CONFIG_FILE_NAME);
+        let content = "database_path = \"cache.db\"\ntheme = \"dark\"\n";
+        fs::write(&config_file, content).unwrap();
+
+        let mut handler = ConfigHandler::load_from(&config_file).unwrap();
+        let backup = with_suffix(&config_file, BACKUP_SUFFIX);
+
+        assert_eq!(handler.config().version, CONFIG_VERSION);
+        assert_eq!(fs::read_to_string(&backup).unwrap(), content);
+
+        let saved = fs::read_to_string(&config_file).unwrap();
+        assert!(saved.contains("theme = \"dark\""));
+
+        handler.add_saved_directory(Path::new("projects"));
+        handler.save_config().unwrap();
+        assert_eq!(fs::read_to_string(&backup).unwrap(), saved);
+    }
+
+    #[test]
+    fn test_set_database_path() {
+        let dir = temp_dir();
+        let mut handler = handler(&dir);
+        fs::write(dir.join("cache.db"), "db").unwrap();
+        fs::write(dir.join("cache.db-wal"), "wal").unwrap();
+
+        let moved = dir.join("moved").join("cache.db");
+        handler.set_database_path(&moved, true).unwrap();
+
+        assert_eq!(handler.config().profile().database_path, moved);
+        assert_eq!(fs::read_to_string(&moved).unwrap(), "db");
+        assert!(dir.join("moved/cache.db-wal").exists());
+        assert!(!dir.join("cache.db").exists());
+
+        handler
+            .set_database_path(&dir.join("fresh.db"), false)
+            .unwrap();
+        assert!(moved.exists());
+        assert!(!dir.join("fresh.db").exists());
+    }
+
+    #[test]
+    fn test_profiles() {
+        let dir = temp_dir();
+        let mut handler = handler(&dir);
+
+        handler.add_profile("work", None).unwrap();
+        handler.select_profile("work").unwrap();
+        handler.add_saved_directory(&dir);
+
+        assert_eq!(
+            handler.config().profile().database_path,
+            dir.join("work.db")
+        );
+        assert!(handler.dirs().contains(&dir));
+        assert!(handler.config().base.directories_to_load.is_empty());
+
+        assert!(handler.add_profile(" ", None).is_err());
+        assert!(handler.set_default_profile("personal").is_err());
+        handler.set_default_profile("work").unwrap();
+        handler.save_config().unwrap();
+
+        let saved = Config::from_file(handler.config_file()).unwrap();
+        assert_eq!(saved.default_profile.as_deref(), Some("work"));
+        assert!(saved.profiles["work"].directories_to_load.contains(&dir));
+    }
+
+    #[test]
+    fn test_unknown_default_profile() {
+        let config_file = temp_dir().join(CONFIG_FILE_NAME);
+        fs::write(
+            &config_file,
+            "version = 1\ndatabase_path = \"cache.db\"\ndefault_profile = \"missing\"\n",
+        )
+        .unwrap();
+
+        let handler = ConfigHandler::load_from(&config_file).unwrap();
+
+        assert_eq!(handler.config().active_profile(), DEFAULT_PROFILE);
+        assert_eq!(handler.warnings().len(), 1);
+        assert!(handler.warnings()[0].contains("missing"));
+    }
+
+    #[test]
+    fn test_load_with_overrides() {
+        let dir = temp_dir();
+        let mut handler = handler(&dir);
+        handler.add_profile("work", None).unwrap();
+        handler.save_config().unwrap();
+
+        let overrides = Overrides {
+            profile: Some("work".to_string()),
+            database: Some(dir.join("other.db")),
+        };
+        let loaded = ConfigHandler::load_with(handler.config_file(), &overrides).unwrap();
+        assert_eq!(loaded.config().active_profile(), "work");
+        assert_eq!(loaded.config().database_path(), dir.join("other.db"));
+
+        let overrides = Overrides {
+            profile: Some("missing".to_string()),
+            database: None,
+        };
+        assert!(matches!(
+            ConfigHandler::load_with(handler.config_file(), &overrides),
+            Err(Error::UnknownProfile(_))
+        ));
+    }
+
+    #[test]
+    fn test_migrate_onto_existing_database() {
+        let dir = temp_dir();
+        let mut handler = handler(&dir);
+        fs::write(dir.join("cache.db"), "db").unwrap();
+        fs::write(dir.join("other.db"), "other").unwrap();
+
+        let result = handler.set_database_path(&dir.join("other.db"), true);
+
+        assert!(matches!(result, Err(Error::DatabaseExists(_))));
+        assert_eq!(
+            handler.config().profile().database_path,
+            dir.join("cache.db")
+        );
+    }
 }
@@ -0,0 +1,47 @@
+//! Submodule of [`crate::config`] for the [`Discovery`] settings.
+use serde::{Deserialize, Serialize};
+
+/// File name used for dedicated manifests, unless configured otherwise.
+pub static DEFAULT_MANIFEST_FILE_NAME: &str = "manifest.toml";
+
+/// Settings regarding how manifest files are named and discovered
+/// when searching directories.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct Discovery {
+    /// File names recognised as dedicated manifests, ordered by precedence.
+    ///
+    /// The first name is used when creating new manifests.
+    pub manifest_names: Vec<String>,
+    /// Additional glob patterns, relative to a searched directory, whose
+    /// matches are treated as dedicated manifests.
+    pub patterns: Vec<String>,
+    /// Glob patterns, relative to a searched directory, which are skipped.
+    pub exclude: Vec<String>,
+}
+
+impl Discovery {
+    /// The file name used when creating a new dedicated manifest.
+    #[must_use]
+    pub fn manifest_name(&self) -> &str {
+        self.manifest_names
+            .first()
+            .map_or(DEFAULT_MANIFEST_FILE_NAME, String::as_str)
+    }
+
+    /// Check whether the given file name is one of the configured manifest names.
+    #[must_use]
+    pub fn is_manifest_name(&self, name: &str) -> bool {
+        self.manifest_names.iter().any(|el| el == name)
+    }
+}
+
+impl Default for Discovery {
+    fn default() -> Self {
+        Self {
+            manifest_names: vec![DEFAULT_MANIFEST_FILE_NAME.to_string()],
+            patterns: vec![],
+            exclude: vec!["**/node_modules/**".to_string(), "**/target/**".to_string()],
+        }
+    }
+}
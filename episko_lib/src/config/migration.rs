@@ -0,0 +1,138 @@
+//! Submodule of [`crate::config`] for the versioning of the config format.
+//!
+//! Every key of the config may be omitted, in which case its default is
+//! used, and keys unknown to this version are kept, see [`Config::unknown`].
+//! Hence only changes to the meaning of existing keys require a new
+//! version along with a migration.
+//!
+//! ## Versions
+//! - `0`: Configs written before the format was versioned
+//! - `1`: Adds the `version` key
+use std::fs;
+
+use super::{Config, Error, Result};
+
+/// Version of the config format written by this version of episko.
+pub static CONFIG_VERSION: u32 = 1;
+
+/// Migrations of the config format, the migration at index `i`
+/// migrates a config of version `i` to version `i + 1`.
+static MIGRATIONS: &[fn(&mut Config)] = &[migrate_unversioned];
+
+impl Config {
+    /// Migrate the config to [`CONFIG_VERSION`], returning whether it
+    /// has been changed.
+    ///
+    /// Configs of a newer version are left as they are.
+    pub fn migrate(&mut self) -> bool {
+        let outdated = self.version < CONFIG_VERSION;
+
+        while self.version < CONFIG_VERSION {
+            MIGRATIONS[self.version as usize](self);
+            self.version += 1;
+        }
+        outdated
+    }
+
+    /// Set the database paths omitted in the config, returning whether
+    /// the config has been changed.
+    ///
+    /// The default profile uses the default location, see
+    /// [`Config::try_default`], while further profiles use a database
+    /// named after the profile next to the one of the default profile.
+    ///
+    /// # Errors
+    /// - [`Error::Directory`] when the parent directory of the db can't be found
+    /// - [`Error::Io`] when creating a non existent db directory fails
+    /// - Propogates errors from [`Config::generate_db_path`]
+    pub fn fill_defaults(&mut self) -> Result<bool> {
+        let mut changed = false;
+
+        if self.base.database_path.as_os_str().is_empty() {
+            let database_path = Self::generate_db_path()?;
+            fs::create_dir_all(database_path.parent().ok_or(Error::Directory)?)?;
+
+            self.base.database_path = database_path;
+            changed = true;
+        }
+
+        for (name, profile) in &mut self.profiles {
+            if profile.database_path.as_os_str().is_empty() {
+                profile.database_path =
+                    self.base.database_path.with_file_name(format!("{name}.db"));
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+}
+
+/// Unversioned configs share the format of version `1`, apart from
+/// lacking the version itself.
+fn migrate_unversioned(_: &mut Config) {}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_migration_for_every_version() {
+        assert_eq!(MIGRATIONS.len(), CONFIG_VERSION as usize);
+    }
+
+    #[test]
+    fn test_migrate_unversioned_config() {
+        let content = r#"
+database_path = "cache.db"
+directories_to_load = []
+files_to_load = []
+"#;
+        let mut config: Config = toml::from_str(content).unwrap();
+        assert_eq!(config.version, 0);
+
+        assert!(config.migrate());
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(!config.migrate());
+    }
+
+    #[test]
+    fn test_keep_unknown_keys_of_newer_config() {
+        let content = r#"
+version = 99
+database_path = "cache.db"
+theme = "dark"
+
+[sync]
+remote = "https://example.com"
+"#;
+        let mut config: Config = toml::from_str(content).unwrap();
+
+        assert!(!config.migrate());
+        assert_eq!(config.version, 99);
+        assert_eq!(config.base.database_path, Path::new("cache.db"));
+        assert_eq!(config.unknown.len(), 2);
+
+        let saved = toml::to_string(&config).unwrap();
+        let saved: Config = toml::from_str(&saved).unwrap();
+        assert_eq!(saved.unknown, config.unknown);
+        assert_eq!(
+            saved.unknown["sync"]["remote"].as_str(),
+            Some("https://example.com")
+        );
+    }
+
+    #[test]
+    fn test_fill_defaults_of_empty_config() {
+        let mut config: Config = toml::from_str("[profiles.work]").unwrap();
+
+        assert!(config.fill_defaults().unwrap());
+        assert!(config.base.database_path.ends_with("cache.db"));
+        assert_eq!(
+            config.profiles["work"].database_path,
+            config.base.database_path.with_file_name("work.db")
+        );
+        assert!(!config.fill_defaults().unwrap());
+    }
+}
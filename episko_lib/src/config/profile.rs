@@ -0,0 +1,235 @@
+//! Submodule of [`crate::config`] for [`Profile`]s.
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Config, Error, Result};
+
+/// Name of the profile stored at the top level of the config.
+pub static DEFAULT_PROFILE: &str = "default";
+
+/// Environmental variable used to select a profile.
+pub static PROFILE_VAR: &str = "EPISKO_PROFILE";
+
+/// Database and saved paths of a workspace, e.g. to separate work
+/// and personal projects.
+///
+/// The default profile is stored at the top level of the config,
+/// further profiles are named tables:
+/// ```toml
+/// database_path = "/home/user/.cache/episko/cache.db"
+/// directories_to_load = ["/home/user/projects"]
+/// files_to_load = []
+/// default_profile = "work"
+///
+/// [profiles.work]
+/// database_path = "/home/user/.cache/episko/work.db"
+/// directories_to_load = ["/home/user/work"]
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    /// Path of the database, see [`Config::fill_defaults`] if omitted.
+    #[serde(default)]
+    pub database_path: PathBuf,
+    #[serde(default)]
+    pub directories_to_load: HashSet<PathBuf>,
+    #[serde(default)]
+    pub files_to_load: HashSet<PathBuf>,
+}
+
+impl Profile {
+    /// Create an empty profile using the given database.
+    #[must_use]
+    pub fn new(database_path: &Path) -> Self {
+        Self {
+            database_path: database_path.to_path_buf(),
+            directories_to_load: HashSet::default(),
+            files_to_load: HashSet::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Name of the profile in use.
+    #[must_use]
+    pub fn active_profile(&self) -> &str {
+        self.active_profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// The profile in use.
+    #[must_use]
+    pub fn profile(&self) -> &Profile {
+        self.active_profile
+            .as_ref()
+            .and_then(|name| self.profiles.get(name))
+            .unwrap_or(&self.base)
+    }
+
+    /// The profile in use, mutably.
+    pub fn profile_mut(&mut self) -> &mut Profile {
+        self.active_profile
+            .as_ref()
+            .and_then(|name| self.profiles.get_mut(name))
+            .unwrap_or(&mut self.base)
+    }
+
+    /// Path of the database in use, the one of the active profile
+    /// unless overridden.
+    #[must_use]
+    pub fn database_path(&self) -> &Path {
+        self.database_override
+            .as_deref()
+            .unwrap_or(&self.profile().database_path)
+    }
+
+    /// Use the given database instead of the one of the active profile,
+    /// e.g. for tests. The override is never saved.
+    pub fn override_database(&mut self, path: &Path) {
+        self.database_override = Some(path.to_path_buf());
+    }
+
+    /// Names of all profiles, starting with the default profile.
+    #[must_use]
+    pub fn profile_names(&self) -> Vec<&str> {
+        std::iter::once(DEFAULT_PROFILE)
+            .chain(self.profiles.keys().map(String::as_str))
+            .collect()
+    }
+
+    /// Check whether a profile with the given name exists.
+    #[must_use]
+    pub fn has_profile(&self, name: &str) -> bool {
+        name == DEFAULT_PROFILE || self.profiles.contains_key(name)
+    }
+
+    /// Use the profile with the given name from now on.
+    ///
+    /// # Errors
+    /// - [`Error::UnknownProfile`] when no profile with the given name exists
+    pub fn select_profile(&mut self, name: &str) -> Result<()> {
+        if !self.has_profile(name) {
+            return Err(Error::UnknownProfile(name.to_string()));
+        }
+
+        self.active_profile = (name != DEFAULT_PROFILE).then(|| name.to_string());
+        Ok(())
+    }
+
+    /// Add a profile with the given name.
+    ///
+    /// # Errors
+    /// - [`Error::ProfileExists`] when a profile with the given name exists
+    pub fn add_profile(&mut self, name: &str, profile: Profile) -> Result<()> {
+        if self.has_profile(name) {
+            return Err(Error::ProfileExists(name.to_string()));
+        }
+
+        self.profiles.insert(name.to_string(), profile);
+        Ok(())
+    }
+
+    /// Remove the profile with the given name, switching to the default
+    /// profile if it is in use.
+    ///
+    /// Returns `false` when the profile can't be removed, implying it
+    /// doesn't exist or is the default profile.
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        if self.profiles.remove(name).is_none() {
+            return false;
+        }
+
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
+        if self.default_profile.as_deref() == Some(name) {
+            self.default_profile = None;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let mut config = Config::try_default().unwrap();
+        config.base = Profile::new(Path::new("cache.db"));
+        config
+            .add_profile("work", Profile::new(Path::new("work.db")))
+            .unwrap();
+        config
+    }
+
+    #[test]
+    fn test_select_profile() {
+        let mut config = config();
+        assert_eq!(config.active_profile(), DEFAULT_PROFILE);
+        assert_eq!(config.profile_names(), vec![DEFAULT_PROFILE, "work"]);
+
+        config.select_profile("work").unwrap();
+        config
+            .profile_mut()
+            .files_to_load
+            .insert(PathBuf::from("manifest.toml"));
+
+        assert_eq!(config.active_profile(), "work");
+        assert_eq!(config.profile().database_path, PathBuf::from("work.db"));
+        assert!(config.base.files_to_load.is_empty());
+
+        assert!(matches!(
+            config.select_profile("personal"),
+            Err(Error::UnknownProfile(_))
+        ));
+        assert_eq!(config.active_profile(), "work");
+
+        config.select_profile(DEFAULT_PROFILE).unwrap();
+        assert_eq!(config.profile().database_path, PathBuf::from("cache.db"));
+
+        config.override_database(Path::new("test.db"));
+        assert_eq!(config.database_path(), Path::new("test.db"));
+        assert_eq!(config.profile().database_path, PathBuf::from("cache.db"));
+    }
+
+    #[test]
+    fn test_add_and_remove_profile() {
+        let mut config = config();
+        assert!(matches!(
+            config.add_profile(DEFAULT_PROFILE, Profile::new(Path::new("other.db"))),
+            Err(Error::ProfileExists(_))
+        ));
+
+        config.select_profile("work").unwrap();
+        config.default_profile = Some("work".to_string());
+
+        assert!(config.remove_profile("work"));
+        assert!(!config.remove_profile(DEFAULT_PROFILE));
+        assert_eq!(config.active_profile(), DEFAULT_PROFILE);
+        assert_eq!(config.default_profile, None);
+    }
+
+    #[test]
+    fn test_profiles_in_file() {
+        let content = r#"
+database_path = "cache.db"
+directories_to_load = []
+files_to_load = ["manifest.toml"]
+default_profile = "work"
+
+[profiles.work]
+database_path = "work.db"
+"#;
+        let config: Config = toml::from_str(content).unwrap();
+
+        assert_eq!(config.base.files_to_load.len(), 1);
+        assert_eq!(config.default_profile.as_deref(), Some("work"));
+        assert_eq!(config.profiles["work"], Profile::new(Path::new("work.db")));
+
+        let saved: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(saved.base, config.base);
+        assert_eq!(saved.profiles, config.profiles);
+    }
+}
@@ -0,0 +1,189 @@
+//! Submodule of [`crate::config`] for the validation of a [`Config`].
+use glob::Pattern;
+
+use crate::metadata::{
+    builder::validation::validate_manifest_name, property::PropertyKind, version::parse_version,
+};
+
+use super::{Config, Error, Profile, Result, CONFIG_VERSION, DEFAULT_PROFILE};
+
+impl Config {
+    /// Check the config, e.g. after it has been edited manually,
+    /// collecting every problem found.
+    ///
+    /// Saved files and directories which no longer exist are reported
+    /// as well, as they can't be loaded.
+    ///
+    /// # Errors
+    /// - [`Error::Invalid`] containing all problems, if there are any
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = vec![];
+
+        // Keys of newer versions are kept, but ignored
+        if self.version > CONFIG_VERSION {
+            problems.push(format!(
+                "config version {} is newer than the supported version {CONFIG_VERSION}",
+                self.version
+            ));
+        }
+
+        validate_profile(&self.base, "", &mut problems);
+        for (name, profile) in &self.profiles {
+            if name.trim().is_empty() || name == DEFAULT_PROFILE {
+                problems.push(format!("invalid profile name: {name:?}"));
+            }
+            validate_profile(profile, &format!("profile {name}: "), &mut problems);
+        }
+        if let Some(name) = &self.default_profile
+            && !self.has_profile(name)
+        {
+            problems.push(format!("default profile {name} does not exist"));
+        }
+
+        if self.discovery.manifest_names.is_empty() {
+            problems.push("no manifest names configured".to_string());
+        }
+        for name in &self.discovery.manifest_names {
+            if let Err(err) = validate_manifest_name(name) {
+                problems.push(err.to_string());
+            }
+        }
+        for pattern in self
+            .discovery
+            .patterns
+            .iter()
+            .chain(&self.discovery.exclude)
+        {
+            if Pattern::new(pattern).is_err() {
+                problems.push(format!("invalid glob pattern: {pattern}"));
+            }
+        }
+
+        let minimums = self
+            .version_policy
+            .languages
+            .iter()
+            .chain(&self.version_policy.build_systems);
+        for (name, version) in minimums {
+            if parse_version(version).is_none() {
+                problems.push(format!("invalid minimum version of {name}: {version}"));
+            }
+        }
+
+        for kind in PropertyKind::ALL {
+            for (alias, name) in self.aliases.table(kind) {
+                if alias.trim().is_empty() || name.trim().is_empty() {
+                    problems.push(format!("{kind} alias can't be empty: {alias:?} = {name:?}"));
+                }
+            }
+        }
+
+        match problems.len() {
+            0 => Ok(()),
+            _ => Err(Error::Invalid(problems)),
+        }
+    }
+}
+
+/// Check the database and saved paths of the profile, prefixing every
+/// problem found.
+fn validate_profile(profile: &Profile, prefix: &str, problems: &mut Vec<String>) {
+    if profile.database_path.as_os_str().is_empty() || profile.database_path.is_dir() {
+        problems.push(format!(
+            "{prefix}database path {} is not a file",
+            profile.database_path.display()
+        ));
+    }
+
+    for dir in &profile.directories_to_load {
+        if !dir.is_dir() {
+            problems.push(format!(
+                "{prefix}saved directory {} does not exist",
+                dir.display()
+            ));
+        }
+    }
+    for file in &profile.files_to_load {
+        if !file.exists() {
+            problems.push(format!(
+                "{prefix}saved file {} does not exist",
+                file.display()
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+    use crate::files::files_test::temp_dir;
+
+    fn config() -> Config {
+        let dir = temp_dir();
+        let mut config = Config::try_default().unwrap();
+        config.base = Profile::new(&dir.join("cache.db"));
+        config.base.directories_to_load.insert(dir);
+        config
+    }
+
+    #[test]
+    fn test_valid_config() {
+        assert!(config().validate().is_ok());
+    }
+
+    #[test]
+    fn test_newer_version() {
+        let mut config = config();
+        config.version = CONFIG_VERSION + 1;
+
+        let Err(Error::Invalid(problems)) = config.validate() else {
+            panic!("expected validation to fail");
+        };
+        assert_eq!(problems.len(), 1);
+    }
+
+    #[test]
+    fn test_validate_collects_all_problems() {
+        let mut config = config();
+        config.base.database_path = temp_dir();
+        config
+            .base
+            .files_to_load
+            .insert(PathBuf::from("/does/not/exist/manifest.toml"));
+        let mut work = Profile::new(Path::new("work.db"));
+        work.directories_to_load
+            .insert(PathBuf::from("/does/not/exist"));
+        config.add_profile("work", work).unwrap();
+        config.default_profile = Some("personal".to_string());
+        config.discovery.manifest_names = vec!["nested/manifest.toml".to_string()];
+        config.discovery.exclude = vec!["target/[".to_string()];
+        config
+            .version_policy
+            .languages
+            .insert("Rust".to_string(), "latest".to_string());
+        config
+            .aliases
+            .languages
+            .insert("ts".to_string(), " ".to_string());
+
+        let Err(Error::Invalid(problems)) = config.validate() else {
+            panic!("expected validation to fail");
+        };
+
+        assert_eq!(problems.len(), 8);
+        assert!(problems[0].starts_with("database path"));
+        assert_eq!(
+            problems[1],
+            "saved file /does/not/exist/manifest.toml does not exist"
+        );
+        assert_eq!(
+            problems[2],
+            "profile work: saved directory /does/not/exist does not exist"
+        );
+        assert_eq!(problems[3], "default profile personal does not exist");
+        assert_eq!(problems[5], "invalid glob pattern: target/[");
+        assert_eq!(problems[6], "invalid minimum version of Rust: latest");
+    }
+}
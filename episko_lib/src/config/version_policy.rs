@@ -0,0 +1,49 @@
+//! Submodule of [`crate::config`] for the [`VersionPolicy`] settings.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Minimum versions projects are expected to use, keyed by the
+/// name of the language or build system.
+///
+/// Names are compared case-insensitively and versions are parsed leniently,
+/// see [`crate::metadata::version::parse_version`].
+///
+/// ```toml
+/// [version_policy.languages]
+/// Rust = "1.80"
+/// Python = "3.10"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct VersionPolicy {
+    pub languages: BTreeMap<String, String>,
+    pub build_systems: BTreeMap<String, String>,
+}
+
+impl VersionPolicy {
+    /// Minimum version required for the given language, if any.
+    #[must_use]
+    pub fn minimum_language(&self, name: &str) -> Option<&str> {
+        Self::minimum(&self.languages, name)
+    }
+
+    /// Minimum version required for the given build system, if any.
+    #[must_use]
+    pub fn minimum_build_system(&self, name: &str) -> Option<&str> {
+        Self::minimum(&self.build_systems, name)
+    }
+
+    /// Check whether no minimum versions are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty() && self.build_systems.is_empty()
+    }
+
+    fn minimum<'a>(minimums: &'a BTreeMap<String, String>, name: &str) -> Option<&'a str> {
+        minimums
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, version)| version.as_str())
+    }
+}
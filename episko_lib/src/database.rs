@@ -24,9 +24,13 @@
 //! - [`update_metadata`]
 //! - [`remove_metadata`]
 //! - [`validate_stored_metadata`]
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
 use thiserror::Error;
 
+use crate::metadata::version::VersionConstraint;
+
 pub mod database_handler;
 pub mod database_object;
 
@@ -46,11 +50,27 @@ use uuid::Uuid;
 /// Result type for this module using [`enum@Error`]
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
 pub struct Filter {
     pub query: Option<String>,
     pub language: Option<String>,
     pub category: Option<String>,
+    pub build_system: Option<String>,
+    pub license: Option<String>,
+    pub framework: Option<String>,
+    /// Only include projects involving a person with a name like the given one.
+    pub person: Option<String>,
+    /// Only include projects using a version of the language satisfying the
+    /// constraint, any language if `language` is `None`.
+    pub language_version: Option<VersionConstraint>,
+    /// Only include projects using a version of the build system satisfying the
+    /// constraint, any build system if `build_system` is `None`.
+    pub build_system_version: Option<VersionConstraint>,
+    /// Only include projects using a version of the framework satisfying the
+    /// constraint, any framework if `framework` is `None`.
+    pub framework_version: Option<VersionConstraint>,
+    /// Only include projects updated at or after the given time.
+    pub updated_since: Option<DateTime<Utc>>,
 }
 
 impl Filter {
@@ -61,8 +81,96 @@ impl Filter {
             query: Some(query.to_string()),
             language: None,
             category: None,
+            build_system: None,
+            license: None,
+            framework: None,
+            person: None,
+            language_version: None,
+            build_system_version: None,
+            framework_version: None,
+            updated_since: None,
+        }
+    }
+
+    /// Check whether the filter doesn't restrict the projects at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Resolve the version constraints of the filter to the ids of all matching
+    /// languages, build systems and frameworks.
+    ///
+    /// As sqlite can't compare semantic versions, the versions are compared
+    /// here and only the ids are passed to the actual query, see [`version_condition`].
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub(crate) async fn matching_versions(&self, db: &DatabaseHandler) -> Result<MatchingVersions> {
+        let mut matching = MatchingVersions::default();
+
+        if let Some(constraint) = &self.language_version {
+            matching.language =
+                Some(matching_ids(db, "language", self.language.as_deref(), constraint).await?);
+        }
+
+        if let Some(constraint) = &self.build_system_version {
+            matching.build_system = Some(
+                matching_ids(db, "build_system", self.build_system.as_deref(), constraint).await?,
+            );
+        }
+
+        if let Some(constraint) = &self.framework_version {
+            matching.framework =
+                Some(matching_ids(db, "framework", self.framework.as_deref(), constraint).await?);
+        }
+
+        Ok(matching)
+    }
+}
+
+/// Ids of the languages, build systems and frameworks matching the version constraints
+/// of a [`Filter`], as json arrays of hex encoded ids.
+#[derive(Debug, Default)]
+pub(crate) struct MatchingVersions {
+    pub language: Option<String>,
+    pub build_system: Option<String>,
+    pub framework: Option<String>,
+}
+
+/// Sql fragment restricting projects to those related to one of the ids
+/// bound to its placeholder, as returned by [`Filter::matching_versions`].
+pub(crate) fn version_condition(table: &str) -> String {
+    format!(
+        "metadata.id IN (
+            SELECT rel.metadata_id FROM rel_metadata_{table} rel
+            WHERE hex(rel.{table}_id) IN (SELECT value FROM json_each(?))
+        )"
+    )
+}
+
+/// Retrieve the ids of all entries of the given table, with a name like the
+/// given one, with a version satisfying the constraint.
+async fn matching_ids(
+    db: &DatabaseHandler,
+    table: &str,
+    name: Option<&str>,
+    constraint: &VersionConstraint,
+) -> Result<String> {
+    let query = format!(
+        "SELECT hex(id) AS id, version FROM {table}
+         WHERE version IS NOT NULL AND (?1 IS NULL OR name LIKE ?1)"
+    );
+
+    let mut ids = vec![];
+    for row in sqlx::query(&query).bind(name).fetch_all(db.conn()).await? {
+        let version: String = row.try_get("version")?;
+        if constraint.matches(&version) {
+            ids.push(row.try_get::<String, _>("id")?);
         }
     }
+
+    Ok(serde_json::to_string(&ids).unwrap_or_else(|_| "[]".to_string()))
 }
 
 #[derive(Debug, Error)]
@@ -1,12 +1,12 @@
 use chrono::{DateTime, Utc};
 use serde_json::Value;
-use sqlx::prelude::FromRow;
+use sqlx::{prelude::FromRow, types::Json};
 use uuid::Uuid;
 
 use crate::{
     metadata::{
-        property::Property, BuildSystem, Category, Ide, Language, Metadata, MetadataBuilder,
-        MetadataPreview,
+        property::Property, BuildSystem, Category, Framework, Ide, Language, License, Link,
+        Metadata, MetadataBuilder, MetadataPreview, Person,
     },
     ApplyIf as _,
 };
@@ -18,7 +18,8 @@ pub struct MetadataDao {
     pub directory: String,
     pub title: String,
     pub description: Option<String>,
-    pub repository_url: Option<String>,
+    pub parent_id: Option<Uuid>,
+    pub subprojects: Json<Vec<String>>,
     pub created: String,
     pub updated: String,
 
@@ -26,6 +27,10 @@ pub struct MetadataDao {
     pub categories: Value,
     pub languages: Value,
     pub build_systems: Value,
+    pub licenses: Value,
+    pub frameworks: Value,
+    pub people: Value,
+    pub links: Value,
 }
 
 impl TryInto<Metadata> for MetadataDao {
@@ -41,6 +46,17 @@ impl TryInto<Metadata> for MetadataDao {
         let mut build_systems: Vec<BuildSystem> = serde_json::from_value(self.build_systems)?;
         build_systems.iter_mut().for_each(Property::update_id);
 
+        let mut licenses: Vec<License> = serde_json::from_value(self.licenses)?;
+        licenses.iter_mut().for_each(Property::update_id);
+
+        let mut frameworks: Vec<Framework> = serde_json::from_value(self.frameworks)?;
+        frameworks.iter_mut().for_each(Property::update_id);
+
+        let mut people: Vec<Person> = serde_json::from_value(self.people)?;
+        people.iter_mut().for_each(Property::update_id);
+
+        let links: Vec<Link> = serde_json::from_value(self.links)?;
+
         let created = self.created.parse::<DateTime<Utc>>()?;
         let updated = self.updated.parse::<DateTime<Utc>>()?;
 
@@ -54,14 +70,16 @@ impl TryInto<Metadata> for MetadataDao {
             .categories(categories)
             .languages(languages)
             .build_systems(build_systems)
+            .licenses(licenses)
+            .frameworks(frameworks)
+            .people(people)
+            .links(links)
+            .subprojects(self.subprojects.0)
             .created(created)
             .updated(updated)
+            .apply_if(self.parent_id, MetadataBuilder::parent)
             .apply_if(preferred_ide, MetadataBuilder::preferred_ide)
             .apply_if(self.description.as_deref(), MetadataBuilder::description)
-            .apply_if(
-                self.repository_url.as_deref(),
-                MetadataBuilder::repository_url,
-            )
             .build()?)
     }
 }
@@ -69,6 +87,7 @@ impl TryInto<Metadata> for MetadataDao {
 #[derive(Debug, FromRow, Clone)]
 pub struct MetadataPreviewDao {
     pub id: Uuid,
+    pub parent_id: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
     pub created: String,
@@ -93,6 +112,7 @@ impl TryInto<MetadataPreview> for MetadataPreviewDao {
 
         Ok(MetadataPreview {
             id: self.id,
+            parent: self.parent_id,
             title: self.title,
             description: self.description,
             categories,
@@ -127,13 +147,21 @@ mod tests {
             directory: ".".to_string(),
             title: "Test Title".to_string(),
             description: Some("Test Description".to_string()),
-            repository_url: Some("https://example.com".to_string()),
+            parent_id: None,
+            subprojects: Json(vec![]),
             created: "2023-01-01T00:00:00Z".to_string(),
             updated: "2023-01-02T00:00:00Z".to_string(),
             preferred_ide_name: Some("Test IDE".to_string()),
             categories: json!([{ "name": "Category 1" }]),
             languages: json!([{  "name": "Language 1" }]),
             build_systems: json!([{ "name": "Build System 1" }]),
+            licenses: json!([{ "name": "MIT" }]),
+            frameworks: json!([{ "name": "Framework 1", "version": "1.0" }]),
+            people: json!([{ "name": "Person 1", "email": null, "role": "maintainer" }]),
+            links: json!([
+                { "kind": "repository", "url": "https://example.com", "label": null },
+                { "kind": "ci", "url": "https://ci.example.com", "label": "Pipelines" }
+            ]),
         };
 
         let result: Result<Metadata, ConversionError> = dao.clone().try_into();
@@ -143,10 +171,8 @@ mod tests {
         assert_eq!(metadata.id, dao.id);
         assert_eq!(metadata.title, dao.title);
         assert_eq!(metadata.description.as_deref(), dao.description.as_deref());
-        assert_eq!(
-            metadata.repository_url.as_deref(),
-            dao.repository_url.as_deref()
-        );
+        assert_eq!(metadata.repository_url(), Some("https://example.com"));
+        assert_eq!(metadata.links[1].title(), "Pipelines");
         assert_eq!(
             metadata.preferred_ide.as_ref().map(Property::name),
             dao.preferred_ide_name.as_deref()
@@ -154,6 +180,9 @@ mod tests {
         assert_eq!(metadata.categories.len(), 1);
         assert_eq!(metadata.languages.len(), 1);
         assert_eq!(metadata.build_systems.len(), 1);
+        assert_eq!(metadata.licenses.len(), 1);
+        assert_eq!(metadata.frameworks.len(), 1);
+        assert_eq!(metadata.people[0].role.as_deref(), Some("maintainer"));
     }
 
     #[test]
@@ -163,13 +192,18 @@ mod tests {
             directory: ".".to_string(),
             title: "Test Title".to_string(),
             description: None,
-            repository_url: None,
+            parent_id: None,
+            subprojects: Json(vec![]),
             created: "invalid_date".to_string(),
             updated: "2023-01-02T00:00:00Z".to_string(),
             preferred_ide_name: None,
             categories: json!([]),
             languages: json!([]),
             build_systems: json!([]),
+            licenses: json!([]),
+            frameworks: json!([]),
+            people: json!([]),
+            links: json!([]),
         };
 
         let result: Result<Metadata, ConversionError> = dao.try_into();
@@ -188,13 +222,18 @@ mod tests {
             directory: ".".to_string(),
             title: "Test Title".to_string(),
             description: None,
-            repository_url: None,
+            parent_id: None,
+            subprojects: Json(vec![]),
             created: "2023-01-01T00:00:00Z".to_string(),
             updated: "2023-01-02T00:00:00Z".to_string(),
             preferred_ide_name: None,
             categories: json!("invalid_json"),
             languages: json!([]),
             build_systems: json!([]),
+            licenses: json!([]),
+            frameworks: json!([]),
+            people: json!([]),
+            links: json!([]),
         };
 
         let result: Result<Metadata, ConversionError> = dao.try_into();
@@ -23,14 +23,14 @@ pub struct DatabaseHandler {
 }
 
 impl DatabaseHandler {
-    /// Creates a new instance using the databas path provided by the given [`Config`].
+    /// Creates a new instance using the database in use by the given [`Config`], see [`Config::database_path`].
     ///
     /// # Errors
     /// - [`Error::Db`] if the database cannot be created
     pub async fn with_config(config: &Config) -> Result<Self> {
         let url = format!(
             "sqlite:///{}",
-            config.database_path.to_str().unwrap_or_default()
+            config.database_path().to_str().unwrap_or_default()
         );
 
         Self::new(&url).await
@@ -55,7 +55,8 @@ pub trait DatabaseObject: Sized + for<'r> FromRow<'r, SqliteRow> {
         executor: impl SqliteExecutor<'e> + 'e,
     ) -> BoxedFuture<'e, Result<bool>>;
 
-    /// Retrieve all unique names
+    /// Retrieve all unique names, together with their remaining columns
+    /// such as versions, sorted by [`Ord`]
     fn all_names<'e>(executor: impl SqliteExecutor<'e> + 'e) -> BoxedFuture<'e, Result<Vec<Self>>>;
 
     /// Remove the given object from the database
@@ -100,4 +101,31 @@ mod tests {
 
         assert!(!category.exists(&conn).await.unwrap());
     }
+
+    #[sqlx::test]
+    async fn test_all_names_sorted(conn: SqlitePool) {
+        for (name, version) in [
+            ("Rust", "1.84"),
+            ("Go", "1.22"),
+            ("Rust", "nightly"),
+            ("Rust", "1.9"),
+        ] {
+            Language::with_version(name, version)
+                .write_to_db(&conn)
+                .await
+                .unwrap();
+        }
+
+        let all: Vec<_> = Language::all_names(&conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|el| format!("{} {}", el.name, el.version.unwrap_or_default()))
+            .collect();
+
+        assert_eq!(
+            all,
+            vec!["Go 1.22", "Rust 1.9", "Rust 1.84", "Rust nightly"]
+        );
+    }
 }
@@ -1,14 +1,17 @@
 use super::{DatabaseHandler, DatabaseObject, Error, Result};
 use crate::metadata::{property::Property, Metadata};
-use sqlx::SqliteConnection;
+use sqlx::{types::Json, SqliteConnection};
 
 impl Metadata {
     const REL_INSERT_QUERY: &str = "INSERT INTO rel_metadata_{}(metadata_id, {}_id) VALUES(?, ?)";
     const METADATA_INSERT_QUERY: &str = "
         INSERT OR REPLACE INTO metadata(
             id, directory, title, description, 
-            preferred_ide, repository_url, created, updated, checksum
-        ) VALUES(?, ?, ?, ?, ?, ?, ?, ?, ?)";
+            preferred_ide, parent_id, subprojects,
+            created, updated, checksum
+        ) VALUES(?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
+    const LINK_INSERT_QUERY: &str =
+        "INSERT INTO link(metadata_id, position, kind, url, label) VALUES(?, ?, ?, ?, ?)";
 
     /// Write a [`Metadata`] instance to the database.
     ///
@@ -34,6 +37,13 @@ impl Metadata {
             .await?;
         self.handle_relations(&mut transaction, "build_system", &self.build_systems)
             .await?;
+        self.handle_relations(&mut transaction, "license", &self.licenses)
+            .await?;
+        self.handle_relations(&mut transaction, "framework", &self.frameworks)
+            .await?;
+        self.handle_relations(&mut transaction, "person", &self.people)
+            .await?;
+        self.handle_links(&mut transaction).await?;
 
         transaction.commit().await?;
         Ok(())
@@ -44,15 +54,15 @@ impl Metadata {
         executor: &mut SqliteConnection,
         relation: Option<&T>,
     ) -> Result<()> {
-        if let Some(item) = relation {
-            if !item.exists(&mut *executor).await? {
-                item.write_to_db(executor).await?;
-            }
+        if let Some(item) = relation
+            && !item.exists(&mut *executor).await?
+        {
+            item.write_to_db(executor).await?;
         }
         Ok(())
     }
 
-    async fn handle_relations<T: DatabaseObject + Property>(
+    pub(super) async fn handle_relations<T: DatabaseObject + Property>(
         &self,
         executor: &mut SqliteConnection,
         relation_type: &str,
@@ -74,6 +84,27 @@ impl Metadata {
         Ok(())
     }
 
+    /// Replace the stored links with the ones of the instance,
+    /// keeping their order.
+    pub(super) async fn handle_links(&self, executor: &mut SqliteConnection) -> Result<()> {
+        sqlx::query("DELETE FROM link WHERE metadata_id = ?")
+            .bind(self.id)
+            .execute(&mut *executor)
+            .await?;
+
+        for (position, link) in (0_i64..).zip(&self.links) {
+            sqlx::query(Self::LINK_INSERT_QUERY)
+                .bind(self.id)
+                .bind(position)
+                .bind(link.kind.to_string())
+                .bind(&link.url)
+                .bind(&link.label)
+                .execute(&mut *executor)
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn insert_metadata(&self, executor: &mut SqliteConnection) -> Result<()> {
         let directory_str = self.directory.to_str();
         let ide_id = self
@@ -87,7 +118,8 @@ impl Metadata {
             .bind(&self.title)
             .bind(&self.description)
             .bind(ide_id)
-            .bind(&self.repository_url)
+            .bind(self.parent)
+            .bind(Json(&self.subprojects))
             .bind(self.created)
             .bind(self.updated)
             .bind(
@@ -1,6 +1,6 @@
 use super::{
     dao::{ConversionError, MetadataDao, MetadataPreviewDao},
-    DatabaseHandler, Filter, Result,
+    version_condition, DatabaseHandler, Filter, Result,
 };
 use crate::metadata::{Metadata, MetadataPreview};
 use sqlx::{QueryBuilder, Row};
@@ -48,7 +48,8 @@ impl Metadata {
         filter: Filter,
         db: &DatabaseHandler,
     ) -> Result<Vec<MetadataPreview>> {
-        let sql = build_query(QueryFilter::Complex(filter.clone()), pagination.as_ref());
+        let matching = filter.matching_versions(db).await?;
+        let sql = build_query(QueryFilter::Complex(&filter), pagination.as_ref());
         let mut query = sqlx::query_as::<_, MetadataPreviewDao>(&sql);
 
         if let Some(search) = filter.query {
@@ -63,6 +64,28 @@ impl Metadata {
             query = query.bind(language);
         }
 
+        if let Some(build_system) = filter.build_system {
+            query = query.bind(build_system);
+        }
+
+        for value in [filter.license, filter.framework, filter.person]
+            .into_iter()
+            .flatten()
+        {
+            query = query.bind(value);
+        }
+
+        for ids in [matching.language, matching.build_system, matching.framework]
+            .into_iter()
+            .flatten()
+        {
+            query = query.bind(ids);
+        }
+
+        if let Some(updated_since) = filter.updated_since {
+            query = query.bind(updated_since.to_rfc3339());
+        }
+
         if let Some(p) = &pagination {
             query = query.bind(p.page_size).bind(p.offset());
         }
@@ -110,9 +133,10 @@ impl Pagination {
     }
 }
 
-enum QueryFilter {
+#[derive(Clone, Copy)]
+enum QueryFilter<'a> {
     Id,
-    Complex(Filter),
+    Complex(&'a Filter),
     None,
 }
 
@@ -124,7 +148,8 @@ fn build_query(filter: QueryFilter, pagination: Option<&Pagination>) -> String {
             metadata.directory,
             metadata.title,
             metadata.description,
-            metadata.repository_url,
+            metadata.parent_id,
+            metadata.subprojects,
             metadata.created,
             metadata.updated,
             metadata.checksum,
@@ -146,7 +171,35 @@ fn build_query(filter: QueryFilter, pagination: Option<&Pagination>) -> String {
                     DISTINCT json_object('name', build_system.name, 'version', build_system.version)
                 ) FILTER(WHERE build_system.name IS NOT NULL),
                 '[]'
-            ) AS build_systems
+            ) AS build_systems,
+            COALESCE(
+                json_group_array(
+                    DISTINCT json_object('name', license.name)
+                ) FILTER(WHERE license.name IS NOT NULL),
+                '[]'
+            ) AS licenses,
+            COALESCE(
+                json_group_array(
+                    DISTINCT json_object('name', framework.name, 'version', framework.version)
+                ) FILTER(WHERE framework.name IS NOT NULL),
+                '[]'
+            ) AS frameworks,
+            COALESCE(
+                json_group_array(
+                    DISTINCT json_object('name', person.name, 'email', person.email, 'role', person.role)
+                ) FILTER(WHERE person.name IS NOT NULL),
+                '[]'
+            ) AS people,
+            (
+                SELECT json_group_array(
+                    json_object('kind', link.kind, 'url', link.url, 'label', link.label)
+                )
+                FROM (
+                    SELECT * FROM link
+                    WHERE link.metadata_id = metadata.id
+                    ORDER BY link.position
+                ) link
+            ) AS links
         FROM metadata
         LEFT JOIN ide ON metadata.preferred_ide = ide.id
         LEFT JOIN rel_metadata_category rmc ON metadata.id = rmc.metadata_id
@@ -155,26 +208,18 @@ fn build_query(filter: QueryFilter, pagination: Option<&Pagination>) -> String {
         LEFT JOIN language ON rml.language_id = language.id
         LEFT JOIN rel_metadata_build_system rmbs ON metadata.id = rmbs.metadata_id
         LEFT JOIN build_system ON rmbs.build_system_id = build_system.id
+        LEFT JOIN rel_metadata_license rmli ON metadata.id = rmli.metadata_id
+        LEFT JOIN license ON rmli.license_id = license.id
+        LEFT JOIN rel_metadata_framework rmf ON metadata.id = rmf.metadata_id
+        LEFT JOIN framework ON rmf.framework_id = framework.id
+        LEFT JOIN rel_metadata_person rmp ON metadata.id = rmp.metadata_id
+        LEFT JOIN person ON rmp.person_id = person.id
         ",
     );
 
     match filter {
         QueryFilter::Id => query.push_str("WHERE metadata.id = ?"),
-        QueryFilter::Complex(filter) => {
-            let mut sep = " WHERE";
-            if filter.query.is_some() {
-                query.push_str(&format!("{sep} metadata.title LIKE ?"));
-                sep = " AND";
-            }
-
-            if filter.category.is_some() {
-                query.push_str(&format!("{sep} category.name LIKE ?"));
-                sep = " AND";
-            }
-            if filter.language.is_some() {
-                query.push_str(&format!("{sep} language.name LIKE ?"));
-            }
-        }
+        QueryFilter::Complex(filter) => push_conditions(&mut query, filter),
         QueryFilter::None => {}
     }
 
@@ -187,6 +232,52 @@ fn build_query(filter: QueryFilter, pagination: Option<&Pagination>) -> String {
     query
 }
 
+/// Append the WHERE clause restricting the query to projects matching the filter.
+///
+/// Placeholders are added in the order their values are bound in
+/// [`Metadata::all_preview_from_db`].
+fn push_conditions(query: &mut String, filter: &Filter) {
+    let mut sep = " WHERE";
+    if filter.query.is_some() {
+        query.push_str(sep);
+        query.push_str(" metadata.title LIKE ?");
+        sep = " AND";
+    }
+
+    for (column, value) in [
+        ("category.name", &filter.category),
+        ("language.name", &filter.language),
+        ("build_system.name", &filter.build_system),
+        ("license.name", &filter.license),
+        ("framework.name", &filter.framework),
+        ("person.name", &filter.person),
+    ] {
+        if value.is_some() {
+            query.push_str(sep);
+            query.push(' ');
+            query.push_str(column);
+            query.push_str(" LIKE ?");
+            sep = " AND";
+        }
+    }
+    for (table, constraint) in [
+        ("language", &filter.language_version),
+        ("build_system", &filter.build_system_version),
+        ("framework", &filter.framework_version),
+    ] {
+        if constraint.is_some() {
+            query.push_str(sep);
+            query.push(' ');
+            query.push_str(&version_condition(table));
+            sep = " AND";
+        }
+    }
+    if filter.updated_since.is_some() {
+        query.push_str(sep);
+        query.push_str(" julianday(metadata.updated) >= julianday(?)");
+    }
+}
+
 fn convert_daos<T, U>(daos: Vec<T>) -> Result<Vec<U>>
 where
     T: TryInto<U, Error = ConversionError>,
@@ -198,7 +289,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::database::db_test::fill_db;
+    use crate::{
+        database::db_test::fill_db,
+        metadata::{property::Property as _, Framework, Language, Link, LinkKind, Person},
+    };
 
     use super::*;
     use sqlx::SqlitePool;
@@ -325,4 +419,94 @@ mod tests {
 
         assert_eq!(previews.len(), 10);
     }
+
+    #[sqlx::test]
+    async fn test_db_version_constraint(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+
+        for (title, version) in [("Old", "1.70"), ("New", "1.84")] {
+            Metadata::builder()
+                .title(title)
+                .directory(".")
+                .add_language(Language::with_version("Rust", version))
+                .build()
+                .unwrap()
+                .write_to_db(&db)
+                .await
+                .unwrap();
+        }
+
+        let filter = Filter {
+            language: Some("Rust".to_string()),
+            language_version: Some(">=1.80".parse().unwrap()),
+            ..Filter::default()
+        };
+        let previews = Metadata::all_preview_from_db(None, filter, &db)
+            .await
+            .unwrap();
+
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].title, "New");
+    }
+
+    #[sqlx::test]
+    async fn test_db_framework_and_person(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+
+        let maintainer = Person::new("Jane Doe")
+            .with_email("jane@example.com")
+            .with_role("maintainer");
+        let expected = Metadata::builder()
+            .title("Web")
+            .directory(".")
+            .add_framework(Framework::with_version("Axum", "0.8"))
+            .add_person(maintainer.clone())
+            .build()
+            .unwrap();
+        expected.write_to_db(&db).await.unwrap();
+        fill_db(5, &db).await;
+
+        let metadata = Metadata::from_db(&db, expected.id).await.unwrap();
+        assert_eq!(metadata.frameworks, expected.frameworks);
+        assert_eq!(metadata.people, vec![maintainer]);
+
+        let filter = Filter {
+            framework: Some("axum".to_string()),
+            framework_version: Some("^0.8".parse().unwrap()),
+            person: Some("Jane%".to_string()),
+            ..Filter::default()
+        };
+        let previews = Metadata::all_preview_from_db(None, filter, &db)
+            .await
+            .unwrap();
+
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].id, expected.id);
+    }
+
+    #[sqlx::test]
+    async fn test_db_links(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+
+        let expected = Metadata::builder()
+            .title("Links")
+            .directory(".")
+            .add_link(Link::new(LinkKind::Homepage, "https://example.com"))
+            .add_link(
+                Link::new(LinkKind::Documentation, "https://docs.example.com").with_label("API"),
+            )
+            .repository_url("https://github.com/example/links")
+            .build()
+            .unwrap();
+        expected.write_to_db(&db).await.unwrap();
+
+        let metadata = Metadata::from_db(&db, expected.id).await.unwrap();
+        assert_eq!(metadata.links, expected.links);
+
+        let updated = metadata.update().links(vec![]).build().unwrap();
+        updated.update_in_db(&db).await.unwrap();
+
+        let metadata = Metadata::from_db(&db, expected.id).await.unwrap();
+        assert!(metadata.links.is_empty());
+    }
 }
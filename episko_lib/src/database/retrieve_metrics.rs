@@ -1,9 +1,23 @@
 //! # Metrics of all projects
+//!
+//! All metrics are calculated for the projects matching a [`Condition`],
+//! built once from a [`Filter`], with [`Condition::default`] including
+//! every cached project.
+//! Properties of the matching projects are never filtered themselves, so the
+//! language breakdown of all "Web" projects still includes every language.
 
-use crate::statistics::Statistic;
-use sqlx::Row;
+use crate::{
+    config::VersionPolicy,
+    statistics::{
+        timeline::{LanguageAdoption, PeriodCount, StaleProject},
+        versions::{self, OutdatedProject, VersionCount, VersionedProperty},
+        Bucket, CoOccurrence, Statistic,
+    },
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query::Query, sqlite::SqliteArguments, Row, Sqlite};
 
-use super::DatabaseHandler;
+use super::{version_condition, DatabaseHandler, Filter};
 use crate::database::Error;
 use std::collections::HashMap;
 
@@ -12,7 +26,10 @@ impl Statistic {
     ///
     /// # Errors
     /// - if the database query fails
-    pub async fn projects_by_language(db: &DatabaseHandler) -> Result<HashMap<String, u32>, Error> {
+    pub async fn projects_by_language(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<HashMap<String, u32>, Error> {
         Self::count_projects(
             db,
             "SELECT language.name AS name, count(language.name) AS count
@@ -21,8 +38,9 @@ impl Statistic {
              ON metadata.id=rel_metadata_language.metadata_id
              JOIN language
              ON rel_metadata_language.language_id=language.id
-             GROUP BY language.name;"
-                .to_string(),
+             WHERE {condition}
+             GROUP BY language.name;",
+            condition,
         )
         .await
     }
@@ -31,15 +49,19 @@ impl Statistic {
     ///
     /// # Errors
     /// - if the database query fails
-    pub async fn projects_by_ide(db: &DatabaseHandler) -> Result<HashMap<String, u32>, Error> {
+    pub async fn projects_by_ide(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<HashMap<String, u32>, Error> {
         Self::count_projects(
             db,
             "SELECT ide.name AS name, count(ide.name) AS count
              FROM metadata
              JOIN ide
              ON metadata.preferred_ide=ide.id
-             GROUP BY ide.name;"
-                .to_string(),
+             WHERE {condition}
+             GROUP BY ide.name;",
+            condition,
         )
         .await
     }
@@ -48,7 +70,10 @@ impl Statistic {
     ///
     /// # Errors
     /// - if the database query fails
-    pub async fn projects_by_category(db: &DatabaseHandler) -> Result<HashMap<String, u32>, Error> {
+    pub async fn projects_by_category(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<HashMap<String, u32>, Error> {
         Self::count_projects(
             db,
             "SELECT category.name AS name, count(category.name) AS count
@@ -57,8 +82,9 @@ impl Statistic {
              ON metadata.id=rel_metadata_category.metadata_id
              JOIN category
              ON rel_metadata_category.category_id=category.id
-             GROUP BY category.name;"
-                .to_string(),
+             WHERE {condition}
+             GROUP BY category.name;",
+            condition,
         )
         .await
     }
@@ -69,6 +95,7 @@ impl Statistic {
     /// - if the database query fails
     pub async fn projects_by_build_system(
         db: &DatabaseHandler,
+        condition: &Condition,
     ) -> Result<HashMap<String, u32>, Error> {
         Self::count_projects(
             db,
@@ -78,8 +105,80 @@ impl Statistic {
              ON metadata.id=rel_metadata_build_system.metadata_id
              JOIN build_system
              ON rel_metadata_build_system.build_system_id=build_system.id
-             GROUP BY build_system.name;"
-                .to_string(),
+             WHERE {condition}
+             GROUP BY build_system.name;",
+            condition,
+        )
+        .await
+    }
+
+    /// Retrieve the project count sorted by license.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn projects_by_license(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<HashMap<String, u32>, Error> {
+        Self::count_projects(
+            db,
+            "SELECT license.name AS name, count(license.name) AS count
+             FROM metadata
+             JOIN rel_metadata_license
+             ON metadata.id=rel_metadata_license.metadata_id
+             JOIN license
+             ON rel_metadata_license.license_id=license.id
+             WHERE {condition}
+             GROUP BY license.name;",
+            condition,
+        )
+        .await
+    }
+
+    /// Retrieve the project count sorted by framework.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn projects_by_framework(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<HashMap<String, u32>, Error> {
+        Self::count_projects(
+            db,
+            "SELECT framework.name AS name, count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_framework
+             ON metadata.id=rel_metadata_framework.metadata_id
+             JOIN framework
+             ON rel_metadata_framework.framework_id=framework.id
+             WHERE {condition}
+             GROUP BY framework.name;",
+            condition,
+        )
+        .await
+    }
+
+    /// Retrieve the project count sorted by maintainer.
+    ///
+    /// Only people with the role `maintainer`, ignoring case, are counted.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn projects_by_maintainer(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<HashMap<String, u32>, Error> {
+        Self::count_projects(
+            db,
+            "SELECT person.name AS name, count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_person
+             ON metadata.id=rel_metadata_person.metadata_id
+             JOIN person
+             ON rel_metadata_person.person_id=person.id
+             WHERE lower(person.role) = 'maintainer' AND {condition}
+             GROUP BY person.name;",
+            condition,
         )
         .await
     }
@@ -88,41 +187,447 @@ impl Statistic {
     ///
     /// # Errors
     /// - if the database query fails
-    pub async fn number_of_projects(db: &DatabaseHandler) -> Result<u32, Error> {
-        let row = sqlx::query(
+    pub async fn number_of_projects(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<u32, Error> {
+        let query = condition.apply(
             "SELECT count(id) AS count
-             FROM metadata;",
-        )
-        .fetch_one(db.conn())
-        .await?;
+             FROM metadata
+             WHERE {condition};",
+        );
+
+        let row = condition
+            .bind(sqlx::query(&query))
+            .fetch_one(db.conn())
+            .await?;
 
         Ok(row.try_get("count")?)
     }
 
-    /// Retrieve the total count of all languages.
+    /// Retrieve the total count of all languages used by the projects.
     ///
     /// # Errors
     /// - if the database query fails
-    pub async fn number_of_languages(db: &DatabaseHandler) -> Result<u32, Error> {
-        let row = sqlx::query(
-            "SELECT count(DISTINCT name) AS count
-             FROM language;",
-        )
-        .fetch_one(db.conn())
-        .await?;
+    pub async fn number_of_languages(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<u32, Error> {
+        let query = condition.apply(
+            "SELECT count(DISTINCT language.name) AS count
+             FROM metadata
+             JOIN rel_metadata_language
+             ON metadata.id=rel_metadata_language.metadata_id
+             JOIN language
+             ON rel_metadata_language.language_id=language.id
+             WHERE {condition};",
+        );
+
+        let row = condition
+            .bind(sqlx::query(&query))
+            .fetch_one(db.conn())
+            .await?;
 
         Ok(row.try_get("count")?)
     }
 
-    /// Execute the given query and return the formatted result.
+    /// Retrieve the project count per language and version,
+    /// sorted by name and semantic version.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn language_versions(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<Vec<VersionCount>, Error> {
+        Self::count_versions(db, VersionedProperty::Language, condition).await
+    }
+
+    /// Retrieve the project count per build system and version,
+    /// sorted by name and semantic version.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn build_system_versions(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<Vec<VersionCount>, Error> {
+        Self::count_versions(db, VersionedProperty::BuildSystem, condition).await
+    }
+
+    /// Retrieve all projects using a language or build system version
+    /// below the minimum required by the given policy.
+    ///
+    /// Versions which can't be parsed are never considered outdated.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn projects_below_minimum(
+        db: &DatabaseHandler,
+        policy: &VersionPolicy,
+        condition: &Condition,
+    ) -> Result<Vec<OutdatedProject>, Error> {
+        let mut outdated = vec![];
+        if policy.is_empty() {
+            return Ok(outdated);
+        }
+
+        for property in [VersionedProperty::Language, VersionedProperty::BuildSystem] {
+            let table = Self::versioned_table(property);
+            let query = condition.apply(&format!(
+                "SELECT metadata.id AS id, metadata.title AS title,
+                        {table}.name AS name, {table}.version AS version
+                 FROM metadata
+                 JOIN rel_metadata_{table}
+                 ON metadata.id=rel_metadata_{table}.metadata_id
+                 JOIN {table}
+                 ON rel_metadata_{table}.{table}_id={table}.id
+                 WHERE {table}.version IS NOT NULL AND {{condition}}
+                 ORDER BY metadata.title, {table}.name;"
+            ));
+
+            for row in condition
+                .bind(sqlx::query(&query))
+                .fetch_all(db.conn())
+                .await?
+            {
+                let name: String = row.try_get("name")?;
+                let version: String = row.try_get("version")?;
+
+                if let Some(minimum) = property.minimum(policy, &name)
+                    && versions::is_below_minimum(&version, minimum)
+                {
+                    outdated.push(OutdatedProject {
+                        id: row.try_get("id")?,
+                        title: row.try_get("title")?,
+                        property,
+                        name,
+                        version,
+                        minimum: minimum.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(outdated)
+    }
+
+    /// Retrieve how often two languages are used within the same project,
+    /// sorted by frequency.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn languages_used_together(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<Vec<CoOccurrence>, Error> {
+        Self::count_pairs(
+            db,
+            "SELECT first.name AS first, second.name AS second,
+                    count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_language AS rel_first
+             ON metadata.id=rel_first.metadata_id
+             JOIN language AS first
+             ON rel_first.language_id=first.id
+             JOIN rel_metadata_language AS rel_second
+             ON metadata.id=rel_second.metadata_id
+             JOIN language AS second
+             ON rel_second.language_id=second.id
+             WHERE first.name < second.name AND {condition}
+             GROUP BY first.name, second.name
+             ORDER BY count DESC, first.name, second.name;",
+            condition,
+        )
+        .await
+    }
+
+    /// Retrieve how often an IDE is preferred for projects using a language,
+    /// sorted by frequency.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn ide_per_language(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<Vec<CoOccurrence>, Error> {
+        Self::count_pairs(
+            db,
+            "SELECT language.name AS first, ide.name AS second,
+                    count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_language
+             ON metadata.id=rel_metadata_language.metadata_id
+             JOIN language
+             ON rel_metadata_language.language_id=language.id
+             JOIN ide
+             ON metadata.preferred_ide=ide.id
+             WHERE {condition}
+             GROUP BY language.name, ide.name
+             ORDER BY count DESC, language.name, ide.name;",
+            condition,
+        )
+        .await
+    }
+
+    /// Retrieve how often a build system is used for projects of a category,
+    /// sorted by frequency.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn build_systems_per_category(
+        db: &DatabaseHandler,
+        condition: &Condition,
+    ) -> Result<Vec<CoOccurrence>, Error> {
+        Self::count_pairs(
+            db,
+            "SELECT category.name AS first, build_system.name AS second,
+                    count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_category
+             ON metadata.id=rel_metadata_category.metadata_id
+             JOIN category
+             ON rel_metadata_category.category_id=category.id
+             JOIN rel_metadata_build_system
+             ON metadata.id=rel_metadata_build_system.metadata_id
+             JOIN build_system
+             ON rel_metadata_build_system.build_system_id=build_system.id
+             WHERE {condition}
+             GROUP BY category.name, build_system.name
+             ORDER BY count DESC, category.name, build_system.name;",
+            condition,
+        )
+        .await
+    }
+
+    /// Retrieve the amount of projects created per period.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn projects_created_per_period(
+        db: &DatabaseHandler,
+        bucket: Bucket,
+        condition: &Condition,
+    ) -> Result<Vec<PeriodCount>, Error> {
+        Self::count_per_period(db, "created", bucket, condition).await
+    }
+
+    /// Retrieve the amount of projects last updated per period.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn projects_updated_per_period(
+        db: &DatabaseHandler,
+        bucket: Bucket,
+        condition: &Condition,
+    ) -> Result<Vec<PeriodCount>, Error> {
+        Self::count_per_period(db, "updated", bucket, condition).await
+    }
+
+    /// Retrieve the amount of projects created per period and language.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn language_adoption(
+        db: &DatabaseHandler,
+        bucket: Bucket,
+        condition: &Condition,
+    ) -> Result<Vec<LanguageAdoption>, Error> {
+        let query = condition.apply(
+            "SELECT strftime(?, metadata.created) AS period,
+                    language.name AS language,
+                    count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_language
+             ON metadata.id=rel_metadata_language.metadata_id
+             JOIN language
+             ON rel_metadata_language.language_id=language.id
+             WHERE {condition}
+             GROUP BY period, language.name
+             ORDER BY period, count DESC, language.name;",
+        );
+
+        let rows = condition
+            .bind(sqlx::query(&query).bind(bucket.strftime_format()))
+            .fetch_all(db.conn())
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(LanguageAdoption {
+                    period: row.try_get("period")?,
+                    language: row.try_get("language")?,
+                    count: row.try_get("count")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Retrieve all projects which haven't been updated for at least the given
+    /// amount of days, the least recently updated first.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    pub async fn stale_projects(
+        db: &DatabaseHandler,
+        stale_after_days: u32,
+        condition: &Condition,
+    ) -> Result<Vec<StaleProject>, Error> {
+        let query = condition.apply(
+            "SELECT id, title, updated,
+                    CAST(julianday('now') - julianday(updated) AS INTEGER) AS days
+             FROM metadata
+             WHERE julianday('now') - julianday(updated) >= ? AND {condition}
+             ORDER BY updated;",
+        );
+
+        let rows = condition
+            .bind(sqlx::query(&query).bind(stale_after_days))
+            .fetch_all(db.conn())
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let updated: String = row.try_get("updated")?;
+
+                Ok(StaleProject {
+                    id: row.try_get("id")?,
+                    title: row.try_get("title")?,
+                    updated: updated.parse::<DateTime<Utc>>()?,
+                    days_since_update: row.try_get("days")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Execute the given query counting pairs of properties.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    async fn count_pairs(
+        db: &DatabaseHandler,
+        query: &str,
+        condition: &Condition,
+    ) -> Result<Vec<CoOccurrence>, Error> {
+        let query = condition.apply(query);
+
+        condition
+            .bind(sqlx::query(&query))
+            .fetch_all(db.conn())
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(CoOccurrence {
+                    first: row.try_get("first")?,
+                    second: row.try_get("second")?,
+                    count: row.try_get("count")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Count all projects per name and version of the given property.
+    ///
+    /// Equal versions stored differently, e.g. `1.84` and `1.84.0`, are
+    /// counted together, see [`versions::merge_version_counts`].
+    ///
+    /// # Errors
+    /// - if the database query fails
+    async fn count_versions(
+        db: &DatabaseHandler,
+        property: VersionedProperty,
+        condition: &Condition,
+    ) -> Result<Vec<VersionCount>, Error> {
+        let table = Self::versioned_table(property);
+        let query = condition.apply(&format!(
+            "SELECT {table}.name AS name, {table}.version AS version,
+                    count(DISTINCT metadata.id) AS count
+             FROM metadata
+             JOIN rel_metadata_{table}
+             ON metadata.id=rel_metadata_{table}.metadata_id
+             JOIN {table}
+             ON rel_metadata_{table}.{table}_id={table}.id
+             WHERE {{condition}}
+             GROUP BY {table}.name, {table}.version;"
+        ));
+
+        let counts = condition
+            .bind(sqlx::query(&query))
+            .fetch_all(db.conn())
+            .await?
+            .into_iter()
+            .map(|row| {
+                let version: Option<String> = row.try_get("version")?;
+
+                Ok(VersionCount {
+                    name: row.try_get("name")?,
+                    version: version.filter(|el| !el.is_empty()),
+                    count: row.try_get("count")?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut counts = versions::merge_version_counts(counts);
+        versions::sort_version_counts(&mut counts);
+        Ok(counts)
+    }
+
+    /// Name of the table storing the given property.
+    fn versioned_table(property: VersionedProperty) -> &'static str {
+        match property {
+            VersionedProperty::Language => "language",
+            VersionedProperty::BuildSystem => "build_system",
+        }
+    }
+
+    /// Count all projects per period of the given date column.
+    ///
+    /// # Errors
+    /// - if the database query fails
+    async fn count_per_period(
+        db: &DatabaseHandler,
+        column: &str,
+        bucket: Bucket,
+        condition: &Condition,
+    ) -> Result<Vec<PeriodCount>, Error> {
+        let query = condition.apply(&format!(
+            "SELECT strftime(?, {column}) AS period, count(id) AS count
+             FROM metadata
+             WHERE {{condition}}
+             GROUP BY period
+             ORDER BY period;"
+        ));
+
+        let rows = condition
+            .bind(sqlx::query(&query).bind(bucket.strftime_format()))
+            .fetch_all(db.conn())
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(PeriodCount {
+                    period: row.try_get("period")?,
+                    count: row.try_get("count")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Execute the given query, restricted by the given [`Condition`],
+    /// and return the formatted result.
     ///
     /// # Errors
     /// - if the database query fails
     async fn count_projects(
         db: &DatabaseHandler,
-        query: String,
+        query: &str,
+        condition: &Condition,
     ) -> Result<HashMap<String, u32>, Error> {
-        let row = sqlx::query(&query).fetch_all(db.conn()).await?;
+        let query = condition.apply(query);
+        let row = condition
+            .bind(sqlx::query(&query))
+            .fetch_all(db.conn())
+            .await?;
 
         let mut counted_projects = HashMap::new();
 
@@ -134,11 +639,118 @@ impl Statistic {
     }
 }
 
+/// WHERE fragment restricting the projects metrics are calculated for,
+/// together with the values bound to its placeholders, in order.
+///
+/// The fragment only ever consists of fixed sql, all values of the
+/// [`Filter`] are passed as bind parameters.
+#[derive(Debug)]
+pub struct Condition {
+    sql: String,
+    binds: Vec<String>,
+}
+
+impl Default for Condition {
+    /// Condition including every cached project.
+    fn default() -> Self {
+        Self {
+            sql: "TRUE".to_string(),
+            binds: vec![],
+        }
+    }
+}
+
+impl Condition {
+    /// Build the fragment for the given filter.
+    ///
+    /// Version constraints are resolved here, so the condition should be
+    /// built once and reused for all metrics.
+    ///
+    /// # Errors
+    /// - if resolving the version constraints of the filter fails
+    pub async fn new(db: &DatabaseHandler, filter: &Filter) -> Result<Self, Error> {
+        let mut fragments = vec![];
+        let mut binds = vec![];
+
+        if let Some(search) = &filter.query {
+            fragments.push("metadata.title LIKE ?".to_string());
+            binds.push(format!("%{search}%"));
+        }
+
+        for (table, name) in [
+            ("category", &filter.category),
+            ("language", &filter.language),
+            ("build_system", &filter.build_system),
+            ("license", &filter.license),
+            ("framework", &filter.framework),
+            ("person", &filter.person),
+        ] {
+            if let Some(name) = name {
+                fragments.push(format!(
+                    "metadata.id IN (
+                        SELECT rel.metadata_id FROM rel_metadata_{table} rel
+                        JOIN {table} ON rel.{table}_id = {table}.id
+                        WHERE {table}.name LIKE ?
+                    )"
+                ));
+                binds.push(name.clone());
+            }
+        }
+
+        let matching = filter.matching_versions(db).await?;
+        for (table, ids) in [
+            ("language", matching.language),
+            ("build_system", matching.build_system),
+            ("framework", matching.framework),
+        ] {
+            if let Some(ids) = ids {
+                fragments.push(version_condition(table));
+                binds.push(ids);
+            }
+        }
+
+        if let Some(updated_since) = &filter.updated_since {
+            fragments.push("julianday(metadata.updated) >= julianday(?)".to_string());
+            binds.push(updated_since.to_rfc3339());
+        }
+
+        if fragments.is_empty() {
+            return Ok(Self::default());
+        }
+
+        Ok(Self {
+            sql: fragments.join(" AND "),
+            binds,
+        })
+    }
+
+    /// Insert the fragment into the `{condition}` placeholder of the given query.
+    ///
+    /// The fragment must be the last part of the query containing bind parameters.
+    fn apply(&self, query: &str) -> String {
+        query.replace("{condition}", &self.sql)
+    }
+
+    /// Bind the values of the fragment to the given query.
+    fn bind<'q>(
+        &'q self,
+        mut query: Query<'q, Sqlite, SqliteArguments<'q>>,
+    ) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+        for el in &self.binds {
+            query = query.bind(el.as_str());
+        }
+        query
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         database::db_test::fill_db,
-        metadata::{property::Property, BuildSystem, Category, Ide, Language, Metadata},
+        metadata::{
+            property::Property, BuildSystem, Category, Framework, Ide, Language, License, Metadata,
+            Person,
+        },
     };
 
     use super::*;
@@ -164,7 +776,14 @@ mod tests {
                 .directory(".")
                 .build_systems(vec![bs1.clone(), bs2.clone()])
                 .categories(vec![cat1.clone(), cat2.clone()])
-                .languages(vec![lang1.clone(), lang2.clone()]);
+                .languages(vec![lang1.clone(), lang2.clone()])
+                .licenses(vec![License::new("MIT")])
+                .frameworks(vec![Framework::with_version("Axum", "0.8")])
+                .people(vec![
+                    Person::new("Jane Doe").with_role("maintainer"),
+                    Person::new("Jane Doe").with_role("reviewer"),
+                    Person::new("John Doe").with_role("reviewer"),
+                ]);
 
             builder = if i < (METADATA_AMOUNT / 2) {
                 builder.preferred_ide(ide1.clone())
@@ -178,13 +797,13 @@ mod tests {
 
     async fn test_projects_by<F>(conn: SqlitePool, projects_by: F, expected_per_key: u32)
     where
-        F: AsyncFnOnce(&DatabaseHandler) -> Result<HashMap<String, u32>, Error>,
+        F: AsyncFnOnce(&DatabaseHandler, &Condition) -> Result<HashMap<String, u32>, Error>,
     {
         let db = DatabaseHandler::with_conn(conn);
 
         fill_db_for_statistics(&db).await;
 
-        let result = projects_by(&db).await.unwrap();
+        let result = projects_by(&db, &Condition::default()).await.unwrap();
 
         for (_, value) in result {
             assert_eq!(value, expected_per_key);
@@ -196,6 +815,31 @@ mod tests {
         test_projects_by(conn, Statistic::projects_by_build_system, METADATA_AMOUNT).await;
     }
 
+    #[sqlx::test]
+    async fn test_projects_by_license(conn: SqlitePool) {
+        test_projects_by(conn, Statistic::projects_by_license, METADATA_AMOUNT).await;
+    }
+
+    #[sqlx::test]
+    async fn test_projects_by_framework(conn: SqlitePool) {
+        test_projects_by(conn, Statistic::projects_by_framework, METADATA_AMOUNT).await;
+    }
+
+    #[sqlx::test]
+    async fn test_projects_by_maintainer(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_statistics(&db).await;
+
+        let result = Statistic::projects_by_maintainer(&db, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            HashMap::from([("Jane Doe".to_string(), METADATA_AMOUNT)])
+        );
+    }
+
     #[sqlx::test]
     async fn test_projects_by_language(conn: SqlitePool) {
         test_projects_by(conn, Statistic::projects_by_language, METADATA_AMOUNT).await;
@@ -216,23 +860,431 @@ mod tests {
         let db = DatabaseHandler::with_conn(conn);
 
         for i in 0..METADATA_AMOUNT {
-            let result = Statistic::number_of_projects(&db).await.unwrap();
+            let result = Statistic::number_of_projects(&db, &Condition::default())
+                .await
+                .unwrap();
 
             assert_eq!(result, i);
 
             fill_db(1, &db).await;
         }
 
-        let result = Statistic::number_of_projects(&db).await.unwrap();
+        let result = Statistic::number_of_projects(&db, &Condition::default())
+            .await
+            .unwrap();
         assert_eq!(result, METADATA_AMOUNT);
     }
 
+    async fn fill_db_for_timeline(db: &DatabaseHandler) {
+        let dates = [
+            ("2024-01-10T12:00:00Z", "rust"),
+            ("2024-01-20T12:00:00Z", "go"),
+            ("2024-03-05T12:00:00Z", "rust"),
+        ];
+
+        for (i, (date, language)) in dates.into_iter().enumerate() {
+            let date = date.parse::<DateTime<Utc>>().unwrap();
+            Metadata::builder()
+                .title(&format!("test_{i}"))
+                .directory(".")
+                .add_language(Language::new(language))
+                .created(date)
+                .updated(if i == 0 { date } else { Utc::now() })
+                .build()
+                .unwrap()
+                .write_to_db(db)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_projects_created_per_period(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_timeline(&db).await;
+
+        let monthly =
+            Statistic::projects_created_per_period(&db, Bucket::Month, &Condition::default())
+                .await
+                .unwrap();
+        let yearly =
+            Statistic::projects_created_per_period(&db, Bucket::Year, &Condition::default())
+                .await
+                .unwrap();
+
+        assert_eq!(
+            monthly,
+            vec![
+                PeriodCount {
+                    period: "2024-01".to_string(),
+                    count: 2
+                },
+                PeriodCount {
+                    period: "2024-03".to_string(),
+                    count: 1
+                }
+            ]
+        );
+        assert_eq!(yearly.len(), 1);
+        assert_eq!(yearly[0].count, 3);
+    }
+
+    #[sqlx::test]
+    async fn test_language_adoption(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_timeline(&db).await;
+
+        let result = Statistic::language_adoption(&db, Bucket::Month, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].period, "2024-01");
+        assert_eq!(result[2].language, "rust");
+        assert_eq!(result[2].period, "2024-03");
+    }
+
+    #[sqlx::test]
+    async fn test_stale_projects(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_timeline(&db).await;
+
+        let result = Statistic::stale_projects(&db, 30, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].title, "test_0");
+        assert!(result[0].days_since_update >= 30);
+
+        let updated =
+            Statistic::projects_updated_per_period(&db, Bucket::Year, &Condition::default())
+                .await
+                .unwrap();
+        assert_eq!(updated.len(), 2);
+    }
+
+    #[sqlx::test]
+    async fn test_languages_used_together(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_statistics(&db).await;
+        Metadata::builder()
+            .title("single")
+            .directory(".")
+            .add_language(Language::new("Rust"))
+            .build()
+            .unwrap()
+            .write_to_db(&db)
+            .await
+            .unwrap();
+
+        let result = Statistic::languages_used_together(&db, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![CoOccurrence {
+                first: "Go".to_string(),
+                second: "Rust".to_string(),
+                count: METADATA_AMOUNT
+            }]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_ide_per_language(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_statistics(&db).await;
+
+        let result = Statistic::ide_per_language(&db, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 4);
+        assert!(result.iter().all(|el| el.count == METADATA_AMOUNT / 2));
+        assert_eq!(result[0].first, "Go");
+        assert_eq!(result[0].second, "emacs");
+    }
+
+    #[sqlx::test]
+    async fn test_build_systems_per_category(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_statistics(&db).await;
+
+        let result = Statistic::build_systems_per_category(&db, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 4);
+        assert!(result.iter().all(|el| el.count == METADATA_AMOUNT));
+    }
+
+    async fn fill_db_for_versions(db: &DatabaseHandler) {
+        let versions = ["1.84", "1.70", "1.84", "1.9"];
+
+        for (i, version) in versions.into_iter().enumerate() {
+            Metadata::builder()
+                .title(&format!("test_{i}"))
+                .directory(".")
+                .add_language(Language::with_version("Rust", version))
+                .add_build_system(BuildSystem::with_version("Cargo", version))
+                .build()
+                .unwrap()
+                .write_to_db(db)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_language_versions(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_versions(&db).await;
+
+        let result = Statistic::language_versions(&db, &Condition::default())
+            .await
+            .unwrap();
+
+        let versions: Vec<_> = result
+            .iter()
+            .map(|el| (el.version.as_deref().unwrap(), el.count))
+            .collect();
+        assert_eq!(versions, vec![("1.9", 1), ("1.70", 1), ("1.84", 2)]);
+    }
+
+    #[sqlx::test]
+    async fn test_equal_versions_are_merged(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+
+        for (i, version) in ["1.84", "1.84.0", "v1.84", "1.85"].into_iter().enumerate() {
+            Metadata::builder()
+                .title(&format!("test_{i}"))
+                .directory(".")
+                .add_language(Language::with_version("Rust", version))
+                .build()
+                .unwrap()
+                .write_to_db(&db)
+                .await
+                .unwrap();
+        }
+
+        let result = Statistic::language_versions(&db, &Condition::default())
+            .await
+            .unwrap();
+
+        let versions: Vec<_> = result
+            .iter()
+            .map(|el| (el.version.as_deref().unwrap(), el.count))
+            .collect();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].1, 3);
+        assert!(["1.84", "1.84.0", "v1.84"].contains(&versions[0].0));
+        assert_eq!(versions[1], ("1.85", 1));
+    }
+
+    #[sqlx::test]
+    async fn test_projects_below_minimum(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_versions(&db).await;
+
+        let mut policy = VersionPolicy::default();
+        assert!(
+            Statistic::projects_below_minimum(&db, &policy, &Condition::default())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        policy
+            .languages
+            .insert("rust".to_string(), "1.80".to_string());
+        let result = Statistic::projects_below_minimum(&db, &policy, &Condition::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].title, "test_1");
+        assert_eq!(result[0].version, "1.70");
+        assert_eq!(result[0].property, VersionedProperty::Language);
+        assert_eq!(result[1].version, "1.9");
+    }
+
+    #[sqlx::test]
+    async fn test_filtered_statistics(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        let projects = [
+            ("Web", vec!["Rust"]),
+            ("Web", vec!["Rust", "TypeScript"]),
+            ("CLI", vec!["Go"]),
+        ];
+
+        for (i, (category, languages)) in projects.into_iter().enumerate() {
+            Metadata::builder()
+                .title(&format!("test_{i}"))
+                .directory(".")
+                .add_category(category)
+                .languages(languages.into_iter().map(Language::new).collect())
+                .build()
+                .unwrap()
+                .write_to_db(&db)
+                .await
+                .unwrap();
+        }
+
+        let filter = Filter {
+            category: Some("Web".to_string()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+
+        let by_language = Statistic::projects_by_language(&db, &condition)
+            .await
+            .unwrap();
+        assert_eq!(
+            by_language,
+            HashMap::from([("Rust".to_string(), 2), ("TypeScript".to_string(), 1)])
+        );
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            Statistic::number_of_languages(&db, &condition)
+                .await
+                .unwrap(),
+            2
+        );
+
+        let filter = Filter {
+            query: Some("'; DROP TABLE metadata; --".to_string()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            Statistic::number_of_projects(&db, &Condition::default())
+                .await
+                .unwrap(),
+            3
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_statistics_updated_since(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db_for_timeline(&db).await;
+
+        let filter = Filter {
+            updated_since: Some("2024-06-01T00:00:00Z".parse().unwrap()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            2
+        );
+        let created = Statistic::projects_created_per_period(&db, Bucket::Year, &condition)
+            .await
+            .unwrap();
+        assert_eq!(created[0].count, 2);
+        assert!(Statistic::stale_projects(&db, 30, &condition)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_statistics_version_constraint(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+
+        for (title, language, build_system) in [
+            ("Old", Language::with_version("Rust", "1.70"), "1.70"),
+            ("New", Language::with_version("Rust", "v1.84"), "1.84.1"),
+            ("Nightly", Language::with_version("Rust", "nightly"), "1.85"),
+            ("Python", Language::with_version("Python", "3.12"), "1.84"),
+        ] {
+            Metadata::builder()
+                .title(title)
+                .directory(".")
+                .add_language(language)
+                .add_build_system(BuildSystem::with_version("Cargo", build_system))
+                .build()
+                .unwrap()
+                .write_to_db(&db)
+                .await
+                .unwrap();
+        }
+
+        let filter = Filter {
+            language: Some("rust".to_string()),
+            language_version: Some(">=1.80".parse().unwrap()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            1
+        );
+
+        let filter = Filter {
+            language_version: Some("nightly".parse().unwrap()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            1
+        );
+
+        let filter = Filter {
+            build_system: Some("Cargo".to_string()),
+            build_system_version: Some("^1.84".parse().unwrap()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            3
+        );
+
+        let filter = Filter {
+            language_version: Some(">=4".parse().unwrap()),
+            ..Filter::default()
+        };
+        let condition = Condition::new(&db, &filter).await.unwrap();
+        assert_eq!(
+            Statistic::number_of_projects(&db, &condition)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
     #[sqlx::test]
     async fn test_number_of_languages(conn: SqlitePool) {
         let db = DatabaseHandler::with_conn(conn);
 
         for i in 0..METADATA_AMOUNT {
-            let result = Statistic::number_of_languages(&db).await.unwrap();
+            let result = Statistic::number_of_languages(&db, &Condition::default())
+                .await
+                .unwrap();
             assert_eq!(result, i);
 
             Metadata::builder()
@@ -246,7 +1298,9 @@ mod tests {
                 .unwrap();
         }
 
-        let result = Statistic::number_of_languages(&db).await.unwrap();
+        let result = Statistic::number_of_languages(&db, &Condition::default())
+            .await
+            .unwrap();
         assert_eq!(result, METADATA_AMOUNT);
     }
 }
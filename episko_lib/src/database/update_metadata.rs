@@ -1,4 +1,5 @@
 use log::info;
+use sqlx::{types::Json, SqliteConnection};
 
 use crate::metadata::property::Property;
 use crate::metadata::Metadata;
@@ -18,11 +19,21 @@ impl Metadata {
     /// Returns [Ok] when the item was updated.
     pub async fn update_in_db(&self, db: &DatabaseHandler) -> Result<()> {
         let mut transaction = db.conn().begin().await?;
+        self.update_with(&mut transaction).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
 
+    /// Update a given metadata using the given executor, e.g. a transaction
+    /// shared by multiple updates.
+    ///
+    /// # Errors
+    /// - if any of the database queries fail
+    pub(crate) async fn update_with(&self, executor: &mut SqliteConnection) -> Result<()> {
         info!("Updating: {self:#?}");
 
         if let Some(ide) = &self.preferred_ide {
-            ide.write_to_db(&mut *transaction).await?;
+            ide.write_to_db(&mut *executor).await?;
         }
 
         let ide_id = self
@@ -36,7 +47,8 @@ impl Metadata {
                  title = ?,
                  description = ?,
                  preferred_ide = ?,
-                 repository_url = ?,
+                 parent_id = ?,
+                 subprojects = ?,
                  created = ?,
                  updated = ?,
                  checksum = ?
@@ -46,7 +58,8 @@ impl Metadata {
         .bind(&self.title)
         .bind(&self.description)
         .bind(&ide_id)
-        .bind(&self.repository_url)
+        .bind(self.parent)
+        .bind(Json(&self.subprojects))
         .bind(self.created)
         .bind(self.updated)
         .bind(
@@ -55,59 +68,40 @@ impl Metadata {
                 .to_vec(),
         )
         .bind(self.id)
-        .execute(&mut *transaction)
+        .execute(&mut *executor)
         .await?;
 
-        sqlx::query("DELETE FROM rel_metadata_category WHERE metadata_id = ?")
-            .bind(self.id)
-            .execute(&mut *transaction)
+        self.replace_relations(executor, "category", &self.categories)
             .await?;
-
-        for category in &self.categories {
-            // Make sure the related category exists.
-            if !category.exists(&mut *transaction).await? {
-                category.write_to_db(&mut *transaction).await?;
-            }
-            sqlx::query("INSERT INTO rel_metadata_category(metadata_id, category_id) VALUES(?, ?)")
-                .bind(self.id)
-                .bind(category.generate_id().as_slice())
-                .execute(&mut *transaction)
-                .await?;
-        }
-
-        sqlx::query("DELETE FROM rel_metadata_language WHERE metadata_id = ?")
-            .bind(self.id)
-            .execute(&mut *transaction)
+        self.replace_relations(executor, "language", &self.languages)
             .await?;
-        for language in &self.languages {
-            if !language.exists(&mut *transaction).await? {
-                language.write_to_db(&mut *transaction).await?;
-            }
-            sqlx::query("INSERT INTO rel_metadata_language(metadata_id, language_id) VALUES(?, ?)")
-                .bind(self.id)
-                .bind(language.generate_id().as_slice())
-                .execute(&mut *transaction)
-                .await?;
-        }
-
-        sqlx::query("DELETE FROM rel_metadata_build_system WHERE metadata_id = ?")
-            .bind(self.id)
-            .execute(&mut *transaction)
+        self.replace_relations(executor, "build_system", &self.build_systems)
             .await?;
-        for build_system in &self.build_systems {
-            if !build_system.exists(&mut *transaction).await? {
-                build_system.write_to_db(&mut *transaction).await?;
-            }
-            sqlx::query(
-                "INSERT INTO rel_metadata_build_system(metadata_id, build_system_id) VALUES(?, ?)",
-            )
-            .bind(self.id)
-            .bind(build_system.generate_id().as_slice())
-            .execute(&mut *transaction)
+        self.replace_relations(executor, "license", &self.licenses)
             .await?;
-        }
+        self.replace_relations(executor, "framework", &self.frameworks)
+            .await?;
+        self.replace_relations(executor, "person", &self.people)
+            .await?;
+        self.handle_links(executor).await
+    }
 
-        transaction.commit().await?;
-        Ok(())
+    /// Replace all relations of the given type with the given items.
+    ///
+    /// Related items are created if they don't exist yet.
+    async fn replace_relations<T: DatabaseObject + Property>(
+        &self,
+        executor: &mut SqliteConnection,
+        relation_type: &str,
+        items: &[T],
+    ) -> Result<()> {
+        sqlx::query(&format!(
+            "DELETE FROM rel_metadata_{relation_type} WHERE metadata_id = ?"
+        ))
+        .bind(self.id)
+        .execute(&mut *executor)
+        .await?;
+
+        self.handle_relations(executor, relation_type, items).await
     }
 }
@@ -0,0 +1,183 @@
+//! # Export of the project catalogue
+//!
+//! This module contains the functionality to render [`Metadata`] into
+//! formats which can be used outside of episko.
+//!
+//! ## Formats
+//! - [`ExportFormat::Json`]: All fields, including the project directory
+//! - [`ExportFormat::Csv`]: One row per project, multi-valued fields are
+//!   joined using [`csv::VALUE_SEPARATOR`]
+//! - [`ExportFormat::Markdown`]: An overview table of all projects
+//!
+//! Projects can be exported from the cache, using the [`ExportHandler`].
+use std::{fmt::Display, path::Path, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::metadata::{property::Property, Metadata};
+
+pub mod csv;
+pub mod export_handler;
+pub mod markdown;
+
+pub use export_handler::ExportHandler;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Available formats to export projects to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    /// Render the given projects in the format.
+    ///
+    /// # Errors
+    /// - [`Error::Json`] when the projects cannot be serialized to json
+    pub fn render(self, projects: &[Metadata]) -> Result<String> {
+        match self {
+            ExportFormat::Json => json(projects),
+            ExportFormat::Csv => Ok(csv::render(projects)),
+            ExportFormat::Markdown => Ok(markdown::render(projects)),
+        }
+    }
+
+    /// Common file extension of the format.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ExportFormat::Json),
+            "csv" => Ok(ExportFormat::Csv),
+            "markdown" | "md" => Ok(ExportFormat::Markdown),
+            _ => Err(Error::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Markdown => "markdown",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Json representation of a project, as [`Metadata`] skips its directory
+/// when serializing.
+#[derive(Serialize)]
+struct JsonEntry<'a> {
+    #[serde(flatten)]
+    metadata: &'a Metadata,
+    directory: &'a Path,
+}
+
+fn json(projects: &[Metadata]) -> Result<String> {
+    let entries: Vec<JsonEntry> = projects
+        .iter()
+        .map(|metadata| JsonEntry {
+            metadata,
+            directory: project_directory(metadata),
+        })
+        .collect();
+
+    Ok(serde_json::to_string_pretty(&entries)?)
+}
+
+/// Directory of the project, which is the directory containing its manifest.
+fn project_directory(metadata: &Metadata) -> &Path {
+    metadata
+        .directory()
+        .parent()
+        .unwrap_or(metadata.directory())
+}
+
+/// Display a property as `<NAME>:<VERSION>`, matching the syntax of the cli.
+fn display_property<T: Property>(property: &T) -> String {
+    match property.version() {
+        Some(version) if !version.is_empty() => format!("{}:{version}", property.name()),
+        _ => property.name().to_string(),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("unknown export format: {0}")]
+    UnknownFormat(String),
+
+    #[error("failed to serialize projects")]
+    Json(#[from] serde_json::Error),
+
+    #[error("io error")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to retrieve projects")]
+    Db(#[from] crate::database::Error),
+}
+
+#[cfg(test)]
+pub(crate) mod export_test {
+    use crate::metadata::{BuildSystem, Language, Metadata};
+
+    /// Metadata containing values which have to be escaped by most formats.
+    pub fn metadata() -> Metadata {
+        Metadata::builder()
+            .title("Episko | Catalogue")
+            .directory("/")
+            .description("Manage \"all\" projects,\nfast")
+            .add_category("Web")
+            .add_category("Cli")
+            .add_language(Language::with_version("Rust", "1.84"))
+            .add_language(Language::with_version("TypeScript", ""))
+            .add_build_system(BuildSystem::with_version("Cargo", "1.84"))
+            .repository_url("https://example.com/episko")
+            .build()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(ExportFormat::from_str("JSON").unwrap(), ExportFormat::Json);
+        assert_eq!(
+            ExportFormat::from_str("md").unwrap(),
+            ExportFormat::Markdown
+        );
+        assert!(ExportFormat::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn test_json_contains_directory() {
+        let metadata = export_test::metadata();
+
+        let rendered = ExportFormat::Json.render(&[metadata]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(value[0]["title"], "Episko | Catalogue");
+        assert_eq!(value[0]["directory"], "/");
+        assert_eq!(value[0]["language"][0]["name"], "Rust");
+    }
+}
@@ -0,0 +1,144 @@
+//! Submodule of [`crate::export`] for rendering projects as CSV.
+//!
+//! Fields are quoted as described in RFC 4180, whenever they contain
+//! a separator, quote or line break.
+use std::fmt::Write as _;
+
+use crate::metadata::Metadata;
+
+use super::{display_property, project_directory};
+
+/// Columns of the CSV export, in order.
+pub static COLUMNS: [&str; 12] = [
+    "id",
+    "title",
+    "description",
+    "directory",
+    "categories",
+    "languages",
+    "build_systems",
+    "preferred_ide",
+    "repository_url",
+    "links",
+    "created",
+    "updated",
+];
+
+/// Separator used to join multi-valued fields, such as categories.
+///
+/// Occurrences inside a value are escaped with a backslash,
+/// see [`split_values`].
+pub static VALUE_SEPARATOR: &str = ";";
+
+/// Render the given projects as CSV, including a header row.
+#[must_use]
+pub fn render(projects: &[Metadata]) -> String {
+    let mut output = String::new();
+    write_row(&mut output, COLUMNS.iter().map(ToString::to_string));
+
+    for metadata in projects {
+        write_row(
+            &mut output,
+            [
+                metadata.id.to_string(),
+                metadata.title.clone(),
+                metadata.description.clone().unwrap_or_default(),
+                project_directory(metadata).display().to_string(),
+                join(metadata.categories.iter().map(display_property)),
+                join(metadata.languages.iter().map(display_property)),
+                join(metadata.build_systems.iter().map(display_property)),
+                metadata
+                    .preferred_ide
+                    .as_ref()
+                    .map(display_property)
+                    .unwrap_or_default(),
+                metadata.repository_url().unwrap_or_default().to_string(),
+                join(metadata.links.iter().map(ToString::to_string)),
+                metadata.created.to_rfc3339(),
+                metadata.updated.to_rfc3339(),
+            ],
+        );
+    }
+
+    output
+}
+
+/// Quote a single field if required.
+#[must_use]
+pub fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_row(output: &mut String, fields: impl IntoIterator<Item = String>) {
+    let row: Vec<String> = fields.into_iter().map(|el| escape(&el)).collect();
+    let _ = writeln!(output, "{}", row.join(","));
+}
+
+fn join(values: impl Iterator<Item = String>) -> String {
+    values
+        .map(|el| {
+            el.replace('\\', "\\\\")
+                .replace(VALUE_SEPARATOR, &format!("\\{VALUE_SEPARATOR}"))
+        })
+        .collect::<Vec<_>>()
+        .join(VALUE_SEPARATOR)
+}
+
+/// Split a multi-valued field on unescaped [`VALUE_SEPARATOR`]s.
+///
+/// Reverses the escaping applied during rendering.
+#[must_use]
+pub fn split_values(field: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut value = String::new();
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.extend(chars.next()),
+            _ if VALUE_SEPARATOR.starts_with(c) => values.push(std::mem::take(&mut value)),
+            _ => value.push(c),
+        }
+    }
+    values.push(value);
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::export_test;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape("a,b"), "\"a,b\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_render() {
+        let metadata = export_test::metadata();
+
+        let rendered = render(&[metadata]);
+
+        assert!(rendered.starts_with(&format!("{}\n", COLUMNS.join(","))));
+        assert!(rendered.contains(",Episko | Catalogue,\"Manage \"\"all\"\" projects,\nfast\","));
+        assert!(rendered.contains(",Cli;Web,Rust:1.84;TypeScript,Cargo:1.84,,"));
+    }
+
+    #[test]
+    fn test_join_escapes_separator() {
+        let values = ["R;D", "C:\\tools", "Web"].map(ToString::to_string);
+
+        let joined = join(values.clone().into_iter());
+
+        assert_eq!(joined, "R\\;D;C:\\\\tools;Web");
+        assert_eq!(split_values(&joined), values);
+    }
+}
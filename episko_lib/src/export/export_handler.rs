@@ -0,0 +1,162 @@
+//! # Export handling
+use std::{fs, path::Path};
+
+use crate::{
+    database::{DatabaseHandler, Filter},
+    metadata::Metadata,
+};
+
+use super::{ExportFormat, Result};
+
+pub struct ExportHandler;
+
+impl ExportHandler {
+    /// Retrieve all cached projects matching the given [`Filter`].
+    ///
+    /// The filter is only used to select projects, all properties of the
+    /// matching projects are retrieved.
+    ///
+    /// # Errors
+    /// - if any of the database queries fail
+    pub async fn projects(filter: Filter, db: &DatabaseHandler) -> Result<Vec<Metadata>> {
+        if filter.is_empty() {
+            return Ok(Metadata::all_from_db(None, db).await?);
+        }
+
+        let previews = Metadata::all_preview_from_db(None, filter, db).await?;
+
+        let mut projects = Vec::with_capacity(previews.len());
+        for preview in previews {
+            projects.push(Metadata::from_db(db, preview.id).await?);
+        }
+
+        Ok(projects)
+    }
+
+    /// Render all cached projects matching the given [`Filter`].
+    ///
+    /// # Errors
+    /// - if the projects cannot be retrieved
+    /// - if the projects cannot be rendered, see [`ExportFormat::render`]
+    pub async fn export(
+        format: ExportFormat,
+        filter: Filter,
+        db: &DatabaseHandler,
+    ) -> Result<String> {
+        format.render(&Self::projects(filter, db).await?)
+    }
+
+    /// Render all cached projects matching the given [`Filter`] to a file.
+    ///
+    /// Returns the amount of exported projects.
+    ///
+    /// # Errors
+    /// - if the projects cannot be retrieved or rendered
+    /// - if the file cannot be written
+    pub async fn export_to_file(
+        format: ExportFormat,
+        filter: Filter,
+        path: &Path,
+        db: &DatabaseHandler,
+    ) -> Result<usize> {
+        let projects = Self::projects(filter, db).await?;
+        fs::write(path, format.render(&projects)?)?;
+
+        Ok(projects.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use chrono::{Duration, Utc};
+
+    use super::*;
+    use crate::{database::db_test::fill_db, files::files_test::temp_dir};
+
+    #[sqlx::test]
+    async fn test_filtered_export_keeps_all_properties(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db(20, &db).await;
+        Metadata::builder()
+            .title("Multiple categories")
+            .directory(".")
+            .add_category("Web")
+            .add_category("Tooling")
+            .build()
+            .unwrap()
+            .write_to_db(&db)
+            .await
+            .unwrap();
+
+        let all = ExportHandler::projects(Filter::default(), &db)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 21);
+
+        let filter = Filter {
+            category: Some("Web".to_string()),
+            ..Filter::default()
+        };
+        let filtered = ExportHandler::projects(filter, &db).await.unwrap();
+
+        assert!(!filtered.is_empty() && filtered.len() < all.len());
+        let project = filtered
+            .iter()
+            .find(|el| el.title == "Multiple categories")
+            .unwrap();
+        assert_eq!(project.categories.len(), 2);
+    }
+
+    #[sqlx::test]
+    async fn test_export_with_other_filters(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db(5, &db).await;
+        Metadata::builder()
+            .title("Licensed")
+            .directory(".")
+            .add_license("MIT")
+            .build()
+            .unwrap()
+            .write_to_db(&db)
+            .await
+            .unwrap();
+
+        let filter = Filter {
+            license: Some("MIT".to_string()),
+            ..Filter::default()
+        };
+        let filtered = ExportHandler::projects(filter, &db).await.unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].title, "Licensed");
+
+        let filter = Filter {
+            updated_since: Some(Utc::now() + Duration::days(1)),
+            ..Filter::default()
+        };
+        assert!(ExportHandler::projects(filter, &db)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_export_to_file(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db(5, &db).await;
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("export.csv");
+
+        let amount =
+            ExportHandler::export_to_file(ExportFormat::Csv, Filter::default(), &path, &db)
+                .await
+                .unwrap();
+
+        assert_eq!(amount, 5);
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 6);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+//! Submodule of [`crate::export`] for rendering projects as a Markdown table.
+use std::fmt::Write as _;
+
+use crate::metadata::Metadata;
+
+use super::display_property;
+
+/// Render an overview table of the given projects.
+///
+/// The title links to the repository, if one is set.
+#[must_use]
+pub fn render(projects: &[Metadata]) -> String {
+    let mut output = String::from(
+        "| Title | Description | Categories | Languages | Build systems | IDE | Updated |\n\
+         | --- | --- | --- | --- | --- | --- | --- |\n",
+    );
+
+    for metadata in projects {
+        let title = match metadata.repository_url() {
+            Some(url) => format!("[{}]({})", escape(&metadata.title), escape_url(url)),
+            None => escape(&metadata.title),
+        };
+
+        let _ = writeln!(
+            output,
+            "| {title} | {} | {} | {} | {} | {} | {} |",
+            escape(metadata.description.as_deref().unwrap_or_default()),
+            join(metadata.categories.iter().map(display_property)),
+            join(metadata.languages.iter().map(display_property)),
+            join(metadata.build_systems.iter().map(display_property)),
+            metadata
+                .preferred_ide
+                .as_ref()
+                .map(display_property)
+                .unwrap_or_default(),
+            metadata.updated.format("%Y-%m-%d"),
+        );
+    }
+
+    output
+}
+
+/// Escape characters which would break the table layout.
+fn escape(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Percent-encode characters of a url which would end the link destination
+/// or break the table layout.
+fn escape_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for char in url.chars() {
+        if char.is_control() || " ()<>[]|\\\"'`".contains(char) {
+            let mut buf = [0; 4];
+            for byte in char.encode_utf8(&mut buf).bytes() {
+                let _ = write!(escaped, "%{byte:02X}");
+            }
+        } else {
+            escaped.push(char);
+        }
+    }
+    escaped
+}
+
+fn join(values: impl Iterator<Item = String>) -> String {
+    escape(&values.collect::<Vec<_>>().join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::export_test;
+
+    #[test]
+    fn test_render() {
+        let metadata = export_test::metadata();
+
+        let rendered = render(&[metadata]);
+        let row = rendered.lines().nth(2).unwrap();
+
+        assert!(row.starts_with("| [Episko \\| Catalogue](https://example.com/episko) |"));
+        assert!(row.contains("| Manage \"all\" projects,<br>fast |"));
+        assert!(row.contains("| Cli, Web | Rust:1.84, TypeScript | Cargo:1.84 |  |"));
+    }
+
+    #[test]
+    fn test_escape_url() {
+        assert_eq!(
+            escape_url("https://example.com/a (b)|c"),
+            "https://example.com/a%20%28b%29%7Cc"
+        );
+        assert_eq!(
+            escape_url("https://example.com/?q=ä"),
+            "https://example.com/?q=ä"
+        );
+    }
+}
@@ -13,14 +13,36 @@
 //! The `FileHandler` struct can also be used directly for structs that can't
 //! implement the `File` trait because of the [orphan rule](https://doc.rust-lang.org/book/ch10-02-traits.html)
 //! but implementing `File` should be preferred.
+//!
+//! ## Embedded manifests
+//!
+//! Besides dedicated manifests, metadata can also be embedded into a
+//! `Cargo.toml` or `package.json`. See [`embedded`] for details on how
+//! these sources are handled.
+//!
+//! ## Parse errors
+//!
+//! Files which can't be parsed result in an [`Error::Parse`], carrying the
+//! path of the file and the location of the offending value, see [`parse_error`].
+//!
+//! ## Schema
+//!
+//! A JSON Schema of the manifest format is generated from the metadata,
+//! see [`schema`]. It is used to report all problems of a manifest at once.
+//! Manifests within whole directories can be checked without touching the
+//! cache or config, see [`check`].
 use std::path::Path;
 
 use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
 
+pub mod check;
 pub mod config;
+pub mod embedded;
 pub mod file_handler;
 pub mod metadata;
+pub mod parse_error;
+pub mod schema;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -80,21 +102,61 @@ pub trait File: Serialize + DeserializeOwned {
 /// Errors used within this module.
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("io error")]
+    #[error("io error: {0}")]
     Io(#[from] std::io::Error),
 
-    #[error("toml serialization error")]
+    #[error("failed to parse {}", .0.path.display())]
+    Parse(#[from] parse_error::ParseError),
+
+    #[error("toml serialization error: {0}")]
     TomlSerialization(#[from] toml::ser::Error),
 
-    #[error("toml deserialization error")]
+    #[error("toml deserialization error: {}", .0.message().trim())]
     TomlDeserialization(#[from] toml::de::Error),
 
+    #[error("toml edit error: {0}")]
+    TomlEdit(#[from] toml_edit::TomlError),
+
+    #[error("toml edit serialization error: {0}")]
+    TomlEditSerialization(#[from] toml_edit::ser::Error),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid glob pattern: {0}")]
+    Pattern(#[from] glob::PatternError),
+
+    #[error("no episko metadata found in {0}")]
+    MissingMetadata(String),
+
     #[error("path {0} already exists")]
     PathExists(String),
 
     #[error("path {0} does not exist")]
     PathDoesNotExist(String),
 
-    #[error("validation error")]
+    #[error("no manifests found in {0}")]
+    NoManifests(String),
+
+    #[error("unknown format: {0}")]
+    UnknownFormat(String),
+
+    #[error("validation error: {0}")]
     Invalid(#[from] crate::metadata::Error),
 }
+
+#[cfg(test)]
+pub mod files_test {
+    use std::path::PathBuf;
+
+    use uuid::Uuid;
+
+    /// Create a new, empty directory within the systems temp directory.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("episko-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        dir
+    }
+}
@@ -0,0 +1,389 @@
+//! # Read-only checks of manifests
+//!
+//! Every manifest found within the given paths is checked against the
+//! [`schema`](super::schema) and the validation of its values, see
+//! [`Metadata::validate`]. Neither the cache nor the config is touched,
+//! which makes the checks suitable for CI.
+//!
+//! ## Report formats
+//! - [`ReportFormat::Text`]: A summary, listing the problems of failed manifests
+//! - [`ReportFormat::Json`]: All checked manifests along with their problems
+//! - [`ReportFormat::Junit`]: `JUnit` XML, with one test case per manifest
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use glob::glob;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Aliases, Discovery},
+    metadata::{metadata_handler::MetadataHandler, Metadata},
+};
+
+use super::{Error, Result};
+
+/// Available formats of a [`CheckReport`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Json,
+    Junit,
+}
+
+/// Result of checking a single manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ManifestCheck {
+    pub path: PathBuf,
+    /// Problems found, empty if the manifest is valid.
+    pub problems: Vec<String>,
+}
+
+/// Results of checking multiple manifests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CheckReport {
+    pub checked: usize,
+    pub failed: usize,
+    pub manifests: Vec<ManifestCheck>,
+}
+
+impl ManifestCheck {
+    /// Check the manifest at the given path, read using the given
+    /// [`Discovery`] settings and aliases.
+    ///
+    /// Manifests violating the schema are not validated any further,
+    /// as they can't be deserialized.
+    #[must_use]
+    pub fn run(path: &Path, discovery: &Discovery, aliases: &Aliases) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            problems: problems(path, discovery, aliases),
+        }
+    }
+
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl CheckReport {
+    /// Check all manifests found within the given paths, see [`find_manifests`].
+    ///
+    /// # Errors
+    /// - Propagates errors from [`find_manifests`]
+    pub fn run(paths: &[&str], discovery: &Discovery, aliases: &Aliases) -> Result<Self> {
+        let mut manifests = BTreeSet::new();
+        for path in paths {
+            manifests.extend(find_manifests(path, discovery)?);
+        }
+
+        Ok(manifests
+            .iter()
+            .map(|manifest| ManifestCheck::run(manifest, discovery, aliases))
+            .collect())
+    }
+
+    /// Whether all checked manifests are valid.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.failed == 0
+    }
+
+    /// Render the report in the given format.
+    ///
+    /// # Errors
+    /// - [`Error::Json`] when the report cannot be serialized to json
+    pub fn render(&self, format: ReportFormat) -> Result<String> {
+        match format {
+            ReportFormat::Text => Ok(self.text()),
+            ReportFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            ReportFormat::Junit => Ok(self.junit()),
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+        for manifest in &self.manifests {
+            let status = if manifest.passed() { "ok" } else { "FAILED" };
+            let _ = writeln!(text, "{status}: {}", manifest.path.display());
+
+            for problem in &manifest.problems {
+                let _ = writeln!(text, "  - {problem}");
+            }
+        }
+
+        let _ = write!(
+            text,
+            "{} manifest(s) checked, {} failed",
+            self.checked, self.failed
+        );
+        text
+    }
+
+    fn junit(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"episko\" tests=\"{0}\" failures=\"{1}\">\n  <testsuite name=\"manifests\" tests=\"{0}\" failures=\"{1}\">",
+            self.checked, self.failed
+        );
+
+        for manifest in &self.manifests {
+            let name = escape_xml(&manifest.path.display().to_string());
+            if manifest.passed() {
+                let _ = writeln!(
+                    xml,
+                    "    <testcase classname=\"manifest\" name=\"{name}\"/>"
+                );
+                continue;
+            }
+
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"manifest\" name=\"{name}\">\n      <failure message=\"{} problem(s)\">{}</failure>\n    </testcase>",
+                manifest.problems.len(),
+                escape_xml(&manifest.problems.join("\n"))
+            );
+        }
+
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+impl FromIterator<ManifestCheck> for CheckReport {
+    fn from_iter<T: IntoIterator<Item = ManifestCheck>>(iter: T) -> Self {
+        let manifests: Vec<ManifestCheck> = iter.into_iter().collect();
+
+        Self {
+            checked: manifests.len(),
+            failed: manifests.iter().filter(|el| !el.passed()).count(),
+            manifests,
+        }
+    }
+}
+
+/// Find all manifests within the given path, which may be a manifest,
+/// a directory, searched as described in [`MetadataHandler::search_directory`],
+/// or a glob matching either of them.
+///
+/// # Errors
+/// - [`Error::PathDoesNotExist`] when nothing matches the given path
+/// - [`Error::NoManifests`] when the given path contains no manifests
+/// - [`Error::Pattern`] when the given glob is invalid
+/// - [`Error::Invalid`] when searching a directory fails
+pub fn find_manifests(path: &str, discovery: &Discovery) -> Result<Vec<PathBuf>> {
+    let candidates: Vec<PathBuf> = if Path::new(path).exists() {
+        vec![PathBuf::from(path)]
+    } else {
+        glob(path)?
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|err| std::io::Error::new(err.error().kind(), err.to_string()))?
+    };
+
+    if candidates.is_empty() {
+        return Err(Error::PathDoesNotExist(path.to_string()));
+    }
+
+    let mut manifests = vec![];
+    for candidate in candidates {
+        if candidate.is_dir() {
+            manifests.extend(MetadataHandler::search_directory(&candidate, discovery)?);
+        } else {
+            manifests.push(candidate);
+        }
+    }
+
+    if manifests.is_empty() {
+        return Err(Error::NoManifests(path.to_string()));
+    }
+
+    Ok(manifests)
+}
+
+fn problems(path: &Path, discovery: &Discovery, aliases: &Aliases) -> Vec<String> {
+    match Metadata::schema_errors(path, discovery) {
+        Ok(errors) if !errors.is_empty() => {
+            return errors.iter().map(ToString::to_string).collect();
+        }
+        Ok(_) => {}
+        Err(err) => return vec![describe(&err)],
+    }
+
+    match Metadata::from_file_with(path, discovery, aliases) {
+        Ok(metadata) => match metadata.validate() {
+            Ok(()) => vec![],
+            Err(err) => err.problems().iter().map(ToString::to_string).collect(),
+        },
+        Err(err) => vec![describe(&err)],
+    }
+}
+
+/// Describe the error, including the location of parse errors.
+fn describe(err: &Error) -> String {
+    match err {
+        Error::Parse(parse_error) => parse_error.to_string(),
+        err => err.to_string(),
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+impl FromStr for ReportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(ReportFormat::Text),
+            "json" => Ok(ReportFormat::Json),
+            "junit" | "xml" => Ok(ReportFormat::Junit),
+            _ => Err(Error::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ReportFormat::Text => "text",
+            ReportFormat::Json => "json",
+            ReportFormat::Junit => "junit",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::files::{files_test::temp_dir, File};
+
+    fn write_manifest(dir: &Path, title: &str) -> PathBuf {
+        fs::create_dir_all(dir).unwrap();
+        let metadata = Metadata::builder()
+            .title(title)
+            .directory_path(dir)
+            .build()
+            .unwrap();
+        let path = dir.join("manifest.toml");
+        metadata.write_file(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_check_directory() {
+        let dir = temp_dir();
+        write_manifest(&dir.join("valid"), "Valid");
+        let broken = write_manifest(&dir.join("broken"), "Broken");
+        fs::write(&broken, "id = 1\ntitle = \"Broken\"\n").unwrap();
+        let invalid = write_manifest(&dir.join("invalid"), "Invalid");
+        let content = fs::read_to_string(&invalid).unwrap();
+        fs::write(&invalid, content.replace("Invalid", " ")).unwrap();
+
+        let report = CheckReport::run(
+            &[dir.to_str().unwrap()],
+            &Discovery::default(),
+            &Aliases::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.failed, 2);
+        assert!(!report.passed());
+
+        let broken = report
+            .manifests
+            .iter()
+            .find(|el| el.path.ends_with("broken/manifest.toml"))
+            .unwrap();
+        assert!(broken.problems.iter().any(|el| el.starts_with("id: ")));
+
+        let invalid = report
+            .manifests
+            .iter()
+            .find(|el| el.path.ends_with("invalid/manifest.toml"))
+            .unwrap();
+        assert_eq!(invalid.problems, vec!["invalid title: \" \"".to_string()]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_glob() {
+        let dir = temp_dir();
+        write_manifest(&dir.join("a"), "A");
+        write_manifest(&dir.join("b"), "B");
+
+        let pattern = format!("{}/*/manifest.toml", dir.display());
+        let report =
+            CheckReport::run(&[&pattern], &Discovery::default(), &Aliases::default()).unwrap();
+        assert_eq!(report.checked, 2);
+        assert!(report.passed());
+
+        let missing = format!("{}/missing/*", dir.display());
+        assert!(matches!(
+            CheckReport::run(&[&missing], &Discovery::default(), &Aliases::default()),
+            Err(Error::PathDoesNotExist(_))
+        ));
+
+        fs::create_dir_all(dir.join("empty")).unwrap();
+        let empty = dir.join("empty");
+        assert!(matches!(
+            CheckReport::run(
+                &[empty.to_str().unwrap()],
+                &Discovery::default(),
+                &Aliases::default()
+            ),
+            Err(Error::NoManifests(_))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_report() {
+        let report: CheckReport = [
+            ManifestCheck {
+                path: PathBuf::from("a/manifest.toml"),
+                problems: vec![],
+            },
+            ManifestCheck {
+                path: PathBuf::from("b/manifest.toml"),
+                problems: vec!["title: \"title\" is a required property".to_string()],
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            report.render(ReportFormat::Text).unwrap(),
+            "ok: a/manifest.toml\nFAILED: b/manifest.toml\n  - title: \"title\" is a required property\n2 manifest(s) checked, 1 failed"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(ReportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["failed"], 1);
+        assert_eq!(json["manifests"][1]["path"], "b/manifest.toml");
+
+        let junit = report.render(ReportFormat::Junit).unwrap();
+        assert!(junit.contains("<testsuites name=\"episko\" tests=\"2\" failures=\"1\">"));
+        assert!(junit.contains("<testcase classname=\"manifest\" name=\"a/manifest.toml\"/>"));
+        assert!(junit.contains(
+            "<failure message=\"1 problem(s)\">title: &quot;title&quot; is a required property</failure>"
+        ));
+    }
+}
@@ -0,0 +1,476 @@
+//! # Manifest sources
+//!
+//! Besides dedicated manifests, named as configured in [`Discovery`],
+//! metadata can also be embedded into files most projects already carry:
+//! - `Cargo.toml` within the `[package.metadata.episko]` table
+//! - `package.json` within the `"episko"` key
+//!
+//! When a directory contains more than one of these sources, the dedicated
+//! manifest takes precedence, followed by `Cargo.toml` and lastly `package.json`.
+//!
+//! Dedicated manifest candidates which are valid toml, but lack the keys
+//! every episko manifest has, are considered to belong to other tools and
+//! are ignored.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use toml_edit::{DocumentMut, Item, Table};
+
+use crate::config::Discovery;
+
+use super::{
+    parse_error::{parse_json, parse_toml},
+    Error, Result,
+};
+
+pub static CARGO_FILE_NAME: &str = "Cargo.toml";
+pub static PACKAGE_JSON_FILE_NAME: &str = "package.json";
+
+/// Key under which the metadata is embedded in foreign files.
+static EMBEDDED_KEY: &str = "episko";
+
+/// Keys every dedicated manifest contains.
+static REQUIRED_KEYS: [&str; 2] = ["id", "title"];
+
+/// The different kinds of files metadata can be read from and written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestSource {
+    /// A dedicated manifest, e.g. `manifest.toml`
+    Dedicated,
+    /// The `[package.metadata.episko]` table of a `Cargo.toml`
+    Cargo,
+    /// The `"episko"` key of a `package.json`
+    PackageJson,
+}
+
+impl ManifestSource {
+    /// All embedded sources, ordered by their precedence.
+    pub const EMBEDDED: [Self; 2] = [Self::Cargo, Self::PackageJson];
+
+    /// Determine the source based on the file name of the given path.
+    ///
+    /// Files that are neither a `Cargo.toml` nor a `package.json` are
+    /// treated as dedicated manifests.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) if name == CARGO_FILE_NAME => Self::Cargo,
+            Some(name) if name == PACKAGE_JSON_FILE_NAME => Self::PackageJson,
+            _ => Self::Dedicated,
+        }
+    }
+
+    /// The file name used by embedded sources.
+    ///
+    /// Returns `None` for dedicated manifests, as their names are configurable.
+    #[must_use]
+    pub fn embedded_file_name(self) -> Option<&'static str> {
+        match self {
+            Self::Dedicated => None,
+            Self::Cargo => Some(CARGO_FILE_NAME),
+            Self::PackageJson => Some(PACKAGE_JSON_FILE_NAME),
+        }
+    }
+
+    /// Whether the source is embedded into a file not owned by episko.
+    #[must_use]
+    pub fn is_embedded(self) -> bool {
+        self != Self::Dedicated
+    }
+
+    /// All file names which may contain metadata, ordered by their precedence.
+    pub fn candidate_names(discovery: &Discovery) -> impl Iterator<Item = &str> {
+        discovery
+            .manifest_names
+            .iter()
+            .map(String::as_str)
+            .chain([CARGO_FILE_NAME, PACKAGE_JSON_FILE_NAME])
+    }
+
+    /// Locate the manifest with the highest precedence in the given directory.
+    #[must_use]
+    pub fn locate(dir: &Path, discovery: &Discovery) -> Option<PathBuf> {
+        Self::candidate_names(discovery)
+            .map(|name| dir.join(name))
+            .find(|path| Self::from_path(path).contains_metadata(path))
+    }
+
+    /// Check whether the given file exists and contains episko metadata.
+    ///
+    /// Dedicated manifests containing invalid toml are still considered
+    /// to contain metadata, so that errors are surfaced when reading them.
+    #[must_use]
+    pub fn contains_metadata(self, path: &Path) -> bool {
+        if !path.is_file() {
+            return false;
+        }
+
+        match self {
+            Self::Dedicated => looks_like_manifest(path),
+            Self::Cargo => read_cargo_section(path).is_ok_and(|section| section.is_some()),
+            Self::PackageJson => {
+                read_package_json_section(path).is_ok_and(|section| section.is_some())
+            }
+        }
+    }
+
+    /// Read data from the given file.
+    ///
+    /// # Errors
+    /// - [`Error::Io`] when a [`std::io::Error`] occurred
+    /// - [`Error::Parse`] when deserialization of toml or json data failed,
+    ///   pointing to the offending value within the whole file
+    /// - [`Error::MissingMetadata`] when an embedded source has no episko metadata
+    pub fn read<T: DeserializeOwned>(self, path: &Path) -> Result<T> {
+        let content = fs::read_to_string(path)?;
+
+        match self {
+            Self::Dedicated => parse_toml(path, &content),
+            Self::Cargo => {
+                read_cargo_section(path)?.ok_or_else(|| missing_metadata(path))?;
+                let manifest: CargoManifest<T> = parse_toml(path, &content)?;
+                Ok(manifest.package.metadata.episko)
+            }
+            Self::PackageJson => {
+                read_package_json_section(path)?.ok_or_else(|| missing_metadata(path))?;
+                let package: PackageJson<T> = parse_json(path, &content)?;
+                Ok(package.episko)
+            }
+        }
+    }
+
+    /// Write data to the given file.
+    ///
+    /// Dedicated manifests are overwritten, while embedded sources only
+    /// replace their episko section and keep the remaining content intact.
+    ///
+    /// # Errors
+    /// - [`Error::Io`] when a [`std::io::Error`] occurred
+    /// - [`Error::PathDoesNotExist`] when writing into an embedded source that does not exist
+    /// - [`Error::TomlSerialization`] when serialization into toml failed
+    /// - [`Error::TomlEditSerialization`] when serialization into a `Cargo.toml` failed
+    /// - [`Error::TomlEdit`] when the existing `Cargo.toml` is invalid
+    /// - [`Error::Json`] when (de)serialization of json data failed
+    pub fn write(self, data: impl Serialize, path: &Path) -> Result<()> {
+        if self.is_embedded() && !path.exists() {
+            return Err(Error::PathDoesNotExist(path.display().to_string()));
+        }
+
+        match self {
+            Self::Dedicated => fs::write(path, toml::to_string(&data)?)?,
+            Self::Cargo => {
+                let mut section = toml_edit::ser::to_document(&data)?.as_table().clone();
+                section.set_implicit(false);
+
+                edit_cargo(path, |metadata| {
+                    metadata.insert(EMBEDDED_KEY, Item::Table(section));
+                })?;
+            }
+            Self::PackageJson => {
+                let value = serde_json::to_value(data)?;
+                edit_package_json(path, |package| {
+                    package.insert(EMBEDDED_KEY.to_string(), value);
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove the metadata from the given file.
+    ///
+    /// Dedicated manifests are deleted, while embedded sources only
+    /// lose their episko section.
+    ///
+    /// # Errors
+    /// - [`Error::Io`] when a [`std::io::Error`] occurred
+    /// - [`Error::TomlEdit`] when the existing `Cargo.toml` is invalid
+    /// - [`Error::Json`] when the existing `package.json` is invalid
+    pub fn remove(self, path: &Path) -> Result<()> {
+        match self {
+            Self::Dedicated => fs::remove_file(path)?,
+            Self::Cargo => edit_cargo(path, |metadata| {
+                metadata.remove(EMBEDDED_KEY);
+            })?,
+            Self::PackageJson => edit_package_json(path, |package| {
+                package.remove(EMBEDDED_KEY);
+            })?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Check whether a dedicated manifest candidate is an episko manifest and
+/// not e.g. another tool's `manifest.toml`.
+fn looks_like_manifest(path: &Path) -> bool {
+    match fs::read_to_string(path).map(|content| toml::from_str::<toml::Table>(&content)) {
+        Ok(Ok(table)) => REQUIRED_KEYS.iter().all(|key| table.contains_key(*key)),
+        Ok(Err(_)) => true,
+        Err(_) => false,
+    }
+}
+
+/// Path of the episko section within a `Cargo.toml`, used to deserialize
+/// the section directly from the file to keep the location of errors.
+#[derive(Deserialize)]
+struct CargoManifest<T> {
+    package: CargoPackage<T>,
+}
+
+#[derive(Deserialize)]
+struct CargoPackage<T> {
+    metadata: EmbeddedSection<T>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddedSection<T> {
+    episko: T,
+}
+
+/// Path of the episko section within a `package.json`.
+type PackageJson<T> = EmbeddedSection<T>;
+
+fn missing_metadata(path: &Path) -> Error {
+    Error::MissingMetadata(path.display().to_string())
+}
+
+/// Retrieve the `[package.metadata.episko]` table of a `Cargo.toml` if present.
+fn read_cargo_section(path: &Path) -> Result<Option<toml::Value>> {
+    let mut document: toml::Table = parse_toml(path, &fs::read_to_string(path)?)?;
+
+    Ok(document
+        .remove("package")
+        .and_then(|mut package| package.as_table_mut()?.remove("metadata"))
+        .and_then(|mut metadata| metadata.as_table_mut()?.remove(EMBEDDED_KEY)))
+}
+
+/// Retrieve the `"episko"` key of a `package.json` if present.
+fn read_package_json_section(path: &Path) -> Result<Option<serde_json::Value>> {
+    let mut package: serde_json::Value = parse_json(path, &fs::read_to_string(path)?)?;
+
+    Ok(package
+        .as_object_mut()
+        .and_then(|object| object.remove(EMBEDDED_KEY)))
+}
+
+/// Apply the given edit to the `[package.metadata]` table of a `Cargo.toml`,
+/// preserving the formatting and comments of the rest of the file.
+fn edit_cargo(path: &Path, edit: impl FnOnce(&mut Table)) -> Result<()> {
+    let mut document: DocumentMut = fs::read_to_string(path)?.parse()?;
+
+    let package = document
+        .entry("package")
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| missing_metadata(path))?;
+
+    let metadata = package
+        .entry("metadata")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .ok_or_else(|| missing_metadata(path))?;
+
+    edit(metadata);
+
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+/// Apply the given edit to the top level object of a `package.json`.
+fn edit_package_json(
+    path: &Path,
+    edit: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+) -> Result<()> {
+    let mut package: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+    let object = package
+        .as_object_mut()
+        .ok_or_else(|| missing_metadata(path))?;
+
+    edit(object);
+
+    let mut json = serde_json::to_string_pretty(&package)?;
+    json.push('\n');
+    fs::write(path, json)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::DEFAULT_MANIFEST_FILE_NAME,
+        files::{files_test::temp_dir, File},
+        metadata::Metadata,
+    };
+
+    const CARGO_TOML: &str = r#"# keep me
+[package]
+name = "example"
+version = "0.1.0"
+
+[dependencies]
+serde = "1"
+"#;
+
+    const PACKAGE_JSON: &str = r#"{
+  "name": "example",
+  "version": "0.1.0",
+  "scripts": {
+    "dev": "vite dev"
+  }
+}
+"#;
+
+    fn metadata(title: &str) -> Metadata {
+        Metadata::builder()
+            .title(title)
+            .directory("/")
+            .add_category("Web")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_source_from_path() {
+        assert_eq!(
+            ManifestSource::from_path(Path::new("/a/Cargo.toml")),
+            ManifestSource::Cargo
+        );
+        assert_eq!(
+            ManifestSource::from_path(Path::new("/a/package.json")),
+            ManifestSource::PackageJson
+        );
+        assert_eq!(
+            ManifestSource::from_path(Path::new("/a/manifest.toml")),
+            ManifestSource::Dedicated
+        );
+    }
+
+    #[test]
+    fn test_cargo_roundtrip_keeps_content() {
+        let dir = temp_dir();
+        let path = dir.join(CARGO_FILE_NAME);
+        fs::write(&path, CARGO_TOML).unwrap();
+
+        assert!(!ManifestSource::Cargo.contains_metadata(&path));
+
+        let expected = metadata("Cargo");
+        expected.write_file(&path).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# keep me"));
+        assert!(content.contains("[package.metadata.episko]"));
+        assert!(content.contains("serde = \"1\""));
+
+        let read = Metadata::from_file(&dir).unwrap();
+        assert_eq!(read.id, expected.id);
+        assert_eq!(read.categories, expected.categories);
+        assert_eq!(read.directory(), path.canonicalize().unwrap());
+
+        Metadata::remove_file(&path).unwrap();
+        assert!(path.exists());
+        assert!(!ManifestSource::Cargo.contains_metadata(&path));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_package_json_roundtrip_keeps_content() {
+        let dir = temp_dir();
+        let path = dir.join(PACKAGE_JSON_FILE_NAME);
+        fs::write(&path, PACKAGE_JSON).unwrap();
+
+        let expected = metadata("Package");
+        expected.write_file(&path).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.find("\"name\"").unwrap() < content.find("\"episko\"").unwrap());
+        assert!(content.contains("\"dev\": \"vite dev\""));
+
+        let read = Metadata::from_file(&path).unwrap();
+        assert_eq!(read.id, expected.id);
+        assert_eq!(read.title, "Package");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_dedicated_manifest_takes_precedence() {
+        let dir = temp_dir();
+        fs::write(dir.join(CARGO_FILE_NAME), CARGO_TOML).unwrap();
+        fs::write(dir.join(PACKAGE_JSON_FILE_NAME), PACKAGE_JSON).unwrap();
+
+        metadata("Package")
+            .write_file(&dir.join(PACKAGE_JSON_FILE_NAME))
+            .unwrap();
+        assert_eq!(
+            ManifestSource::locate(&dir, &Discovery::default()),
+            Some(dir.join(PACKAGE_JSON_FILE_NAME))
+        );
+
+        metadata("Cargo")
+            .write_file(&dir.join(CARGO_FILE_NAME))
+            .unwrap();
+        assert_eq!(
+            ManifestSource::locate(&dir, &Discovery::default()),
+            Some(dir.join(CARGO_FILE_NAME))
+        );
+
+        metadata("Dedicated")
+            .write_file(&dir.join(DEFAULT_MANIFEST_FILE_NAME))
+            .unwrap();
+        assert_eq!(
+            ManifestSource::locate(&dir, &Discovery::default()),
+            Some(dir.join(DEFAULT_MANIFEST_FILE_NAME))
+        );
+        assert_eq!(Metadata::from_file(&dir).unwrap().title, "Dedicated");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_foreign_manifest_is_ignored() {
+        let dir = temp_dir();
+        let path = dir.join(DEFAULT_MANIFEST_FILE_NAME);
+
+        fs::write(&path, "[dependencies]\nfoo = \"1\"\n").unwrap();
+        assert!(!ManifestSource::Dedicated.contains_metadata(&path));
+        assert_eq!(ManifestSource::locate(&dir, &Discovery::default()), None);
+
+        // Broken manifests are still found, so that errors are surfaced
+        fs::write(&path, "title = ").unwrap();
+        assert!(ManifestSource::Dedicated.contains_metadata(&path));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_locate_configured_manifest_name() {
+        let dir = temp_dir();
+        let discovery = Discovery {
+            manifest_names: vec![".episko.toml".to_string()],
+            ..Discovery::default()
+        };
+
+        metadata("Configured")
+            .write_file(&dir.join(".episko.toml"))
+            .unwrap();
+
+        assert_eq!(ManifestSource::locate(&dir, &Discovery::default()), None);
+        assert_eq!(
+            ManifestSource::locate(&dir, &discovery),
+            Some(dir.join(".episko.toml"))
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -6,7 +6,7 @@ use std::{fs, path::Path};
 
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::{Error, Result};
+use super::{parse_error::parse_toml, Error, Result};
 
 /// Utility struct for performing file operations with serialization/deserialization support.
 pub struct FileHandler;
@@ -73,8 +73,8 @@ impl FileHandler {
     /// # Errors
     ///
     /// - [`Error::Io`] when a `std::io::Error` occurred
-    /// - [`Error::TomlDeserialization`] when deserialization of the given data failed
+    /// - [`Error::Parse`] when deserialization of the given data failed
     pub fn read_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
-        Ok(toml::from_str(&fs::read_to_string(path)?)?)
+        parse_toml(path, &fs::read_to_string(path)?)
     }
 }
@@ -1,8 +1,203 @@
 //! This module contains implementations regarding file based operations
 //! for the [`Metadata`] struct.
-use crate::metadata::Metadata;
+//!
+//! Besides dedicated manifest files, metadata embedded into a `Cargo.toml`
+//! or `package.json` is supported as well, see [`super::embedded`].
+//!
+//! ## Sub-projects
+//! When reading a manifest, its parent is the closest manifest within the
+//! parent directories, if that manifest declares it as sub-project.
+//! Defaults of the parent are then inherited, see [`Metadata::inherit_from`].
+//!
+//! ## Legacy manifests
+//! Manifests written before links were supported declare a single
+//! `repository_url`, which is turned into a repository link when reading.
+//! The manifest itself is migrated the next time it is written.
+use std::path::{Path, PathBuf};
 
-use super::{file_handler::FileHandler, File, Result};
+use glob::glob;
+use serde::Deserialize;
+
+use crate::{
+    config::{Aliases, Discovery},
+    metadata::{link::complete_url, Error as MetadataError, License, Link, LinkKind, Metadata},
+};
+
+use super::{embedded::ManifestSource, File, Result};
+
+impl Metadata {
+    /// Read an instance from a file, using the given [`Discovery`] settings
+    /// when a directory is given.
+    ///
+    /// The manifest with the highest precedence within the directory is used,
+    /// as described in [`super::embedded`].
+    ///
+    /// If the manifest is declared as sub-project by a manifest in one of the
+    /// parent directories, the defaults of that parent are inherited.
+    ///
+    /// The given aliases of properties are replaced by their canonical names,
+    /// the file itself is left unchanged (see [`Metadata::normalise_file`]).
+    ///
+    /// # Errors
+    ///
+    /// - The same errors as [`ManifestSource::read`] are returned.
+    /// - [`super::Error::Invalid`] if a license isn't a valid SPDX expression,
+    ///   after resolving aliases
+    pub fn from_file_with(path: &Path, discovery: &Discovery, aliases: &Aliases) -> Result<Self> {
+        let mut metadata = Self::read_manifest(path, discovery)?;
+
+        if let Some(parent) = Self::find_parent(&metadata.directory, discovery, aliases) {
+            metadata.inherit_from(&parent);
+        }
+
+        metadata.normalise_properties(aliases);
+        for license in &metadata.licenses {
+            License::validate(&license.name)
+                .map_err(|_| MetadataError::InvalidLicense(license.name.clone()))?;
+        }
+
+        Ok(metadata)
+    }
+
+    /// Write the instance to a file, using the given [`Discovery`] settings
+    /// and aliases to read the parent of the manifest.
+    ///
+    /// When the file is a `Cargo.toml` or `package.json` only the episko
+    /// section of the file is replaced.
+    ///
+    /// Values inherited from the parent of the instance are not written,
+    /// see [`Metadata::strip_inherited`].
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`ManifestSource::write`] are returned.
+    pub fn write_file_with(
+        &self,
+        path: &Path,
+        discovery: &Discovery,
+        aliases: &Aliases,
+    ) -> Result<()> {
+        let source = ManifestSource::from_path(path);
+
+        if self.parent.is_some()
+            && let Ok(manifest) = path.canonicalize()
+            && let Some(parent) = Self::find_parent(&manifest, discovery, aliases)
+            && Some(parent.id) == self.parent
+        {
+            let mut own = self.clone();
+            own.strip_inherited(&parent);
+            return source.write(&own, path);
+        }
+
+        source.write(self, path)
+    }
+
+    /// Rewrite the manifest at the given path, replacing the given aliases
+    /// of properties with their canonical names.
+    ///
+    /// The file is only written if any property has been changed, which
+    /// is indicated by the returned value.
+    /// Values inherited from a parent are not written to the file.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`ManifestSource::read`] and [`ManifestSource::write`]
+    /// are returned.
+    pub fn normalise_file(path: &Path, discovery: &Discovery, aliases: &Aliases) -> Result<bool> {
+        let mut metadata = Self::read_manifest(path, discovery)?;
+
+        if !metadata.normalise_properties(aliases) {
+            return Ok(false);
+        }
+
+        metadata.write_file_with(&metadata.directory, discovery, aliases)?;
+        Ok(true)
+    }
+
+    /// Resolve the manifest files of all sub-projects declared by the instance.
+    ///
+    /// Each path or glob is resolved relative to the directory of the instances
+    /// manifest. Matched directories are searched for their manifest as described
+    /// in [`ManifestSource::locate`], matched files are used directly.
+    ///
+    /// # Errors
+    /// - [`super::Error::Pattern`] when a declared glob is invalid
+    pub fn subproject_manifests(&self, discovery: &Discovery) -> Result<Vec<PathBuf>> {
+        let Some(base) = self.directory.parent() else {
+            return Ok(vec![]);
+        };
+
+        let mut manifests = vec![];
+
+        for pattern in &self.subprojects {
+            let pattern = base.join(pattern);
+            let Some(pattern) = pattern.to_str() else {
+                continue;
+            };
+
+            for path in glob(pattern)?.filter_map(std::result::Result::ok) {
+                let manifest = if path.is_dir() {
+                    ManifestSource::locate(&path, discovery)
+                } else {
+                    ManifestSource::from_path(&path)
+                        .contains_metadata(&path)
+                        .then_some(path)
+                };
+
+                if let Some(manifest) = manifest.and_then(|el| el.canonicalize().ok())
+                    && manifest != self.directory
+                    && !manifests.contains(&manifest)
+                {
+                    manifests.push(manifest);
+                }
+            }
+        }
+
+        Ok(manifests)
+    }
+
+    /// Read a manifest without resolving its parent.
+    pub(crate) fn read_manifest(path: &Path, discovery: &Discovery) -> Result<Self> {
+        let path = Self::resolve_manifest(path, discovery)?;
+
+        let source = ManifestSource::from_path(&path);
+        let mut metadata: Metadata = source.read(&path)?;
+        source.read::<LegacyFields>(&path)?.migrate(&mut metadata);
+        metadata.update_directory(path);
+        metadata.update_ids();
+        Ok(metadata)
+    }
+
+    /// Resolve the manifest to read for the given path, which may be a
+    /// directory or the manifest itself.
+    pub(crate) fn resolve_manifest(path: &Path, discovery: &Discovery) -> Result<PathBuf> {
+        let path = if path.is_file() {
+            path.to_path_buf()
+        } else {
+            ManifestSource::locate(path, discovery)
+                .unwrap_or_else(|| path.join(discovery.manifest_name()))
+        };
+        Ok(path.canonicalize()?)
+    }
+
+    /// Search the parent directories for the closest manifest and return it,
+    /// if it declares the given manifest as one of its sub-projects.
+    ///
+    /// A manifest that can't be read is not considered to be the parent.
+    fn find_parent(manifest: &Path, discovery: &Discovery, aliases: &Aliases) -> Option<Metadata> {
+        let parent = manifest
+            .parent()?
+            .ancestors()
+            .skip(1)
+            .find_map(|dir| ManifestSource::locate(dir, discovery))?;
+
+        Self::read_manifest(&parent, discovery)
+            .and_then(|candidate| candidate.subproject_manifests(discovery))
+            .is_ok_and(|children| children.iter().any(|el| el == manifest))
+            .then(|| Self::from_file_with(&parent, discovery, aliases).ok())
+            .flatten()
+    }
+}
 
 /// Implementing the [`File`] trait allows calling serialization and
 /// deserialization functionality directly from an instance of [`Metadata`]
@@ -10,24 +205,293 @@ use super::{file_handler::FileHandler, File, Result};
 impl File for Metadata {
     /// Write an instance to a file.
     ///
+    /// The default [`Discovery`] settings and aliases are used to read the
+    /// parent. See [`Metadata::write_file_with`].
+    ///
     /// # Errors
     ///
-    /// The same errors as [`FileHandler::write_file`] are returned.
-    fn write_file(&self, path: &std::path::Path) -> Result<()> {
-        FileHandler::write_file(self, path)
+    /// The same errors as [`ManifestSource::write`] are returned.
+    fn write_file(&self, path: &Path) -> Result<()> {
+        self.write_file_with(path, &Discovery::default(), &Aliases::default())
     }
 
-    fn from_file(path: &std::path::Path) -> Result<Self> {
-        let path = if path.is_file() {
-            path.to_path_buf()
-        } else {
-            path.join("manifest.toml")
+    /// Read an instance from a file.
+    ///
+    /// The default [`Discovery`] settings and aliases are used.
+    /// See [`Metadata::from_file_with`].
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`ManifestSource::read`] are returned.
+    fn from_file(path: &Path) -> Result<Self> {
+        Self::from_file_with(path, &Discovery::default(), &Aliases::default())
+    }
+
+    /// Remove the metadata from a file.
+    ///
+    /// Embedded metadata is only removed from its file, the file itself is kept.
+    ///
+    /// # Errors
+    ///
+    /// - Errors from [`File::validate_file`] if the file is no valid manifest
+    /// - The same errors as [`ManifestSource::remove`]
+    fn remove_file(path: &Path) -> Result<()> {
+        Self::validate_file(path)?;
+        ManifestSource::from_path(path).remove(path)
+    }
+}
+
+/// Keys of manifests written by earlier versions, which have been replaced.
+#[derive(Deserialize)]
+struct LegacyFields {
+    repository_url: Option<String>,
+}
+
+impl LegacyFields {
+    /// Move the legacy values into their replacements, unless these are set.
+    ///
+    /// Repository urls lacking a scheme are completed, as they weren't
+    /// validated before.
+    fn migrate(self, metadata: &mut Metadata) {
+        if let Some(url) = self.repository_url.filter(|url| !url.trim().is_empty())
+            && metadata.repository_url().is_none()
+        {
+            metadata
+                .links
+                .insert(0, Link::new(LinkKind::Repository, &complete_url(&url)));
         }
-        .canonicalize()?;
+    }
+}
 
-        let mut metadata: Metadata = FileHandler::read_file(&path)?;
-        metadata.update_directory(path);
-        metadata.update_ids();
-        Ok(metadata)
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        files::{files_test::temp_dir, Error},
+        metadata::property::PropertyKind,
+    };
+
+    fn write_manifest(dir: &Path, builder: crate::metadata::MetadataBuilder) -> Metadata {
+        fs::create_dir_all(dir).unwrap();
+        let metadata = builder.directory_path(dir).build().unwrap();
+        metadata.write_file(metadata.directory()).unwrap();
+        metadata
+    }
+
+    #[test]
+    fn test_subproject_inherits_from_parent() {
+        let root = temp_dir();
+        let parent = write_manifest(
+            &root,
+            Metadata::builder()
+                .title("Monorepo")
+                .repository_url("https://example.com/monorepo")
+                .add_category("Web")
+                .subprojects(vec!["packages/*".to_string(), "packages/app/*".to_string()]),
+        );
+        write_manifest(&root.join("packages/app"), Metadata::builder().title("App"));
+        write_manifest(
+            &root.join("packages/app/nested"),
+            Metadata::builder().title("Nested"),
+        );
+        write_manifest(&root.join("unrelated"), Metadata::builder().title("Other"));
+
+        let discovery = Discovery::default();
+        let aliases = Aliases::default();
+        assert_eq!(parent.subproject_manifests(&discovery).unwrap().len(), 2);
+
+        let child =
+            Metadata::from_file_with(&root.join("packages/app"), &discovery, &aliases).unwrap();
+        assert_eq!(child.parent, Some(parent.id));
+        assert_eq!(child.repository_url(), Some("https://example.com/monorepo"));
+        assert_eq!(child.categories.len(), 1);
+
+        let other =
+            Metadata::from_file_with(&root.join("unrelated"), &discovery, &aliases).unwrap();
+        assert_eq!(other.parent, None);
+        assert_eq!(other.repository_url(), None);
+
+        // Only the closest manifest is considered as parent
+        let nested =
+            Metadata::from_file_with(&root.join("packages/app/nested"), &discovery, &aliases)
+                .unwrap();
+        assert_eq!(nested.parent, None);
+
+        child.write_file(child.directory()).unwrap();
+        let content = fs::read_to_string(child.directory()).unwrap();
+        assert!(!content.contains("monorepo"));
+        assert!(!content.contains("Web"));
+        let child = Metadata::from_file_with(child.directory(), &discovery, &aliases).unwrap();
+        assert_eq!(child.repository_url(), Some("https://example.com/monorepo"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_write_with_configured_manifest_name() {
+        let root = temp_dir();
+        let discovery = Discovery {
+            manifest_names: vec![".episko.toml".to_string()],
+            ..Discovery::default()
+        };
+        let aliases = Aliases::default();
+        fs::create_dir_all(root.join("app")).unwrap();
+
+        let parent = Metadata::builder()
+            .title("Monorepo")
+            .add_category("Web")
+            .subprojects(vec!["app".to_string()])
+            .manifest_name(".episko.toml")
+            .directory_path(&root)
+            .build()
+            .unwrap();
+        parent
+            .write_file_with(parent.directory(), &discovery, &aliases)
+            .unwrap();
+        let child = Metadata::builder()
+            .title("App")
+            .manifest_name(".episko.toml")
+            .directory_path(&root.join("app"))
+            .build()
+            .unwrap();
+        child
+            .write_file_with(child.directory(), &discovery, &aliases)
+            .unwrap();
+
+        let child = Metadata::from_file_with(child.directory(), &discovery, &aliases).unwrap();
+        assert_eq!(child.parent, Some(parent.id));
+        assert_eq!(child.categories.len(), 1);
+
+        child
+            .write_file_with(child.directory(), &discovery, &aliases)
+            .unwrap();
+        let content = fs::read_to_string(child.directory()).unwrap();
+        assert!(!content.contains("Web"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_repository_url() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("manifest.toml");
+        fs::write(
+            &path,
+            r#"id = "eb443171-a046-440e-b17e-55689e7bf6bf"
+title = "Legacy"
+repository_url = "example.com/legacy"
+created = "2025-03-05T15:23:33Z"
+updated = "2025-03-05T15:23:33Z"
+category = []
+language = []
+build_system = []
+
+[[link]]
+kind = "documentation"
+url = "https://docs.example.com"
+"#,
+        )
+        .unwrap();
+
+        let metadata =
+            Metadata::from_file_with(&path, &Discovery::default(), &Aliases::default()).unwrap();
+        assert_eq!(metadata.links.len(), 2);
+        assert_eq!(
+            metadata.repository_url(),
+            Some("https://example.com/legacy")
+        );
+
+        metadata.write_file(&path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("repository_url"));
+        assert!(content.contains("kind = \"repository\""));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_normalise_file() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("manifest.toml");
+        fs::write(
+            &path,
+            r#"id = "eb443171-a046-440e-b17e-55689e7bf6bf"
+title = "Aliases"
+created = "2025-03-05T15:23:33Z"
+updated = "2025-03-05T15:23:33Z"
+category = []
+build_system = []
+
+[[language]]
+name = "TS"
+
+[[language]]
+name = "typescript"
+
+[[language]]
+name = "Rust"
+version = "1.84"
+"#,
+        )
+        .unwrap();
+
+        let discovery = Discovery::default();
+        let aliases = Aliases::default();
+        let read = Metadata::from_file_with(&path, &discovery, &aliases).unwrap();
+        assert_eq!(read.languages.len(), 2);
+        assert_eq!(read.languages[0].name, "TypeScript");
+
+        assert!(Metadata::normalise_file(&path, &discovery, &aliases).unwrap());
+        assert!(!Metadata::normalise_file(&path, &discovery, &aliases).unwrap());
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains(r#"name = "TypeScript""#));
+        assert!(!content.contains(r#"name = "TS""#));
+        assert!(content.contains(r#"version = "1.84""#));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_configured_aliases() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("manifest.toml");
+        fs::write(
+            &path,
+            r#"id = "eb443171-a046-440e-b17e-55689e7bf6bf"
+title = "Configured"
+created = "2025-03-05T15:23:33Z"
+updated = "2025-03-05T15:23:33Z"
+category = []
+build_system = []
+
+[[language]]
+name = "ecma"
+
+[[license]]
+name = "Company"
+"#,
+        )
+        .unwrap();
+
+        let discovery = Discovery::default();
+        assert!(matches!(
+            Metadata::from_file_with(&path, &discovery, &Aliases::default()),
+            Err(Error::Invalid(MetadataError::InvalidLicense(_)))
+        ));
+
+        let mut aliases = Aliases::default();
+        aliases.insert(PropertyKind::Language, "ECMA", "JavaScript");
+        aliases.insert(PropertyKind::License, "company", "LicenseRef-Company");
+        let metadata = Metadata::from_file_with(&path, &discovery, &aliases).unwrap();
+        assert_eq!(metadata.languages[0].name, "JavaScript");
+        assert_eq!(metadata.licenses[0].name, "LicenseRef-Company");
+
+        fs::remove_dir_all(dir).unwrap();
     }
 }
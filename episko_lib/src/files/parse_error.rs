@@ -0,0 +1,211 @@
+//! # Errors of files that can't be parsed
+//!
+//! Deserialization errors of `toml` and `json` files are enriched with the
+//! path of the file and the location of the offending value, so they can be
+//! rendered along with the source line, e.g. by the CLI, or displayed next
+//! to the affected file, e.g. by the GUI.
+use std::{
+    fmt::Display,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::Result;
+
+/// Error of a file which couldn't be parsed or deserialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParseError {
+    pub path: PathBuf,
+    pub message: String,
+    /// Location of the offending value, if known.
+    pub location: Option<Location>,
+}
+
+/// Location of an offending value within a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Location {
+    /// Line of the value, starting at 1.
+    pub line: usize,
+    /// Column of the value in characters, starting at 1.
+    pub column: usize,
+    /// Byte range of the value within the file.
+    pub span: Range<usize>,
+    /// The whole line containing the value.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Create an error from a failed `toml` deserialization of the given content.
+    #[must_use]
+    pub fn toml(path: &Path, content: &str, err: &toml::de::Error) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            message: err.message().trim().to_string(),
+            location: err.span().map(|span| Location::from_span(content, span)),
+        }
+    }
+
+    /// Create an error from a failed `json` deserialization of the given content.
+    #[must_use]
+    pub fn json(path: &Path, content: &str, err: &serde_json::Error) -> Self {
+        // The location is appended to the message by serde_json
+        let message = err.to_string();
+        let suffix = format!(" at line {} column {}", err.line(), err.column());
+
+        Self {
+            path: path.to_path_buf(),
+            message: message
+                .strip_suffix(&suffix)
+                .unwrap_or(&message)
+                .to_string(),
+            location: (err.line() > 0)
+                .then(|| Location::from_line_column(content, err.line(), err.column())),
+        }
+    }
+}
+
+impl Location {
+    /// Locate the given byte range within the content.
+    #[must_use]
+    pub fn from_span(content: &str, span: Range<usize>) -> Self {
+        let start = span.start.min(content.len());
+        let before = &content[..start];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source_line: source_line(content, line_start),
+            span,
+        }
+    }
+
+    /// Locate the given line and column within the content.
+    ///
+    /// Columns of zero, as reported for errors at the start of a line,
+    /// are treated as the first column.
+    #[must_use]
+    pub fn from_line_column(content: &str, line: usize, column: usize) -> Self {
+        let line_start: usize = content
+            .split_inclusive('\n')
+            .take(line.saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let source_line = source_line(content, line_start);
+        let offset: usize = source_line
+            .chars()
+            .take(column.saturating_sub(1))
+            .map(char::len_utf8)
+            .sum();
+
+        Self {
+            line,
+            column: column.max(1),
+            span: line_start + offset..line_start + offset,
+            source_line,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "{}:{}:{}: {}",
+                self.path.display(),
+                location.line,
+                location.column,
+                self.message
+            ),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Deserialize the `toml` content of the file at the given path.
+///
+/// # Errors
+/// - [`super::Error::Parse`] when deserialization fails
+pub(crate) fn parse_toml<T: DeserializeOwned>(path: &Path, content: &str) -> Result<T> {
+    toml::from_str(content).map_err(|err| ParseError::toml(path, content, &err).into())
+}
+
+/// Deserialize the `json` content of the file at the given path.
+///
+/// # Errors
+/// - [`super::Error::Parse`] when deserialization fails
+pub(crate) fn parse_json<T: DeserializeOwned>(path: &Path, content: &str) -> Result<T> {
+    serde_json::from_str(content).map_err(|err| ParseError::json(path, content, &err).into())
+}
+
+fn source_line(content: &str, line_start: usize) -> String {
+    content[line_start..]
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::files::Error;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Data {
+        title: String,
+        version: String,
+    }
+
+    #[test]
+    fn test_toml_location() {
+        let content = "title = \"Episko\"\nversion = 1\n";
+
+        let Err(Error::Parse(err)) = parse_toml::<Data>(Path::new("manifest.toml"), content) else {
+            panic!("expected a parse error");
+        };
+        let location = err.location.as_ref().unwrap();
+
+        assert_eq!(err.message, "invalid type: integer `1`, expected a string");
+        assert_eq!((location.line, location.column), (2, 11));
+        assert_eq!(location.source_line, "version = 1");
+        assert_eq!(&content[location.span.clone()], "1");
+        assert_eq!(
+            err.to_string(),
+            "manifest.toml:2:11: invalid type: integer `1`, expected a string"
+        );
+    }
+
+    #[test]
+    fn test_toml_syntax_error() {
+        let content = "title = \"Episko\nversion = \"1\"\n";
+
+        let Err(Error::Parse(err)) = parse_toml::<Data>(Path::new("manifest.toml"), content) else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!(err.location.unwrap().line, 1);
+    }
+
+    #[test]
+    fn test_json_location() {
+        let content = "{\n  \"title\": \"Episko\",\n  \"version\": 1\n}";
+
+        let Err(Error::Parse(err)) = parse_json::<Data>(Path::new("package.json"), content) else {
+            panic!("expected a parse error");
+        };
+        let location = err.location.unwrap();
+
+        assert_eq!(err.message, "invalid type: integer `1`, expected a string");
+        assert_eq!(location.line, 3);
+        assert_eq!(location.source_line, "  \"version\": 1");
+    }
+}
@@ -0,0 +1,180 @@
+//! # JSON Schema of the manifest format
+//!
+//! The schema is generated from the [`Metadata`] struct and as such follows
+//! its serde attributes, e.g. categories being stored under the `category` key.
+//! Editors can use it to autocomplete and validate manifests.
+//!
+//! Manifests are checked against the schema before being deserialized,
+//! which allows reporting every problem along with the path to its key,
+//! see [`Metadata::schema_errors`].
+use std::{fmt::Display, path::Path, sync::LazyLock};
+
+use jsonschema::{paths::LocationSegment, Validator};
+use schemars::schema_for;
+use serde_json::Value;
+
+use crate::{config::Discovery, metadata::Metadata};
+
+use super::{embedded::ManifestSource, Result};
+
+/// Title of the generated schema.
+static SCHEMA_TITLE: &str = "Episko manifest";
+
+/// Validator of the manifest schema, compiled on first use.
+static VALIDATOR: LazyLock<Validator> = LazyLock::new(|| {
+    jsonschema::validator_for(&manifest_schema())
+        .expect("generated manifest schema should be valid")
+});
+
+/// A value of a manifest violating the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    /// Path to the offending key, e.g. `language[1].version`.
+    /// Empty if the manifest itself is affected.
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Generate the JSON Schema of the manifest format.
+#[must_use]
+pub fn manifest_schema() -> Value {
+    let mut schema = schema_for!(Metadata);
+    schema.schema.metadata().title = Some(SCHEMA_TITLE.to_string());
+
+    serde_json::to_value(schema).unwrap_or_default()
+}
+
+/// Check the given manifest data against the schema, returning all violations.
+///
+/// # Panics
+/// - When the generated schema is invalid, which would be a bug
+#[must_use]
+pub fn validate_schema(data: &Value) -> Vec<SchemaError> {
+    VALIDATOR
+        .iter_errors(data)
+        .map(|err| SchemaError {
+            path: render_path(&err.instance_path),
+            message: err.to_string(),
+        })
+        .collect()
+}
+
+impl Metadata {
+    /// Check the manifest at the given path against the schema, without
+    /// deserializing it. See [`validate_schema`].
+    ///
+    /// Directories are resolved the same way as in [`Metadata::from_file_with`].
+    ///
+    /// # Errors
+    /// - The same errors as [`ManifestSource::read`] are returned.
+    pub fn schema_errors(path: &Path, discovery: &Discovery) -> Result<Vec<SchemaError>> {
+        let path = Self::resolve_manifest(path, discovery)?;
+        let data: Value = ManifestSource::from_path(&path).read(&path)?;
+
+        Ok(validate_schema(&data))
+    }
+}
+
+/// Render a json pointer in the notation used for toml keys,
+/// e.g. `/language/1/version` as `language[1].version`.
+fn render_path(location: &jsonschema::paths::Location) -> String {
+    location
+        .into_iter()
+        .enumerate()
+        .map(|(i, segment)| match segment {
+            LocationSegment::Index(index) => format!("[{index}]"),
+            LocationSegment::Property(key) if i == 0 => key.to_string(),
+            LocationSegment::Property(key) => format!(".{key}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        files::{files_test::temp_dir, File},
+        metadata::Language,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_schema_follows_serde_attributes() {
+        let schema = manifest_schema();
+        let properties = &schema["properties"];
+
+        assert_eq!(schema["title"], SCHEMA_TITLE);
+        assert!(properties.get("category").is_some());
+        assert!(properties.get("link").is_some());
+        assert!(properties.get("categories").is_none());
+        assert!(properties.get("directory").is_none());
+    }
+
+    #[test]
+    fn test_valid_manifest() {
+        let metadata = Metadata::builder()
+            .title("Schema")
+            .directory(".")
+            .add_language(Language::with_version("Rust", "1.85"))
+            .build()
+            .unwrap();
+
+        let data = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(validate_schema(&data), vec![]);
+    }
+
+    #[test]
+    fn test_errors_contain_paths() {
+        let data = json!({
+            "id": "eb443171-a046-440e-b17e-55689e7bf6bf",
+            "category": [],
+            "language": [{ "name": "Rust" }, { "name": "TS", "version": 5 }],
+            "build_system": [],
+            "link": [{ "kind": "wiki", "url": "https://example.com" }],
+            "created": "2025-03-05T15:23:33Z",
+            "updated": "yesterday",
+        });
+
+        let errors = validate_schema(&data);
+        let paths: Vec<_> = errors.iter().map(|err| err.path.as_str()).collect();
+
+        assert_eq!(errors.len(), 4);
+        assert!(paths.contains(&""));
+        assert!(paths.contains(&"language[1].version"));
+        assert!(paths.contains(&"link[0].kind"));
+        assert!(paths.contains(&"updated"));
+        assert!(errors
+            .iter()
+            .any(|err| err.to_string() == "\"title\" is a required property"));
+    }
+
+    #[test]
+    fn test_schema_errors_of_file() {
+        let dir = temp_dir();
+        let metadata = Metadata::builder()
+            .title("Schema")
+            .directory_path(&dir)
+            .build()
+            .unwrap();
+        metadata.write_file(&dir.join("manifest.toml")).unwrap();
+
+        let errors = Metadata::schema_errors(&dir, &Discovery::default()).unwrap();
+        assert!(errors.is_empty());
+
+        std::fs::write(dir.join("manifest.toml"), "id = 1\ntitle = \"Broken\"\n").unwrap();
+        let errors = Metadata::schema_errors(&dir, &Discovery::default()).unwrap();
+        assert!(errors.iter().any(|err| err.path == "id"));
+    }
+}
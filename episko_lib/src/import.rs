@@ -0,0 +1,245 @@
+//! # Import of projects from other formats
+//!
+//! This module contains importers for formats of other project managers,
+//! which create new manifests for all listed projects.
+//!
+//! ## Formats
+//! - [`ImportFormat::VsCode`]: The `projects.json` of the VS Code "Project Manager" extension
+//! - [`ImportFormat::Csv`]: A CSV file as created by [`crate::export::ExportFormat::Csv`]
+//! - [`ImportFormat::DirectoryList`]: A newline separated list of directories
+//!
+//! Each importer parses its input into [`ImportedProject`]s, which can optionally
+//! be completed by [`detection`] before the manifests are created and cached
+//! using the [`ImportHandler`].
+use std::{
+    env,
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::metadata::{
+    property::Property as _, BuildSystem, Category, Ide, Language, Link, LinkKind, Metadata,
+    MetadataBuilder,
+};
+use crate::ApplyIf as _;
+
+pub mod csv;
+pub mod detection;
+pub mod directory_list;
+pub mod import_handler;
+pub mod vscode;
+
+pub use import_handler::{ImportHandler, ImportReport};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Available formats to import projects from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    VsCode,
+    Csv,
+    #[serde(rename = "directories", alias = "directorylist")]
+    DirectoryList,
+}
+
+impl ImportFormat {
+    /// Parse the given input in the format.
+    ///
+    /// # Errors
+    /// - [`Error::Json`] when a vscode input is invalid json
+    /// - [`Error::Csv`] when a csv input is malformed
+    pub fn parse(self, input: &str) -> Result<Vec<ImportedProject>> {
+        match self {
+            ImportFormat::VsCode => vscode::parse(input),
+            ImportFormat::Csv => csv::parse(input),
+            ImportFormat::DirectoryList => Ok(directory_list::parse(input)),
+        }
+    }
+}
+
+impl FromStr for ImportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "vscode" => Ok(ImportFormat::VsCode),
+            "csv" => Ok(ImportFormat::Csv),
+            "directorylist" | "directories" | "dirs" => Ok(ImportFormat::DirectoryList),
+            _ => Err(Error::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+impl Display for ImportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ImportFormat::VsCode => "vscode",
+            ImportFormat::Csv => "csv",
+            ImportFormat::DirectoryList => "directories",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A project read by one of the importers, which has no manifest yet.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImportedProject {
+    pub directory: PathBuf,
+    pub title: String,
+    pub description: Option<String>,
+    pub categories: Vec<String>,
+    pub languages: Vec<Language>,
+    pub build_systems: Vec<BuildSystem>,
+    pub preferred_ide: Option<Ide>,
+    pub links: Vec<Link>,
+}
+
+impl ImportedProject {
+    /// Create a project for the given directory, using the name of the
+    /// directory as title.
+    #[must_use]
+    pub fn from_directory(directory: PathBuf) -> Self {
+        let title = directory.file_name().map_or_else(
+            || directory.display().to_string(),
+            |el| el.to_string_lossy().to_string(),
+        );
+
+        Self {
+            directory,
+            title,
+            ..Self::default()
+        }
+    }
+
+    /// Create a [`MetadataBuilder`] containing all values of the project.
+    #[must_use]
+    pub fn builder(self) -> MetadataBuilder {
+        Metadata::builder()
+            .directory_path(&self.directory)
+            .title(&self.title)
+            .categories(self.categories.iter().map(|el| Category::new(el)).collect())
+            .languages(self.languages)
+            .build_systems(self.build_systems)
+            .apply_if(self.preferred_ide, MetadataBuilder::preferred_ide)
+            .apply_if(self.description.as_deref(), MetadataBuilder::description)
+            .links(self.links)
+    }
+
+    /// Url of the first repository link, if any.
+    #[must_use]
+    pub fn repository_url(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.kind == LinkKind::Repository)
+            .map(|link| link.url.as_str())
+    }
+}
+
+/// Expand a leading `~` or `$home` to the home directory of the user.
+#[must_use]
+pub fn expand_home(path: &str) -> PathBuf {
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE"));
+
+    for prefix in ["~", "$home", "$HOME"] {
+        if let Some(rest) = path.strip_prefix(prefix)
+            && (rest.is_empty() || rest.starts_with(['/', '\\']))
+            && let Ok(home) = &home
+        {
+            return Path::new(home).join(rest.trim_start_matches(['/', '\\']));
+        }
+    }
+
+    PathBuf::from(path)
+}
+
+/// Parse a property given as `<NAME>:<VERSION>`, the version is optional.
+fn parse_property<T: crate::metadata::property::Property>(
+    value: &str,
+    with_version: fn(&str, &str) -> T,
+) -> Option<T> {
+    let value = value.trim();
+    match value.split_once(':') {
+        _ if value.is_empty() => None,
+        Some((name, version)) if !version.trim().is_empty() => {
+            Some(with_version(name.trim(), version.trim()))
+        }
+        Some((name, _)) => Some(T::new(name.trim())),
+        None => Some(T::new(value)),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("unknown import format: {0}")]
+    UnknownFormat(String),
+
+    #[error("invalid json")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid csv in line {0}: {1}")]
+    Csv(usize, String),
+
+    #[error("io error")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(
+            ImportFormat::from_str("VSCode").unwrap(),
+            ImportFormat::VsCode
+        );
+        assert_eq!(
+            ImportFormat::from_str("directories").unwrap(),
+            ImportFormat::DirectoryList
+        );
+        assert!(ImportFormat::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn test_format_serde() {
+        let format = ImportFormat::DirectoryList;
+
+        let serialized = serde_json::to_string(&format).unwrap();
+
+        assert_eq!(serialized, format!("\"{format}\""));
+        assert_eq!(
+            serde_json::from_str::<ImportFormat>(&serialized).unwrap(),
+            format
+        );
+        assert_eq!(
+            serde_json::from_str::<ImportFormat>("\"directorylist\"").unwrap(),
+            format
+        );
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = env::var("HOME").unwrap();
+
+        assert_eq!(expand_home("~/code"), Path::new(&home).join("code"));
+        assert_eq!(expand_home("$home/code"), Path::new(&home).join("code"));
+        assert_eq!(expand_home("/srv/~code"), PathBuf::from("/srv/~code"));
+    }
+
+    #[test]
+    fn test_parse_property() {
+        let language = parse_property("Rust:1.84", Language::with_version).unwrap();
+        assert_eq!(language.name, "Rust");
+        assert_eq!(language.version.as_deref(), Some("1.84"));
+
+        let language = parse_property("Go", Language::with_version).unwrap();
+        assert_eq!(language.version, None);
+
+        assert!(parse_property(" ", Language::with_version).is_none());
+    }
+}
@@ -0,0 +1,202 @@
+//! Submodule of [`crate::import`] for reading projects from CSV.
+//!
+//! The expected columns are the ones written by [`crate::export::csv`],
+//! identified by the header row. Only `title` and `directory` are required,
+//! unknown columns are ignored.
+use crate::{
+    export::csv::split_values,
+    metadata::{property::Property as _, BuildSystem, Ide, Language, Link, LinkKind},
+};
+
+use super::{expand_home, parse_property, Error, ImportedProject, Result};
+
+/// Parse projects from CSV, including a header row.
+///
+/// # Errors
+/// - [`Error::Csv`] when the input is malformed or a required column is missing
+pub fn parse(input: &str) -> Result<Vec<ImportedProject>> {
+    let mut records = parse_records(input)?.into_iter();
+
+    let Some(header) = records.next() else {
+        return Ok(vec![]);
+    };
+    let column = |name: &str| header.iter().position(|el| el.trim() == name);
+
+    let title = column("title").ok_or(Error::Csv(1, "missing column 'title'".to_string()))?;
+    let directory =
+        column("directory").ok_or(Error::Csv(1, "missing column 'directory'".to_string()))?;
+    let description = column("description");
+    let categories = column("categories");
+    let languages = column("languages");
+    let build_systems = column("build_systems");
+    let preferred_ide = column("preferred_ide");
+    let repository_url = column("repository_url");
+    let links = column("links");
+
+    Ok(records
+        .filter(|record| record.iter().any(|el| !el.is_empty()))
+        .map(|record| {
+            let field = |index: Option<usize>| {
+                index
+                    .and_then(|index| record.get(index))
+                    .map(|el| el.trim())
+                    .filter(|el| !el.is_empty())
+            };
+            let values = |index: Option<usize>| field(index).map(split_values).unwrap_or_default();
+
+            ImportedProject {
+                directory: expand_home(field(Some(directory)).unwrap_or_default()),
+                title: field(Some(title)).unwrap_or_default().to_string(),
+                description: field(description).map(ToString::to_string),
+                categories: values(categories)
+                    .into_iter()
+                    .map(|el| el.trim().to_string())
+                    .filter(|el| !el.is_empty())
+                    .collect(),
+                languages: values(languages)
+                    .into_iter()
+                    .filter_map(|el| parse_property(&el, Language::with_version))
+                    .collect(),
+                build_systems: values(build_systems)
+                    .into_iter()
+                    .filter_map(|el| parse_property(&el, BuildSystem::with_version))
+                    .collect(),
+                preferred_ide: field(preferred_ide).map(Ide::new),
+                links: parse_links(field(repository_url), values(links)),
+            }
+        })
+        .collect())
+}
+
+/// Parse the links of a project, skipping invalid ones.
+///
+/// The repository url is only used if no repository link is given,
+/// as exports contain the repository in both columns.
+fn parse_links(repository_url: Option<&str>, links: Vec<String>) -> Vec<Link> {
+    let mut links: Vec<Link> = links
+        .into_iter()
+        .filter_map(|el| el.trim().parse().ok())
+        .collect();
+
+    if let Some(url) = repository_url
+        && links.iter().all(|link| link.kind != LinkKind::Repository)
+    {
+        links.insert(0, Link::new(LinkKind::Repository, url));
+    }
+
+    links
+}
+
+/// Split CSV into records of fields as described in RFC 4180.
+fn parse_records(input: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if field.is_empty() => quoted = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+                line += 1;
+            }
+            ('\n', true) => {
+                field.push(c);
+                line += 1;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(Error::Csv(line, "unterminated quoted field".to_string()));
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::export::{csv::render, export_test};
+
+    #[test]
+    fn test_parse_records() {
+        let records = parse_records("a,\"b,\"\"c\"\"\nd\"\r\ne,\n").unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                vec!["a".to_string(), "b,\"c\"\nd".to_string()],
+                vec!["e".to_string(), String::new()]
+            ]
+        );
+        assert!(parse_records("a,\"b").is_err());
+    }
+
+    #[test]
+    fn test_parse_own_export() {
+        let metadata = export_test::metadata();
+
+        let projects = parse(&render(&[metadata])).unwrap();
+
+        assert_eq!(projects.len(), 1);
+        let project = &projects[0];
+        assert_eq!(project.title, "Episko | Catalogue");
+        assert_eq!(project.directory, PathBuf::from("/"));
+        assert_eq!(
+            project.description.as_deref(),
+            Some("Manage \"all\" projects,\nfast")
+        );
+        assert_eq!(project.categories, vec!["Cli", "Web"]);
+        assert_eq!(
+            project.languages,
+            vec![
+                Language::with_version("Rust", "1.84"),
+                Language::new("TypeScript")
+            ]
+        );
+        assert_eq!(project.repository_url(), Some("https://example.com/episko"));
+        assert_eq!(project.links.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_escaped_values() {
+        let metadata = export_test::metadata()
+            .update()
+            .add_category("R;D")
+            .add_category("C:\\tools")
+            .build()
+            .unwrap();
+
+        let projects = parse(&render(&[metadata])).unwrap();
+
+        assert_eq!(
+            projects[0].categories,
+            vec!["C:\\tools", "Cli", "R;D", "Web"]
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_column() {
+        assert!(parse("title,description\nExample,\n").is_err());
+    }
+}
@@ -0,0 +1,142 @@
+//! Submodule of [`crate::import`] for detecting properties of a project
+//! based on the files within its directory.
+//!
+//! Detection only completes a project, values which are already set are
+//! never replaced.
+use std::{fs, path::Path};
+
+use crate::metadata::{
+    link::complete_url, property::Property as _, BuildSystem, Language, Link, LinkKind,
+};
+
+use super::ImportedProject;
+
+/// Marker files, with the language and build system they indicate.
+static MARKERS: [(&str, Option<&str>, Option<&str>); 11] = [
+    ("Cargo.toml", Some("Rust"), Some("Cargo")),
+    ("tsconfig.json", Some("TypeScript"), None),
+    ("package.json", Some("JavaScript"), Some("npm")),
+    ("pyproject.toml", Some("Python"), None),
+    ("requirements.txt", Some("Python"), None),
+    ("go.mod", Some("Go"), Some("Go")),
+    ("pom.xml", Some("Java"), Some("Maven")),
+    ("build.gradle", Some("Java"), Some("Gradle")),
+    ("build.gradle.kts", Some("Kotlin"), Some("Gradle")),
+    ("CMakeLists.txt", None, Some("CMake")),
+    ("Makefile", None, Some("Make")),
+];
+
+/// Complete the given project with languages, build systems and the
+/// repository url detected within its directory.
+#[must_use]
+pub fn detect(mut project: ImportedProject) -> ImportedProject {
+    let dir = project.directory.clone();
+
+    let markers: Vec<_> = MARKERS
+        .iter()
+        .filter(|(file, _, _)| dir.join(file).is_file())
+        .collect();
+
+    if project.languages.is_empty() {
+        for language in markers.iter().filter_map(|(_, language, _)| *language) {
+            let language = Language::new(language);
+            if !project.languages.contains(&language) {
+                project.languages.push(language);
+            }
+        }
+    }
+
+    if project.build_systems.is_empty() {
+        for build_system in markers
+            .iter()
+            .filter_map(|(_, _, build_system)| *build_system)
+        {
+            let build_system = BuildSystem::new(build_system);
+            if !project.build_systems.contains(&build_system) {
+                project.build_systems.push(build_system);
+            }
+        }
+    }
+
+    // Remotes which are local paths can't be linked
+    if project.repository_url().is_none()
+        && let Some(url) = git_remote_url(&dir).map(|remote| complete_url(&remote))
+        && Link::validate(&url).is_ok()
+    {
+        project
+            .links
+            .insert(0, Link::new(LinkKind::Repository, &url));
+    }
+
+    project
+}
+
+/// Read the url of the `origin` remote from the git config of the directory.
+fn git_remote_url(dir: &Path) -> Option<String> {
+    let config = fs::read_to_string(dir.join(".git").join("config")).ok()?;
+
+    let mut in_origin = false;
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == r#"[remote "origin"]"#;
+        } else if in_origin
+            && let Some(url) = line
+                .strip_prefix("url")
+                .and_then(|rest| rest.trim_start().strip_prefix('='))
+        {
+            return Some(url.trim().to_string());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files::files_test::temp_dir;
+
+    #[test]
+    fn test_detect() {
+        let dir = temp_dir();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::write(dir.join("package.json"), "{}").unwrap();
+        fs::write(dir.join("tsconfig.json"), "{}").unwrap();
+        fs::write(
+            dir.join(".git/config"),
+            "[core]\n\tbare = false\n[remote \"origin\"]\n\turl = https://example.com/app.git\n",
+        )
+        .unwrap();
+
+        let project = detect(ImportedProject::from_directory(dir.clone()));
+
+        assert_eq!(
+            project.languages,
+            vec![Language::new("TypeScript"), Language::new("JavaScript")]
+        );
+        assert_eq!(project.build_systems, vec![BuildSystem::new("npm")]);
+        assert_eq!(
+            project.repository_url(),
+            Some("https://example.com/app.git")
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_detect_keeps_existing_values() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("Cargo.toml"), "").unwrap();
+
+        let project = detect(ImportedProject {
+            languages: vec![Language::new("C")],
+            ..ImportedProject::from_directory(dir.clone())
+        });
+
+        assert_eq!(project.languages, vec![Language::new("C")]);
+        assert_eq!(project.build_systems, vec![BuildSystem::new("Cargo")]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+//! Submodule of [`crate::import`] for reading a newline separated list
+//! of project directories.
+//!
+//! Empty lines and lines starting with `#` are ignored. The name of each
+//! directory is used as title of its project.
+use super::{expand_home, ImportedProject};
+
+/// Parse projects from a list of directories.
+#[must_use]
+pub fn parse(input: &str) -> Vec<ImportedProject> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| ImportedProject::from_directory(expand_home(line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let projects = parse("# work\n/srv/code/episko\n\n  /srv/code/other/  \n");
+
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].title, "episko");
+        assert_eq!(projects[1].directory, PathBuf::from("/srv/code/other/"));
+        assert_eq!(projects[1].title, "other");
+    }
+}
@@ -0,0 +1,108 @@
+//! # Import handling
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+use crate::{
+    config::ConfigHandler, database::DatabaseHandler, files::embedded::ManifestSource,
+    metadata::metadata_handler::MetadataHandler,
+};
+
+use super::{detection, ImportFormat, ImportedProject, Result};
+
+pub struct ImportHandler;
+
+/// Outcome of an import.
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    /// Manifests which have been created.
+    pub imported: Vec<PathBuf>,
+    /// Directories which have been skipped, along with the reason.
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+impl ImportHandler {
+    /// Import all projects listed in the given file.
+    ///
+    /// See [`ImportHandler::import`].
+    ///
+    /// # Errors
+    /// - if the file cannot be read
+    /// - if the content of the file cannot be parsed, see [`ImportFormat::parse`]
+    pub async fn import_file(
+        path: &Path,
+        format: ImportFormat,
+        detect: bool,
+        db: &DatabaseHandler,
+        config_handler: &mut ConfigHandler,
+    ) -> Result<ImportReport> {
+        let projects = format.parse(&fs::read_to_string(path)?)?;
+
+        Ok(Self::import(projects, detect, db, config_handler).await)
+    }
+
+    /// Create and cache a manifest for each of the given projects.
+    ///
+    /// The manifests are named as configured in the [`crate::config::Discovery`]
+    /// settings. When `detect` is set, the projects are completed using
+    /// [`detection::detect`] first.
+    ///
+    /// Projects are skipped, if their directory doesn't exist, already contains
+    /// a manifest or if creating the manifest fails.
+    pub async fn import(
+        projects: Vec<ImportedProject>,
+        detect: bool,
+        db: &DatabaseHandler,
+        config_handler: &mut ConfigHandler,
+    ) -> ImportReport {
+        let mut report = ImportReport::default();
+
+        for project in projects {
+            let directory = project.directory.clone();
+
+            if !directory.is_dir() {
+                report
+                    .skipped
+                    .push((directory, "directory does not exist".to_string()));
+                continue;
+            }
+
+            let discovery = &config_handler.config().discovery;
+            if ManifestSource::locate(&directory, discovery).is_some() {
+                report
+                    .skipped
+                    .push((directory, "manifest already exists".to_string()));
+                continue;
+            }
+
+            let project = if detect {
+                detection::detect(project)
+            } else {
+                project
+            };
+
+            let metadata = match project
+                .builder()
+                .manifest_name(discovery.manifest_name())
+                .aliases(&config_handler.config().aliases)
+                .build()
+            {
+                Ok(metadata) => metadata,
+                Err(err) => {
+                    report.skipped.push((directory, err.to_string()));
+                    continue;
+                }
+            };
+
+            match MetadataHandler::save_metadata(&metadata, db, config_handler).await {
+                Ok(()) => report.imported.push(metadata.directory().to_path_buf()),
+                Err(err) => report.skipped.push((directory, err.to_string())),
+            }
+        }
+
+        report
+    }
+}
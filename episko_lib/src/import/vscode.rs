@@ -0,0 +1,72 @@
+//! Submodule of [`crate::import`] for reading the `projects.json` of the
+//! VS Code "Project Manager" extension.
+//!
+//! Each entry provides a name and a root path, which may start with `$home`
+//! or `~`. Tags of an entry are imported as categories.
+use serde::Deserialize;
+
+use super::{expand_home, ImportedProject, Result};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    name: String,
+    root_path: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Parse projects from the content of a `projects.json`.
+///
+/// # Errors
+/// - [`super::Error::Json`] when the input is not valid json
+pub fn parse(input: &str) -> Result<Vec<ImportedProject>> {
+    let entries: Vec<Entry> = serde_json::from_str(input)?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| ImportedProject {
+            directory: expand_home(&entry.root_path),
+            title: entry.name,
+            categories: entry.tags,
+            ..ImportedProject::default()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = r#"[
+            {
+                "name": "episko",
+                "rootPath": "/srv/code/episko",
+                "paths": [],
+                "tags": ["Work", "Rust"],
+                "enabled": true
+            },
+            {
+                "name": "dotfiles",
+                "rootPath": "/srv/dotfiles"
+            }
+        ]"#;
+
+        let projects = parse(input).unwrap();
+
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].title, "episko");
+        assert_eq!(projects[0].directory, PathBuf::from("/srv/code/episko"));
+        assert_eq!(projects[0].categories, vec!["Work", "Rust"]);
+        assert!(projects[1].categories.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(r#"{ "name": "episko" }"#).is_err());
+    }
+}
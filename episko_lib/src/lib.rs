@@ -10,6 +10,10 @@
 //! - metadata
 //! - files
 //! - database
+//! - export
+//! - import
+//! - rename
+//! - site
 //!
 //! The metadata module is part of the core crate, while the files module
 //! is placed under the "files" feature flag, which is however enabled by
@@ -25,9 +29,17 @@
 pub mod config;
 #[cfg(feature = "database")]
 pub mod database;
+#[cfg(feature = "database")]
+pub mod export;
 #[cfg(feature = "files")]
 pub mod files;
+#[cfg(all(feature = "database", feature = "files"))]
+pub mod import;
 pub mod metadata;
+#[cfg(all(feature = "database", feature = "files"))]
+pub mod rename;
+#[cfg(feature = "database")]
+pub mod site;
 pub mod statistics;
 
 /// Trait to perform a self consuming action based on a condition.
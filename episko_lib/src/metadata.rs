@@ -48,37 +48,57 @@
 //!
 //! These properties are accompanied by additional traits and structures, which can
 //! be found as submodules.
+//!
+//! ### Sub-projects
+//! A manifest can declare sub-projects by path or glob, relative to its own
+//! directory. Sub-projects inherit defaults from their parent, see
+//! [`Metadata::inherit_from`]. The resulting hierarchy can be displayed
+//! using a [`ProjectTree`].
 use std::{
     io,
     path::{Path, PathBuf},
 };
 
 use chrono::{DateTime, Utc};
-use property::Property;
+use property::{Property, PropertyKind};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 
 use uuid::Uuid;
 
+use crate::config::Aliases;
+
 pub mod build_system;
 pub mod builder;
 pub mod category;
+pub mod framework;
 pub mod ide;
 pub mod language;
+pub mod license;
+pub mod link;
 pub mod metadata_handler;
+pub mod person;
+pub mod project_tree;
 pub mod property;
+pub mod version;
 
 pub use build_system::BuildSystem;
 pub use builder::MetadataBuilder;
 pub use category::Category;
+pub use framework::Framework;
 pub use ide::Ide;
 pub use language::Language;
+pub use license::License;
+pub use link::{Link, LinkKind};
+pub use person::Person;
+pub use project_tree::ProjectTree;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Metadata structure containing information about a project.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Metadata {
     pub id: Uuid,
     #[serde(skip)]
@@ -92,7 +112,24 @@ pub struct Metadata {
     #[serde(rename = "build_system")]
     pub build_systems: Vec<BuildSystem>,
     pub preferred_ide: Option<Ide>,
-    pub repository_url: Option<String>,
+    /// SPDX identifiers or expressions of the licenses used by the project.
+    #[serde(rename = "license", default, skip_serializing_if = "Vec::is_empty")]
+    pub licenses: Vec<License>,
+    /// Frameworks used by the project, e.g. `Axum` or `Svelte`.
+    #[serde(rename = "framework", default, skip_serializing_if = "Vec::is_empty")]
+    pub frameworks: Vec<Framework>,
+    /// People involved in the project, e.g. its maintainers.
+    #[serde(rename = "person", default, skip_serializing_if = "Vec::is_empty")]
+    pub people: Vec<Person>,
+    /// Links to resources of the project, e.g. its repository or documentation.
+    #[serde(rename = "link", default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<Link>,
+    /// Paths or globs, relative to the manifests directory, of sub-projects.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subprojects: Vec<String>,
+    /// Id of the parent project, resolved when reading the manifest.
+    #[serde(skip)]
+    pub parent: Option<Uuid>,
     pub created: DateTime<Utc>,
     pub updated: DateTime<Utc>,
 }
@@ -134,7 +171,8 @@ impl Metadata {
     }
 
     /// Update the id's of each of the properties.
-    /// These include categories, languages, build systems and preferred ide.
+    /// These include categories, languages, build systems, preferred ide,
+    /// licenses, frameworks and people.
     ///
     /// This can used when the metadata object is created from a file and the
     /// id's are not set yet.
@@ -143,6 +181,133 @@ impl Metadata {
         self.languages.iter_mut().for_each(Property::update_id);
         self.build_systems.iter_mut().for_each(Property::update_id);
         self.preferred_ide.iter_mut().for_each(Property::update_id);
+        self.licenses.iter_mut().for_each(Property::update_id);
+        self.frameworks.iter_mut().for_each(Property::update_id);
+        self.people.iter_mut().for_each(Property::update_id);
+    }
+
+    /// Replace the given aliases of all properties with their canonical names,
+    /// removing properties that are duplicates afterwards.
+    ///
+    /// Returns `true` if any property has been changed.
+    pub fn normalise_properties(&mut self, aliases: &Aliases) -> bool {
+        let mut changed = property::normalise_all(&mut self.categories, aliases);
+        changed |= property::normalise_all(&mut self.languages, aliases);
+        changed |= property::normalise_all(&mut self.build_systems, aliases);
+        changed |= property::normalise_all(&mut self.licenses, aliases);
+        changed |= property::normalise_all(&mut self.frameworks, aliases);
+        changed |= property::normalise_all(&mut self.people, aliases);
+        if let Some(ide) = &mut self.preferred_ide {
+            changed |= ide.normalise(aliases);
+        }
+
+        changed
+    }
+
+    /// Rename all properties of the given kind named `from` to `to`,
+    /// keeping their versions.
+    ///
+    /// Names are compared ignoring case and the given aliases. If the instance
+    /// already has a property named `to`, the renamed property is merged into it.
+    ///
+    /// Returns `true` if any property has been renamed.
+    pub fn rename_property(
+        &mut self,
+        kind: PropertyKind,
+        from: &str,
+        to: &str,
+        aliases: &Aliases,
+    ) -> bool {
+        match kind {
+            PropertyKind::Category => property::rename_all(&mut self.categories, from, to, aliases),
+            PropertyKind::Language => property::rename_all(&mut self.languages, from, to, aliases),
+            PropertyKind::BuildSystem => {
+                property::rename_all(&mut self.build_systems, from, to, aliases)
+            }
+            PropertyKind::Ide => match &mut self.preferred_ide {
+                Some(ide) if property::same_name::<Ide>(ide.name(), from, aliases) => {
+                    ide.rename(to);
+                    true
+                }
+                _ => false,
+            },
+            PropertyKind::License => property::rename_all(&mut self.licenses, from, to, aliases),
+            PropertyKind::Framework => {
+                property::rename_all(&mut self.frameworks, from, to, aliases)
+            }
+            PropertyKind::Person => property::rename_all(&mut self.people, from, to, aliases),
+        }
+    }
+
+    /// Url of the first repository link, if any.
+    #[must_use]
+    pub fn repository_url(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.kind == LinkKind::Repository)
+            .map(|link| link.url.as_str())
+    }
+
+    /// Link the instance to the given parent and inherit its defaults.
+    ///
+    /// Inherited are links of kinds the instance has none of, the preferred
+    /// ide, categories, licenses and people, but only if they aren't set
+    /// by the instance itself.
+    ///
+    /// Inherited values are part of the instance from then on, but aren't
+    /// written when the instance is saved to its file, see
+    /// [`Metadata::strip_inherited`].
+    pub fn inherit_from(&mut self, parent: &Metadata) {
+        self.parent = Some(parent.id);
+
+        let inherited: Vec<Link> = parent
+            .links
+            .iter()
+            .filter(|link| self.links.iter().all(|own| own.kind != link.kind))
+            .cloned()
+            .collect();
+        self.links.extend(inherited);
+
+        if self.preferred_ide.is_none() {
+            self.preferred_ide.clone_from(&parent.preferred_ide);
+        }
+
+        if self.categories.is_empty() {
+            self.categories.clone_from(&parent.categories);
+        }
+
+        if self.licenses.is_empty() {
+            self.licenses.clone_from(&parent.licenses);
+        }
+
+        if self.people.is_empty() {
+            self.people.clone_from(&parent.people);
+        }
+    }
+
+    /// Remove all values which are inherited from the given parent,
+    /// reverting [`Metadata::inherit_from`].
+    ///
+    /// Values equal to those of the parent are considered inherited, as
+    /// the instance would inherit them anyway.
+    pub fn strip_inherited(&mut self, parent: &Metadata) {
+        self.links.retain(|link| !parent.links.contains(link));
+
+        if self.preferred_ide == parent.preferred_ide {
+            self.preferred_ide = None;
+        }
+
+        if self.categories == parent.categories {
+            self.categories.clear();
+        }
+
+        if self.licenses == parent.licenses {
+            self.licenses.clear();
+        }
+
+        if self.people == parent.people {
+            self.people.clear();
+        }
     }
 
     /// Generate a Sha256 hash based on the instance for
@@ -163,6 +328,7 @@ impl Metadata {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MetadataPreview {
     pub id: Uuid,
+    pub parent: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
     pub categories: Vec<Category>,
@@ -173,13 +339,13 @@ pub struct MetadataPreview {
 
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("failed to build Metadata")]
+    #[error("failed to build metadata: {0}")]
     FailedToBuild(#[from] builder::Error),
 
-    #[error("io error")]
+    #[error("io error: {0}")]
     Io(#[from] io::Error),
 
-    #[error("serialization error")]
+    #[error("serialization error: {0}")]
     Serialization(#[from] toml::ser::Error),
 
     #[error("name cant be empty")]
@@ -188,6 +354,11 @@ pub enum Error {
     #[error("failed to load from file: {0}")]
     File(String),
 
+    /// A manifest couldn't be read, e.g. because it can't be parsed.
+    #[cfg(feature = "files")]
+    #[error("failed to read manifest")]
+    Manifest(#[source] Box<crate::files::Error>),
+
     #[error("unable to save metadata: {0}")]
     Save(String),
 
@@ -196,6 +367,21 @@ pub enum Error {
 
     #[error("unable to find manifests in directory: {0}")]
     Directory(String),
+
+    #[error("unknown property: {0}")]
+    UnknownProperty(String),
+
+    #[error("invalid version constraint: {0}")]
+    InvalidVersionConstraint(String),
+
+    #[error("invalid license: {0}")]
+    InvalidLicense(String),
+
+    #[error("invalid url: {0}")]
+    InvalidUrl(String),
+
+    #[error("unknown link kind: {0}")]
+    UnknownLinkKind(String),
 }
 
 #[cfg(test)]
@@ -228,6 +414,41 @@ mod tests {
         assert_ne!(checksum2, checksum3);
     }
 
+    #[test]
+    fn test_inherit_from_parent() {
+        let parent = Metadata::builder()
+            .title("Parent")
+            .directory("/")
+            .add_category("Web")
+            .preferred_ide(Ide::new("Neovim"))
+            .repository_url("https://github.com/example/mono")
+            .build()
+            .unwrap();
+
+        let mut child = get_simple_metadata();
+        child.inherit_from(&parent);
+
+        assert_eq!(child.parent, Some(parent.id));
+        assert_eq!(child.categories, parent.categories);
+        assert_eq!(child.preferred_ide, parent.preferred_ide);
+        assert_eq!(child.repository_url(), parent.repository_url());
+
+        let mut child = Metadata::builder()
+            .title("Child")
+            .directory("/")
+            .add_category("CLI")
+            .build()
+            .unwrap();
+        child.inherit_from(&parent);
+
+        assert_eq!(child.categories, vec![Category::new("CLI")]);
+
+        child.strip_inherited(&parent);
+        assert_eq!(child.categories, vec![Category::new("CLI")]);
+        assert_eq!(child.preferred_ide, None);
+        assert!(child.links.is_empty());
+    }
+
     fn get_simple_metadata() -> Metadata {
         Metadata::builder()
             .title("Hello")
@@ -1,10 +1,11 @@
 use crate::database::DatabaseObject;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
-use super::property::{self, Property};
+use super::property::{self, Property, PropertyKind};
 
-#[derive(Debug, Serialize, Deserialize, DatabaseObject, FromRow, PartialOrd, Ord, Clone)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
 #[db(table = "build_system")]
 pub struct BuildSystem {
     #[serde(skip)]
@@ -27,6 +28,8 @@ impl BuildSystem {
 }
 
 impl Property for BuildSystem {
+    const KIND: PropertyKind = PropertyKind::BuildSystem;
+
     fn new(name: &str) -> Self {
         let mut s = Self {
             id: vec![],
@@ -45,10 +48,16 @@ impl Property for BuildSystem {
         self.version.as_deref()
     }
 
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
     fn update_id(&mut self) {
         self.id = self.generate_id().to_vec();
     }
 }
 
 property::impl_property_traits!(BuildSystem);
+property::impl_from_str!(BuildSystem);
 property::impl_try_from_tuple!(BuildSystem);
@@ -2,6 +2,8 @@
 //!
 //! The [`MetadataBuilder`] is the core struct for creating and
 //! updating [`Metadata`].
+//!
+//! All values are validated when building, see [`validation`].
 use std::{
     io,
     path::{Path, PathBuf},
@@ -11,10 +13,17 @@ use chrono::{DateTime, Utc};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::config::Aliases;
 // Temporary for compatibility reasons.
+use crate::config::DEFAULT_MANIFEST_FILE_NAME;
 pub use crate::ApplyIf;
 
-use super::{property::Property, BuildSystem, Category, Ide, Language, Metadata};
+pub mod validation;
+
+use super::{
+    property::{self, Property, PropertyKind},
+    BuildSystem, Category, Framework, Ide, Language, License, Link, LinkKind, Metadata, Person,
+};
 
 /// To allow for flexible building all fields
 /// can start of as `None` or an empty `Vec`.
@@ -27,16 +36,25 @@ pub struct MetadataBuilder {
     id: Option<Uuid>,
     /// Must be set by the caller
     directory: Option<PathBuf>,
+    /// File name of the manifest, used when the directory is not a file
+    manifest_name: Option<String>,
     /// Must be set by the caller
     title: Option<String>,
     categories: Vec<Category>,
     languages: Vec<Language>,
     preferred_ide: Option<Ide>,
     build_systems: Vec<BuildSystem>,
+    licenses: Vec<License>,
+    frameworks: Vec<Framework>,
+    people: Vec<Person>,
     description: Option<String>,
-    repository_url: Option<String>,
+    links: Vec<Link>,
+    subprojects: Vec<String>,
+    parent: Option<Uuid>,
     created: Option<DateTime<Utc>>,
     updated: Option<DateTime<Utc>>,
+    /// Aliases replaced by their canonical names when building
+    aliases: Option<Aliases>,
 }
 
 impl MetadataBuilder {
@@ -48,15 +66,22 @@ impl MetadataBuilder {
         Self {
             id: None,
             directory: None,
+            manifest_name: None,
             title: None,
             categories: vec![],
             languages: vec![],
             preferred_ide: None,
             build_systems: vec![],
+            licenses: vec![],
+            frameworks: vec![],
+            people: vec![],
             description: None,
-            repository_url: None,
+            links: vec![],
+            subprojects: vec![],
+            parent: None,
             created: None,
             updated: None,
+            aliases: None,
         }
     }
 
@@ -68,15 +93,22 @@ impl MetadataBuilder {
         Self {
             id: Some(metadata.id),
             directory: Some(metadata.directory),
+            manifest_name: None,
             title: Some(metadata.title),
             categories: metadata.categories,
             languages: metadata.languages,
             preferred_ide: metadata.preferred_ide,
             build_systems: metadata.build_systems,
+            licenses: metadata.licenses,
+            frameworks: metadata.frameworks,
+            people: metadata.people,
             description: metadata.description,
-            repository_url: metadata.repository_url,
+            links: metadata.links,
+            subprojects: metadata.subprojects,
+            parent: metadata.parent,
             created: Some(metadata.created),
             updated: None,
+            aliases: None,
         }
     }
 
@@ -84,27 +116,61 @@ impl MetadataBuilder {
     ///
     /// Additionally generates a new Id if necessarry, sets the creation date if not
     /// given and updates the last updated date.
+    /// If aliases are given, see [`MetadataBuilder::aliases`], they are
+    /// replaced by their canonical names.
     ///
     /// # Errors
     ///
-    /// - [`Error::DirectoryMissing`], when the caller didn't provide a directory
-    ///     or provided an invalid directory.
-    /// - [`Error::TitleMissing`], when the caller didn't provide a title.
+    /// - [`Error::Invalid`], containing every problem found by
+    ///   [`MetadataBuilder::validate`], e.g. a missing title or directory.
     pub fn build(mut self) -> Result<Metadata, Error> {
+        if let Some(aliases) = &self.aliases {
+            property::normalise_all(&mut self.categories, aliases);
+            property::normalise_all(&mut self.build_systems, aliases);
+            property::normalise_all(&mut self.languages, aliases);
+            property::normalise_all(&mut self.licenses, aliases);
+            property::normalise_all(&mut self.frameworks, aliases);
+            property::normalise_all(&mut self.people, aliases);
+            if let Some(ide) = &mut self.preferred_ide {
+                ide.normalise(aliases);
+            }
+        }
+
+        self.validate()?;
+
         self.categories.sort();
         self.build_systems.sort();
         self.languages.sort();
+        self.licenses.sort();
+        self.frameworks.sort();
+        self.people.sort();
+
+        let directory = self.directory.ok_or(Error::DirectoryMissing)?;
+        let directory = if directory.is_dir() {
+            directory.join(
+                self.manifest_name
+                    .as_deref()
+                    .unwrap_or(DEFAULT_MANIFEST_FILE_NAME),
+            )
+        } else {
+            directory
+        };
 
         Ok(Metadata {
             id: self.id.unwrap_or_else(Uuid::new_v4),
-            directory: self.directory.ok_or(Error::DirectoryMissing)?,
+            directory,
             title: self.title.ok_or(Error::TitleMissing)?,
             categories: self.categories,
             languages: self.languages,
             preferred_ide: self.preferred_ide,
             build_systems: self.build_systems,
+            licenses: self.licenses,
+            frameworks: self.frameworks,
+            people: self.people,
             description: self.description,
-            repository_url: self.repository_url,
+            links: self.links,
+            subprojects: self.subprojects,
+            parent: self.parent,
             created: self.created.unwrap_or_else(Utc::now),
             updated: self.updated.unwrap_or_else(Utc::now),
         })
@@ -127,23 +193,32 @@ impl MetadataBuilder {
 
     /// Set the metadatas directory based on a [`Path`].
     ///
+    /// When the path points to a directory, the manifest file will be placed
+    /// within it when building, named as set by [`MetadataBuilder::manifest_name`].
+    ///
     /// > When an invalid path is given, the builders path field won't
     /// > be set and an error will occur when trying to build.
     /// > This is not the cleanet solution and should be looked at, however
     /// > it allows for normalization of all builder methods.
     #[must_use]
     pub fn directory_path(mut self, path: &Path) -> Self {
-        match path.canonicalize() {
-            Ok(absolute_path) => {
-                if absolute_path.is_dir() {
-                    self.directory = Some(absolute_path.join("manifest.toml"));
-                } else {
-                    self.directory = Some(absolute_path);
-                }
-            }
-            Err(_) => self.directory = Some(path.to_path_buf()),
-        }
+        self.directory = Some(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+        self
+    }
 
+    /// Set the file name of the manifest, used when the directory is not a file.
+    /// Defaults to [`DEFAULT_MANIFEST_FILE_NAME`].
+    #[must_use]
+    pub fn manifest_name(mut self, name: &str) -> Self {
+        self.manifest_name = Some(name.to_string());
+        self
+    }
+
+    /// Replace the given aliases of properties by their canonical names
+    /// when building, see [`Property::normalise`].
+    #[must_use]
+    pub fn aliases(mut self, aliases: &Aliases) -> Self {
+        self.aliases = Some(aliases.clone());
         self
     }
 
@@ -200,6 +275,53 @@ impl MetadataBuilder {
         self
     }
 
+    /// Add one [`License`] to already existing licenses.
+    ///
+    /// The license is validated when building.
+    #[must_use]
+    pub fn add_license(mut self, license: &str) -> Self {
+        if license.is_empty() {
+            return self;
+        }
+        self.licenses.push(License::new(license));
+        self
+    }
+
+    /// Replace the existing [`Vec<License>`].
+    #[must_use]
+    pub fn licenses(mut self, licenses: Vec<License>) -> Self {
+        self.licenses = licenses;
+        self
+    }
+
+    /// Add one [`Framework`] to already existing frameworks.
+    #[must_use]
+    pub fn add_framework(mut self, framework: Framework) -> Self {
+        self.frameworks.push(framework);
+        self
+    }
+
+    /// Replace the existing [`Vec<Framework>`].
+    #[must_use]
+    pub fn frameworks(mut self, frameworks: Vec<Framework>) -> Self {
+        self.frameworks = frameworks;
+        self
+    }
+
+    /// Add one [`Person`] to already existing people.
+    #[must_use]
+    pub fn add_person(mut self, person: Person) -> Self {
+        self.people.push(person);
+        self
+    }
+
+    /// Replace the existing [`Vec<Person>`].
+    #[must_use]
+    pub fn people(mut self, people: Vec<Person>) -> Self {
+        self.people = people;
+        self
+    }
+
     /// Set the preferred [`Ide`]
     #[must_use]
     pub fn preferred_ide(mut self, ide: Ide) -> Self {
@@ -231,20 +353,45 @@ impl MetadataBuilder {
         self
     }
 
-    /// Set the repository url
+    /// Set the url of the repository link, replacing an existing one.
+    ///
+    /// The url is validated when building.
     #[must_use]
     pub fn repository_url(mut self, url: &str) -> Self {
-        self.repository_url = match url.len() {
-            0 => None,
-            _ => Some(url.to_string()),
-        };
+        self.links.retain(|link| link.kind != LinkKind::Repository);
+        if !url.is_empty() {
+            self.links.insert(0, Link::new(LinkKind::Repository, url));
+        }
+        self
+    }
+
+    /// Add one [`Link`] to already existing links.
+    ///
+    /// The url is validated when building.
+    #[must_use]
+    pub fn add_link(mut self, link: Link) -> Self {
+        self.links.push(link);
         self
     }
 
-    /// Update the repository url
+    /// Replace the existing [`Vec<Link>`].
     #[must_use]
-    pub fn update_repository_url(mut self, url: Option<String>) -> Self {
-        self.repository_url = url;
+    pub fn links(mut self, links: Vec<Link>) -> Self {
+        self.links = links;
+        self
+    }
+
+    /// Replace the existing sub-project paths or globs.
+    #[must_use]
+    pub fn subprojects(mut self, subprojects: Vec<String>) -> Self {
+        self.subprojects = subprojects;
+        self
+    }
+
+    /// Set the id of the parent project
+    #[must_use]
+    pub fn parent(mut self, parent: Uuid) -> Self {
+        self.parent = Some(parent);
         self
     }
 
@@ -298,13 +445,62 @@ pub enum Error {
     #[error("title missing")]
     TitleMissing,
 
+    #[error("invalid title: {0:?}")]
+    InvalidTitle(String),
+
     #[error("directory missing")]
     DirectoryMissing,
 
+    #[error("invalid manifest name: {0}")]
+    InvalidManifestName(String),
+
+    #[error("{0} name can't be empty")]
+    EmptyName(PropertyKind),
+
+    #[error("invalid license: {0}")]
+    InvalidLicense(String),
+
+    #[error("invalid email: {0}")]
+    InvalidEmail(String),
+
+    #[error("invalid url: {0}")]
+    InvalidUrl(String),
+
+    #[error("link label can't be empty: {0}")]
+    EmptyLinkLabel(String),
+
+    #[error("invalid sub-project path or glob: {0}")]
+    InvalidSubproject(String),
+
+    /// All problems found when validating, see [`MetadataBuilder::validate`].
+    #[error("invalid metadata: {}", join_problems(.0))]
+    Invalid(Vec<Error>),
+
     #[error("io error")]
     Io(#[from] io::Error),
 }
 
+impl Error {
+    /// The single problems contained in the error.
+    ///
+    /// Returns the problems of [`Error::Invalid`], or the error itself otherwise.
+    #[must_use]
+    pub fn problems(&self) -> &[Error] {
+        match self {
+            Error::Invalid(problems) => problems,
+            other => std::slice::from_ref(other),
+        }
+    }
+}
+
+fn join_problems(problems: &[Error]) -> String {
+    problems
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,34 +527,113 @@ mod tests {
         assert_eq!(metadata.build_systems.len(), 1);
         assert_eq!(metadata.description, Some("A test project".to_string()));
         assert_eq!(
-            metadata.repository_url,
-            Some("https://github.com/test/project".to_string())
+            metadata.repository_url(),
+            Some("https://github.com/test/project")
         );
     }
 
+    #[test]
+    fn test_alias_ids() {
+        let alias = Language::new("TS");
+        let language = Language::new("TypeScript");
+        assert_ne!(alias, language);
+        assert_ne!(alias.generate_id(), language.generate_id());
+
+        let metadata = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .add_language(alias)
+            .aliases(&Aliases::default())
+            .build()
+            .unwrap();
+        assert_eq!(metadata.languages[0].name, "TypeScript");
+        assert_eq!(metadata.languages[0].generate_id(), language.generate_id());
+    }
+
+    #[test]
+    fn test_metadata_links() {
+        let metadata = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .add_link(Link::new(LinkKind::Documentation, "https://docs.rs/episko"))
+            .repository_url("https://github.com/test/old")
+            .repository_url("https://github.com/test/project")
+            .build()
+            .unwrap();
+        assert_eq!(metadata.links.len(), 2);
+        assert_eq!(metadata.links[0].kind, LinkKind::Repository);
+        assert_eq!(metadata.links[0].url, "https://github.com/test/project");
+
+        let result = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .add_link(Link::new(LinkKind::Homepage, "example.com"))
+            .build();
+        assert!(matches!(
+            result.unwrap_err().problems(),
+            [Error::InvalidUrl(_)]
+        ));
+    }
+
+    #[test]
+    fn test_metadata_license() {
+        let metadata = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .add_license("mit/apache2")
+            .aliases(&Aliases::default())
+            .build()
+            .unwrap();
+        assert_eq!(metadata.licenses[0].name, "MIT OR Apache-2.0");
+
+        let result = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .add_license("Proprietary")
+            .build();
+        assert!(matches!(
+            result.unwrap_err().problems(),
+            [Error::InvalidLicense(_)]
+        ));
+    }
+
+    #[test]
+    fn test_metadata_manifest_name() {
+        let dir = Path::new(".").canonicalize().unwrap();
+
+        let metadata = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .build()
+            .unwrap();
+        assert_eq!(metadata.directory, dir.join(DEFAULT_MANIFEST_FILE_NAME));
+
+        let metadata = MetadataBuilder::new()
+            .title("Test")
+            .directory(".")
+            .manifest_name(".episko.toml")
+            .build()
+            .unwrap();
+        assert_eq!(metadata.directory, dir.join(".episko.toml"));
+    }
+
     #[test]
     fn test_metadata_missing_title() {
         let builder = MetadataBuilder::new().directory(".");
         let result = builder.build();
-        assert!(result.is_err());
-        if let Err(err) = result {
-            match err {
-                Error::TitleMissing => (),
-                _ => panic!("Unexpected error type"),
-            }
-        }
+        assert!(matches!(
+            result.unwrap_err().problems(),
+            [Error::TitleMissing]
+        ));
     }
 
     #[test]
     fn test_metadata_missing_dir() {
         let builder = MetadataBuilder::new().title("Test");
         let result = builder.build();
-        assert!(result.is_err());
-        if let Err(err) = result {
-            match err {
-                Error::DirectoryMissing => (),
-                _ => panic!("Unexpected error type"),
-            }
-        }
+        assert!(matches!(
+            result.unwrap_err().problems(),
+            [Error::DirectoryMissing]
+        ));
     }
 }
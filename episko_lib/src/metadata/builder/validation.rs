@@ -0,0 +1,257 @@
+//! # Validation of [`MetadataBuilder`] values
+//!
+//! Besides being used by [`MetadataBuilder::build`], the single checks
+//! can be used to validate user input before it is handed to the builder,
+//! e.g. within interactive prompts.
+use std::path::Path;
+
+use glob::Pattern;
+
+use super::{Error, MetadataBuilder};
+use crate::metadata::{
+    property::{Property, PropertyKind},
+    License, Link, Metadata,
+};
+
+impl MetadataBuilder {
+    /// Check all values of the builder, collecting every problem found.
+    ///
+    /// # Errors
+    /// - [`Error::Invalid`] containing all problems, if there are any
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut problems = vec![];
+        let mut check = |result: Result<(), Error>| {
+            if let Err(err) = result {
+                problems.push(err);
+            }
+        };
+
+        match &self.title {
+            Some(title) => check(validate_title(title)),
+            None => check(Err(Error::TitleMissing)),
+        }
+
+        match &self.directory {
+            Some(directory) if !directory.as_os_str().is_empty() => {}
+            _ => check(Err(Error::DirectoryMissing)),
+        }
+        if let Some(name) = &self.manifest_name {
+            check(validate_manifest_name(name));
+        }
+
+        check(validate_names(&self.categories));
+        check(validate_names(&self.languages));
+        check(validate_names(&self.build_systems));
+        check(validate_names(&self.frameworks));
+        check(validate_names(&self.people));
+        check(validate_names(self.preferred_ide.as_slice()));
+
+        for license in &self.licenses {
+            check(
+                License::validate(&license.name)
+                    .map_err(|_| Error::InvalidLicense(license.name.clone())),
+            );
+        }
+
+        for person in &self.people {
+            if let Some(email) = &person.email {
+                check(validate_email(email));
+            }
+        }
+
+        for link in &self.links {
+            check(validate_link(link));
+        }
+
+        for subproject in &self.subprojects {
+            check(validate_subproject(subproject));
+        }
+
+        match problems.len() {
+            0 => Ok(()),
+            _ => Err(Error::Invalid(problems)),
+        }
+    }
+}
+
+impl Metadata {
+    /// Check all values of the instance, e.g. after reading it from a
+    /// manually edited manifest. See [`MetadataBuilder::validate`].
+    ///
+    /// # Errors
+    /// - [`Error::Invalid`] containing all problems, if there are any
+    pub fn validate(&self) -> Result<(), Error> {
+        MetadataBuilder::from_metadata(self.clone()).validate()
+    }
+}
+
+/// Titles must not be blank and fit on a single line.
+///
+/// # Errors
+/// - [`Error::InvalidTitle`] when the title is invalid
+pub fn validate_title(title: &str) -> Result<(), Error> {
+    if title.trim().is_empty() || title.contains(['\n', '\r']) {
+        return Err(Error::InvalidTitle(title.to_string()));
+    }
+    Ok(())
+}
+
+/// Names of properties must not be blank.
+///
+/// # Errors
+/// - [`Error::EmptyName`] when the name is blank
+pub fn validate_name(kind: PropertyKind, name: &str) -> Result<(), Error> {
+    if name.trim().is_empty() {
+        return Err(Error::EmptyName(kind));
+    }
+    Ok(())
+}
+
+/// Emails must consist of a local part and a domain, separated by an `@`.
+///
+/// # Errors
+/// - [`Error::InvalidEmail`] when the email is invalid
+pub fn validate_email(email: &str) -> Result<(), Error> {
+    match email.split_once('@') {
+        Some((local, domain))
+            if !local.is_empty()
+                && !domain.is_empty()
+                && !domain.contains('@')
+                && !email.contains(char::is_whitespace) =>
+        {
+            Ok(())
+        }
+        _ => Err(Error::InvalidEmail(email.to_string())),
+    }
+}
+
+/// Links need a valid url and, if set, a label which isn't blank.
+///
+/// # Errors
+/// - [`Error::InvalidUrl`] when the url is invalid, see [`Link::validate`]
+/// - [`Error::EmptyLinkLabel`] when the label is blank
+pub fn validate_link(link: &Link) -> Result<(), Error> {
+    Link::validate(&link.url).map_err(|_| Error::InvalidUrl(link.url.clone()))?;
+
+    if link
+        .label
+        .as_deref()
+        .is_some_and(|label| label.trim().is_empty())
+    {
+        return Err(Error::EmptyLinkLabel(link.url.clone()));
+    }
+    Ok(())
+}
+
+/// Sub-projects are given as relative paths or globs.
+///
+/// # Errors
+/// - [`Error::InvalidSubproject`] when the pattern is blank, absolute or no valid glob
+pub fn validate_subproject(pattern: &str) -> Result<(), Error> {
+    if pattern.trim().is_empty() || Path::new(pattern).has_root() || Pattern::new(pattern).is_err()
+    {
+        return Err(Error::InvalidSubproject(pattern.to_string()));
+    }
+    Ok(())
+}
+
+/// Manifest names are plain file names, without any directories.
+///
+/// # Errors
+/// - [`Error::InvalidManifestName`] when the name is blank or contains a path separator
+pub fn validate_manifest_name(name: &str) -> Result<(), Error> {
+    if name.trim().is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(Error::InvalidManifestName(name.to_string()));
+    }
+    Ok(())
+}
+
+fn validate_names<T: Property>(properties: &[T]) -> Result<(), Error> {
+    properties
+        .iter()
+        .try_for_each(|property| validate_name(T::KIND, property.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{Category, LinkKind, Person};
+
+    #[test]
+    fn test_validate_collects_all_problems() {
+        let result = Metadata::builder()
+            .title(" ")
+            .directory(".")
+            .categories(vec![Category::new("Web"), Category::new("  ")])
+            .add_license("Proprietary")
+            .add_person(Person::new("Jane Doe").with_email("jane"))
+            .add_link(Link::new(LinkKind::Homepage, "example.com"))
+            .subprojects(vec!["/absolute".to_string(), "packages/*".to_string()])
+            .build();
+
+        let Err(err) = result else {
+            panic!("expected validation to fail");
+        };
+        let problems = err.problems();
+
+        assert_eq!(problems.len(), 6);
+        assert!(matches!(problems[0], Error::InvalidTitle(_)));
+        assert!(matches!(
+            problems[1],
+            Error::EmptyName(PropertyKind::Category)
+        ));
+        assert!(matches!(problems[2], Error::InvalidLicense(_)));
+        assert!(matches!(problems[3], Error::InvalidEmail(_)));
+        assert!(matches!(problems[4], Error::InvalidUrl(_)));
+        assert!(matches!(problems[5], Error::InvalidSubproject(_)));
+    }
+
+    #[test]
+    fn test_validate_missing_values() {
+        let err = Metadata::builder().build().unwrap_err();
+
+        assert!(matches!(
+            err.problems(),
+            [Error::TitleMissing, Error::DirectoryMissing]
+        ));
+        assert_eq!(
+            err.to_string(),
+            "invalid metadata: title missing; directory missing"
+        );
+    }
+
+    #[test]
+    fn test_validate_fields() {
+        assert!(validate_title("Episko").is_ok());
+        assert!(validate_title("Two\nlines").is_err());
+
+        assert!(validate_email("jane@example.com").is_ok());
+        assert!(validate_email("jane@").is_err());
+        assert!(validate_email("jane doe@example.com").is_err());
+
+        assert!(validate_subproject("packages/*").is_ok());
+        assert!(validate_subproject("packages/[").is_err());
+
+        assert!(validate_manifest_name(".episko.toml").is_ok());
+        assert!(validate_manifest_name("nested/manifest.toml").is_err());
+
+        let link = Link::new(LinkKind::Ci, "https://ci.example.com").with_label(" ");
+        assert!(matches!(
+            validate_link(&link),
+            Err(Error::EmptyLinkLabel(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        let mut metadata = Metadata::builder()
+            .title("Episko")
+            .directory(".")
+            .build()
+            .unwrap();
+        assert!(metadata.validate().is_ok());
+
+        metadata.title = String::new();
+        assert!(metadata.validate().is_err());
+    }
+}
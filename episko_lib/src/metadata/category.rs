@@ -1,10 +1,11 @@
 use crate::database::DatabaseObject;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
-use super::property::{self, Property};
+use super::property::{self, Property, PropertyKind};
 
-#[derive(Debug, Serialize, Deserialize, DatabaseObject, FromRow, PartialOrd, Ord, Clone)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
 #[db(table = "category")]
 pub struct Category {
     #[serde(skip)]
@@ -15,6 +16,8 @@ pub struct Category {
 }
 
 impl Property for Category {
+    const KIND: PropertyKind = PropertyKind::Category;
+
     fn new(name: &str) -> Self {
         let mut s = Self {
             id: vec![],
@@ -27,9 +30,15 @@ impl Property for Category {
         &self.name
     }
 
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
     fn update_id(&mut self) {
         self.id = self.generate_id().to_vec();
     }
 }
 
 property::impl_property_traits!(Category);
+property::impl_from_str!(Category);
@@ -0,0 +1,62 @@
+use crate::database::DatabaseObject;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use super::property::{self, Property, PropertyKind};
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
+#[db(table = "framework")]
+pub struct Framework {
+    #[serde(skip)]
+    #[db(col = "id")]
+    id: Vec<u8>,
+    #[db(col = "name")]
+    pub name: String,
+    #[db(col = "version")]
+    pub version: Option<String>,
+}
+
+impl Framework {
+    #[must_use]
+    pub fn with_version(name: &str, version: &str) -> Self {
+        let mut s = Framework::new(name);
+        s.version = Some(version.to_string());
+        s.id = s.generate_id().into();
+        s
+    }
+}
+
+impl Property for Framework {
+    const KIND: PropertyKind = PropertyKind::Framework;
+
+    fn new(name: &str) -> Self {
+        let mut s = Self {
+            id: vec![],
+            name: name.to_string(),
+            version: None,
+        };
+        s.update_id();
+        s
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
+    fn update_id(&mut self) {
+        self.id = self.generate_id().to_vec();
+    }
+}
+
+property::impl_property_traits!(Framework);
+property::impl_from_str!(Framework);
+property::impl_try_from_tuple!(Framework);
@@ -1,10 +1,11 @@
 use crate::database::DatabaseObject;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
-use super::property::{self, Property};
+use super::property::{self, Property, PropertyKind};
 
-#[derive(Debug, Serialize, Deserialize, DatabaseObject, FromRow, PartialOrd, Ord, Clone)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
 #[db(table = "ide")]
 pub struct Ide {
     #[serde(skip)]
@@ -15,6 +16,8 @@ pub struct Ide {
 }
 
 impl Property for Ide {
+    const KIND: PropertyKind = PropertyKind::Ide;
+
     fn new(name: &str) -> Self {
         let mut s = Self {
             id: vec![],
@@ -28,9 +31,15 @@ impl Property for Ide {
         &self.name
     }
 
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
     fn update_id(&mut self) {
         self.id = self.generate_id().to_vec();
     }
 }
 
 property::impl_property_traits!(Ide);
+property::impl_from_str!(Ide);
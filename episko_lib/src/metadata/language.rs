@@ -1,10 +1,11 @@
 use crate::database::DatabaseObject;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
-use super::property::{self, Property};
+use super::property::{self, Property, PropertyKind};
 
-#[derive(Debug, Serialize, Deserialize, DatabaseObject, FromRow, PartialOrd, Ord, Clone)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
 #[db(table = "language")]
 pub struct Language {
     #[serde(skip)]
@@ -27,7 +28,8 @@ impl Language {
 }
 
 impl Property for Language {
-    #[must_use]
+    const KIND: PropertyKind = PropertyKind::Language;
+
     fn new(name: &str) -> Self {
         let mut s = Self {
             id: vec![],
@@ -45,10 +47,16 @@ impl Property for Language {
         self.version.as_deref()
     }
 
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
     fn update_id(&mut self) {
         self.id = self.generate_id().to_vec();
     }
 }
 
 property::impl_property_traits!(Language);
+property::impl_from_str!(Language);
 property::impl_try_from_tuple!(Language);
@@ -0,0 +1,146 @@
+use crate::database::DatabaseObject;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use spdx::{Expression, ParseMode};
+use sqlx::FromRow;
+
+use crate::config::Aliases;
+
+use super::property::{self, Property, PropertyKind};
+
+/// License of a project, given as SPDX identifier or expression,
+/// e.g. `MIT` or `MIT OR Apache-2.0`.
+///
+/// Common deviations such as `apache2`, `mit/apache-2.0` or `GPL-3.0+` are
+/// accepted and replaced by their canonical SPDX form when normalising.
+/// ```
+/// use episko_lib::config::Aliases;
+/// use episko_lib::metadata::License;
+/// use episko_lib::metadata::property::Property as _;
+///
+/// let mut license: License = "mit/apache2".parse().unwrap();
+///
+/// license.normalise(&Aliases::default());
+/// license.name; // -> "MIT OR Apache-2.0"
+///
+/// "Proprietary".parse::<License>().is_err(); // -> true
+/// ```
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
+#[db(table = "license")]
+pub struct License {
+    #[serde(skip)]
+    #[db(col = "id")]
+    id: Vec<u8>,
+    /// SPDX identifier or expression, e.g. `MIT OR Apache-2.0`.
+    #[db(col = "name")]
+    pub name: String,
+}
+
+impl License {
+    /// Check whether the given string is a valid SPDX identifier or expression.
+    ///
+    /// # Errors
+    /// - [`super::Error::InvalidLicense`] when the expression can't be parsed
+    pub fn validate(expression: &str) -> Result<(), super::Error> {
+        Expression::parse_mode(expression, ParseMode::LAX)
+            .map(|_| ())
+            .map_err(|err| super::Error::InvalidLicense(err.to_string()))
+    }
+
+    /// Canonical SPDX form of the given expression.
+    ///
+    /// Expressions which can't be parsed are returned unchanged.
+    #[must_use]
+    pub fn canonical_expression(expression: &str) -> String {
+        match Expression::canonicalize(expression) {
+            Ok(Some(canonical)) => canonical,
+            _ => expression.to_string(),
+        }
+    }
+}
+
+impl Property for License {
+    const KIND: PropertyKind = PropertyKind::License;
+
+    /// Create a license without validating it, see [`License::validate`].
+    fn new(name: &str) -> Self {
+        let mut s = Self {
+            id: vec![],
+            name: name.to_string(),
+        };
+        s.update_id();
+        s
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
+    /// Replace the expression with its canonical SPDX form,
+    /// after resolving aliases.
+    fn normalise(&mut self, aliases: &Aliases) -> bool {
+        let name = Self::canonical_expression(&aliases.canonical_name(Self::KIND, &self.name));
+        if name == self.name {
+            return false;
+        }
+
+        self.rename(&name);
+        true
+    }
+
+    fn update_id(&mut self) {
+        self.id = self.generate_id().to_vec();
+    }
+}
+
+impl std::str::FromStr for License {
+    type Err = super::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(Self::Err::EmptyName);
+        }
+
+        Self::validate(s)?;
+        Ok(Self::new(s))
+    }
+}
+
+property::impl_property_traits!(License);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(License::validate("MIT").is_ok());
+        assert!(License::validate("MIT OR Apache-2.0 WITH LLVM-exception").is_ok());
+        assert!(License::validate("gpl-3.0+").is_ok());
+        assert!(License::validate("LicenseRef-Internal").is_ok());
+        assert!(License::validate("Proprietary").is_err());
+        assert!(License::validate("MIT OR").is_err());
+    }
+
+    #[test]
+    fn test_normalise() {
+        let aliases = Aliases::default();
+        let mut license = License::new("apache2/mit");
+        assert!(license.normalise(&aliases));
+        assert_eq!(license.name, "Apache-2.0 OR MIT");
+        assert!(!license.normalise(&aliases));
+
+        let mut aliases = Aliases::default();
+        aliases.insert(PropertyKind::License, "Company", "LicenseRef-Company");
+        let mut license = License::new("company");
+        assert!(license.normalise(&aliases));
+        assert_eq!(license.name, "LicenseRef-Company");
+
+        assert_eq!(License::new("mit"), License::new("MIT"));
+    }
+}
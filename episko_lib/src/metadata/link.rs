@@ -0,0 +1,287 @@
+use std::fmt::Display;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// The different kinds of links a project can have.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkKind {
+    Repository,
+    Documentation,
+    Ci,
+    IssueTracker,
+    Deployment,
+    Homepage,
+    Other,
+}
+
+impl LinkKind {
+    pub const ALL: [LinkKind; 7] = [
+        LinkKind::Repository,
+        LinkKind::Documentation,
+        LinkKind::Ci,
+        LinkKind::IssueTracker,
+        LinkKind::Deployment,
+        LinkKind::Homepage,
+        LinkKind::Other,
+    ];
+
+    /// Human readable name, used when a link has no label.
+    #[must_use]
+    pub fn title(self) -> &'static str {
+        match self {
+            LinkKind::Repository => "Repository",
+            LinkKind::Documentation => "Documentation",
+            LinkKind::Ci => "CI",
+            LinkKind::IssueTracker => "Issue tracker",
+            LinkKind::Deployment => "Deployment",
+            LinkKind::Homepage => "Homepage",
+            LinkKind::Other => "Link",
+        }
+    }
+}
+
+impl std::str::FromStr for LinkKind {
+    type Err = super::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "repository" | "repo" => Ok(LinkKind::Repository),
+            "documentation" | "docs" => Ok(LinkKind::Documentation),
+            "ci" => Ok(LinkKind::Ci),
+            "issue_tracker" | "issues" => Ok(LinkKind::IssueTracker),
+            "deployment" | "deploy" => Ok(LinkKind::Deployment),
+            "homepage" | "website" => Ok(LinkKind::Homepage),
+            "other" => Ok(LinkKind::Other),
+            _ => Err(super::Error::UnknownLinkKind(s.to_string())),
+        }
+    }
+}
+
+impl Display for LinkKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            LinkKind::Repository => "repository",
+            LinkKind::Documentation => "documentation",
+            LinkKind::Ci => "ci",
+            LinkKind::IssueTracker => "issue_tracker",
+            LinkKind::Deployment => "deployment",
+            LinkKind::Homepage => "homepage",
+            LinkKind::Other => "other",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Link to a resource related to a project, e.g. its repository or documentation.
+///
+/// Links are not shared between projects and keep the order they were given in.
+/// ```
+/// use episko_lib::metadata::{Link, LinkKind};
+///
+/// let link: Link = "docs:API=https://docs.rs/episko".parse().unwrap();
+///
+/// link.kind; // -> LinkKind::Documentation
+/// link.label.as_deref(); // -> Some("API")
+/// link.title(); // -> "API"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct Link {
+    pub kind: LinkKind,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Link {
+    /// Create a link without validating its url, see [`Link::validate`].
+    #[must_use]
+    pub fn new(kind: LinkKind, url: &str) -> Self {
+        Self {
+            kind,
+            url: url.to_string(),
+            label: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Check whether the url is absolute and points to a host,
+    /// e.g. `https://example.com` or `ssh://git@example.com/repo.git`.
+    ///
+    /// # Errors
+    /// - [`super::Error::InvalidUrl`] when the url can't be parsed or has no host
+    pub fn validate(url: &str) -> Result<(), super::Error> {
+        match Url::parse(url) {
+            Ok(parsed) if parsed.has_host() => Ok(()),
+            Ok(_) => Err(super::Error::InvalidUrl(format!("{url}: missing host"))),
+            Err(err) => Err(super::Error::InvalidUrl(format!("{url}: {err}"))),
+        }
+    }
+
+    /// Label of the link, falling back to the title of its kind.
+    #[must_use]
+    pub fn title(&self) -> &str {
+        self.label.as_deref().unwrap_or(self.kind.title())
+    }
+
+    /// Whether the link can be opened in a browser.
+    #[must_use]
+    pub fn is_web(&self) -> bool {
+        Url::parse(&self.url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+    }
+}
+
+impl std::str::FromStr for Link {
+    type Err = super::Error;
+
+    /// Parse a link, syntax: `[<KIND>[:<LABEL>]=]<URL>`.
+    ///
+    /// Links without a kind are of kind [`LinkKind::Other`].
+    /// The url is validated, see [`Link::validate`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, url) = match s.split_once('=') {
+            // Urls may contain '=' within their query, but never before a '/'
+            Some((head, url)) if !head.contains('/') => (Some(head), url.trim()),
+            _ => (None, s.trim()),
+        };
+
+        if url.is_empty() {
+            return Err(Self::Err::InvalidUrl(s.to_string()));
+        }
+        Self::validate(url)?;
+
+        let Some(head) = head else {
+            return Ok(Self::new(LinkKind::Other, url));
+        };
+
+        let (kind, label) = match head.split_once(':') {
+            Some((kind, label)) => (kind, Some(label.trim())),
+            None => (head, None),
+        };
+
+        let link = Self::new(kind.parse()?, url);
+        Ok(match label.filter(|el| !el.is_empty()) {
+            Some(label) => link.with_label(label),
+            None => link,
+        })
+    }
+}
+
+/// Displays the link in the syntax accepted by [`Link::from_str`](std::str::FromStr).
+impl Display for Link {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "{}:{label}={}", self.kind, self.url),
+            None => write!(f, "{}={}", self.kind, self.url),
+        }
+    }
+}
+
+/// Complete urls given without a scheme, as e.g. found in older manifests
+/// or git configs.
+///
+/// Scp-like git remotes, e.g. `git@github.com:user/repo.git`, are turned
+/// into `ssh` urls, anything else looking like a host, optionally with a
+/// port, e.g. `example.com:8080/repo`, into `https` urls.
+/// Urls with a scheme and local paths are returned unchanged.
+#[must_use]
+pub fn complete_url(url: &str) -> String {
+    let url = url.trim();
+    if url.contains("://") || url.starts_with(['/', '.', '~']) || url.is_empty() {
+        return url.to_string();
+    }
+
+    match url.split_once(':') {
+        Some((host, path)) if !host.contains('/') && !path.starts_with('/') => {
+            let port = path.split('/').next().unwrap_or_default();
+            if !port.is_empty() && port.chars().all(|char| char.is_ascii_digit()) {
+                format!("https://{url}")
+            } else {
+                format!("ssh://{host}/{path}")
+            }
+        }
+        Some(_) => url.to_string(),
+        None => format!("https://{url}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_link() {
+        let link: Link = "https://example.com/?page=1".parse().unwrap();
+        assert_eq!(
+            link,
+            Link::new(LinkKind::Other, "https://example.com/?page=1")
+        );
+
+        let link: Link = "repo=https://github.com/episko/episko".parse().unwrap();
+        assert_eq!(link.kind, LinkKind::Repository);
+        assert_eq!(link.label, None);
+        assert_eq!(link.title(), "Repository");
+
+        let link: Link = "issue-tracker:Bugs=https://example.com/issues"
+            .parse()
+            .unwrap();
+        assert_eq!(link.kind, LinkKind::IssueTracker);
+        assert_eq!(link.title(), "Bugs");
+
+        assert_eq!(link.to_string().parse::<Link>().unwrap(), link);
+
+        assert!("wiki=https://example.com".parse::<Link>().is_err());
+        assert!("docs=".parse::<Link>().is_err());
+        assert!("docs=example.com".parse::<Link>().is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Link::validate("https://example.com").is_ok());
+        assert!(Link::validate("ssh://git@example.com/repo.git").is_ok());
+        assert!(Link::validate("git@example.com:repo.git").is_err());
+        assert!(Link::validate("mailto:jane@example.com").is_err());
+        assert!(Link::validate("not a url").is_err());
+    }
+
+    #[test]
+    fn test_complete_url() {
+        let url = complete_url("git@github.com:episko/episko.git");
+        assert_eq!(url, "ssh://git@github.com/episko/episko.git");
+        assert!(Link::validate(&url).is_ok());
+
+        assert_eq!(
+            complete_url("github.com/episko/episko"),
+            "https://github.com/episko/episko"
+        );
+        assert_eq!(
+            complete_url("git.example.com:8080/episko"),
+            "https://git.example.com:8080/episko"
+        );
+
+        for url in [
+            "https://github.com/episko/episko.git",
+            "/srv/git/episko.git",
+        ] {
+            assert_eq!(complete_url(url), url);
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        let link = Link::new(LinkKind::IssueTracker, "https://example.com/issues");
+        assert_eq!(
+            toml::to_string(&link).unwrap(),
+            "kind = \"issue_tracker\"\nurl = \"https://example.com/issues\"\n"
+        );
+    }
+}
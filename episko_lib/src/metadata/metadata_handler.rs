@@ -1,10 +1,13 @@
-use glob::glob;
-use std::path::{Path, PathBuf};
+use glob::{glob, Pattern};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use crate::{
-    config::{Config, ConfigHandler},
+    config::{Aliases, Config, ConfigHandler, Discovery},
     database::DatabaseHandler,
-    files::File,
+    files::{embedded::ManifestSource, File},
 };
 
 use super::{Error, Metadata, Result};
@@ -19,12 +22,13 @@ impl MetadataHandler {
         Self
     }
 
-    /// Load all metadata files from the given [`Config`].
+    /// Load all metadata files saved in the active profile of the given [`Config`].
     ///
     /// # Errors
     /// - if the file cannot be read
     pub fn load_from_config(&self, config: &Config) -> Result<Vec<Metadata>> {
         Ok(config
+            .profile()
             .files_to_load
             .iter()
             .filter_map(|el| Metadata::from_file(el).ok())
@@ -46,7 +50,11 @@ impl MetadataHandler {
             .await
             .map_err(|err| Error::Save(err.to_string()))?;
         metadata
-            .write_file(metadata.directory())
+            .write_file_with(
+                metadata.directory(),
+                &config_handler.config().discovery,
+                &config_handler.config().aliases,
+            )
             .map_err(|err| Error::Save(err.to_string()))?;
 
         config_handler.add_saved_file(metadata.directory());
@@ -60,16 +68,192 @@ impl MetadataHandler {
 
     /// Get paths to locations of manifests in the given directory.
     ///
+    /// Candidates are all files named as configured in the given [`Discovery`],
+    /// matches of its additional patterns, as well as `Cargo.toml` and `package.json`
+    /// files containing episko metadata. Files matching an exclude pattern
+    /// and toml files which clearly aren't episko manifests are skipped.
+    ///
+    /// When a directory contains multiple sources only the one with the
+    /// highest precedence is returned.
+    ///
     /// # Errors
     /// - if the directory cannot be read
-    pub fn search_directory(dir: &Path) -> Result<Vec<PathBuf>> {
-        glob(
-            dir.join("**/manifest.toml")
-                .to_str()
-                .ok_or(Error::Directory("unable to locate dir".to_string()))?,
+    /// - if any of the configured patterns is invalid
+    pub fn search_directory(dir: &Path, discovery: &Discovery) -> Result<Vec<PathBuf>> {
+        let exclude = discovery
+            .exclude
+            .iter()
+            .map(|pattern| Pattern::new(pattern).map_err(|err| Error::Directory(err.to_string())))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Candidate patterns are ordered by precedence
+        let patterns = discovery
+            .manifest_names
+            .iter()
+            .map(|name| format!("**/{name}"))
+            .chain(discovery.patterns.iter().cloned())
+            .chain(
+                ManifestSource::EMBEDDED
+                    .into_iter()
+                    .filter_map(ManifestSource::embedded_file_name)
+                    .map(|name| format!("**/{name}")),
+            );
+
+        let mut manifests: BTreeMap<PathBuf, PathBuf> = BTreeMap::new();
+
+        for pattern in patterns {
+            let paths = glob(
+                dir.join(pattern)
+                    .to_str()
+                    .ok_or(Error::Directory("unable to locate dir".to_string()))?,
+            )
+            .map_err(|err| Error::Directory(err.to_string()))?;
+
+            for path in paths {
+                let path = path.map_err(|err| Error::File(err.to_string()))?;
+                let relative = path.strip_prefix(dir).unwrap_or(&path);
+
+                if exclude.iter().any(|pattern| pattern.matches_path(relative))
+                    || !ManifestSource::from_path(&path).contains_metadata(&path)
+                {
+                    continue;
+                }
+
+                // The first match per directory has the highest precedence
+                let parent = path.parent().map(Path::to_path_buf).unwrap_or_default();
+                manifests.entry(parent).or_insert(path);
+            }
+        }
+
+        Ok(manifests.into_values().collect())
+    }
+
+    /// Load the manifest at the given path along with all of its (nested)
+    /// sub-projects.
+    ///
+    /// The manifests are read using the given [`Discovery`] settings and
+    /// aliases, see [`Metadata::from_file_with`].
+    /// The given manifest is always the first element of the result.
+    /// Sub-projects which are declared multiple times are only loaded once.
+    ///
+    /// # Errors
+    /// - if the given manifest or any of its sub-projects cannot be read
+    pub fn load_with_subprojects(
+        path: &Path,
+        discovery: &Discovery,
+        aliases: &Aliases,
+    ) -> Result<Vec<Metadata>> {
+        let mut projects =
+            vec![Metadata::from_file_with(path, discovery, aliases).map_err(manifest_error)?];
+        let mut index = 0;
+
+        while let Some(project) = projects.get(index) {
+            let children = project
+                .subproject_manifests(discovery)
+                .map_err(manifest_error)?;
+
+            for child in children {
+                if projects.iter().any(|el| el.directory == child) {
+                    continue;
+                }
+
+                projects.push(
+                    Metadata::from_file_with(&child, discovery, aliases).map_err(manifest_error)?,
+                );
+            }
+
+            index += 1;
+        }
+
+        Ok(projects)
+    }
+}
+
+fn manifest_error(err: crate::files::Error) -> Error {
+    Error::Manifest(Box::new(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::files::files_test::temp_dir;
+
+    fn write_manifest(path: &Path, title: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        Metadata::builder()
+            .title(title)
+            .directory("/")
+            .build()
+            .unwrap()
+            .write_file(path)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_search_skips_foreign_and_excluded_manifests() {
+        let dir = temp_dir();
+        write_manifest(&dir.join("a/manifest.toml"), "a");
+        write_manifest(&dir.join("node_modules/b/manifest.toml"), "b");
+        fs::create_dir_all(dir.join("c")).unwrap();
+        fs::write(dir.join("c/manifest.toml"), "[tool]\nname = \"c\"\n").unwrap();
+
+        let result = MetadataHandler::search_directory(&dir, &Discovery::default()).unwrap();
+
+        assert_eq!(result, vec![dir.join("a/manifest.toml")]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_search_with_configured_names_and_patterns() {
+        let dir = temp_dir();
+        write_manifest(&dir.join("a/.episko.toml"), "a");
+        write_manifest(&dir.join("a/manifest.toml"), "ignored");
+        write_manifest(&dir.join("b/project.toml"), "b");
+
+        let discovery = Discovery {
+            manifest_names: vec![".episko.toml".to_string()],
+            patterns: vec!["b/*.toml".to_string()],
+            ..Discovery::default()
+        };
+
+        let result = MetadataHandler::search_directory(&dir, &discovery).unwrap();
+
+        assert_eq!(
+            result,
+            vec![dir.join("a/.episko.toml"), dir.join("b/project.toml")]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_with_subprojects() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        let root = Metadata::builder()
+            .title("root")
+            .directory_path(&dir)
+            .subprojects(vec!["packages/*".to_string()])
+            .build()
+            .unwrap();
+        root.write_file(root.directory()).unwrap();
+        write_manifest(&dir.join("packages/a/manifest.toml"), "a");
+        write_manifest(&dir.join("packages/b/manifest.toml"), "b");
+
+        let result = MetadataHandler::load_with_subprojects(
+            &dir,
+            &Discovery::default(),
+            &Aliases::default(),
         )
-        .map_err(|err| Error::Directory(err.to_string()))?
-        .map(|manifest| manifest.map_err(|err| Error::File(err.to_string())))
-        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].title, "root");
+        assert!(result[1..].iter().all(|el| el.parent == Some(root.id)));
+
+        fs::remove_dir_all(dir).unwrap();
     }
 }
@@ -0,0 +1,143 @@
+use crate::database::DatabaseObject;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use super::property::{self, Property, PropertyKind};
+
+/// Person involved in a project, e.g. one of its maintainers.
+///
+/// People are identified by their name, email and role, so the same
+/// person can be listed with different roles in different projects.
+/// ```
+/// use episko_lib::metadata::Person;
+///
+/// let person: Person = "Jane Doe:jane@example.com:maintainer".parse().unwrap();
+///
+/// person.email.as_deref(); // -> Some("jane@example.com")
+/// person.role.as_deref(); // -> Some("maintainer")
+/// ```
+#[derive(Debug, Serialize, Deserialize, JsonSchema, DatabaseObject, FromRow, Clone)]
+#[db(table = "person")]
+pub struct Person {
+    #[serde(skip)]
+    #[db(col = "id")]
+    id: Vec<u8>,
+    #[db(col = "name")]
+    pub name: String,
+    #[db(col = "email")]
+    pub email: Option<String>,
+    #[db(col = "role")]
+    pub role: Option<String>,
+}
+
+impl Person {
+    #[must_use]
+    pub fn with_email(mut self, email: &str) -> Self {
+        self.email = Some(email.to_string());
+        self.update_id();
+        self
+    }
+
+    #[must_use]
+    pub fn with_role(mut self, role: &str) -> Self {
+        self.role = Some(role.to_string());
+        self.update_id();
+        self
+    }
+}
+
+impl Property for Person {
+    const KIND: PropertyKind = PropertyKind::Person;
+
+    fn new(name: &str) -> Self {
+        let mut s = Self {
+            id: vec![],
+            name: name.to_string(),
+            email: None,
+            role: None,
+        };
+        s.update_id();
+        s
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.update_id();
+    }
+
+    fn canonical(&self) -> String {
+        self.id_key()
+    }
+
+    fn id_key(&self) -> String {
+        format!(
+            "{}<{}>{}",
+            self.name,
+            self.email.as_deref().unwrap_or_default(),
+            self.role.as_deref().unwrap_or_default()
+        )
+        .to_lowercase()
+    }
+
+    fn update_id(&mut self) {
+        self.id = self.generate_id().to_vec();
+    }
+}
+
+impl std::str::FromStr for Person {
+    type Err = super::Error;
+
+    /// Parse a person, syntax: `<NAME>[:<EMAIL>[:<ROLE>]]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ':').map(str::trim);
+
+        let mut person = match parts.next() {
+            Some(name) if !name.is_empty() => Self::new(name),
+            _ => return Err(Self::Err::EmptyName),
+        };
+        if let Some(email) = parts.next().filter(|el| !el.is_empty()) {
+            person = person.with_email(email);
+        }
+        if let Some(role) = parts.next().filter(|el| !el.is_empty()) {
+            person = person.with_role(role);
+        }
+
+        Ok(person)
+    }
+}
+
+property::impl_property_traits!(Person);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_person() {
+        let person: Person = "Jane Doe:jane@example.com:maintainer".parse().unwrap();
+        assert_eq!(person.name, "Jane Doe");
+        assert_eq!(person.email.as_deref(), Some("jane@example.com"));
+        assert_eq!(person.role.as_deref(), Some("maintainer"));
+
+        let person: Person = "Jane Doe::reviewer".parse().unwrap();
+        assert_eq!(person.email, None);
+        assert_eq!(person.role.as_deref(), Some("reviewer"));
+
+        assert!(":jane@example.com".parse::<Person>().is_err());
+    }
+
+    #[test]
+    fn test_person_identity() {
+        let jane = Person::new("Jane Doe").with_email("jane@example.com");
+
+        assert_eq!(jane, Person::new("jane doe").with_email("JANE@example.com"));
+        assert_ne!(jane, Person::new("Jane Doe"));
+        assert_ne!(jane, jane.clone().with_role("maintainer"));
+        assert!(Person::new("Jane Doe") < jane);
+    }
+}
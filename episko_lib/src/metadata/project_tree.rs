@@ -0,0 +1,118 @@
+//! # Hierarchy of projects and their sub-projects
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::MetadataPreview;
+
+/// A project along with all of its (nested) sub-projects.
+#[derive(Debug, Serialize)]
+pub struct ProjectTree {
+    pub project: MetadataPreview,
+    pub children: Vec<ProjectTree>,
+}
+
+impl ProjectTree {
+    /// Build trees from the given projects using their parent links.
+    ///
+    /// Projects whose parent is not part of the given projects are treated
+    /// as roots. The order of the given projects is kept for siblings.
+    #[must_use]
+    pub fn build(projects: Vec<MetadataPreview>) -> Vec<ProjectTree> {
+        let ids: HashSet<Uuid> = projects.iter().map(|project| project.id).collect();
+
+        let mut roots = vec![];
+        let mut children: HashMap<Uuid, Vec<MetadataPreview>> = HashMap::new();
+
+        for project in projects {
+            match project.parent.filter(|parent| ids.contains(parent)) {
+                Some(parent) => children.entry(parent).or_default().push(project),
+                None => roots.push(project),
+            }
+        }
+
+        let mut trees: Vec<ProjectTree> = roots
+            .into_iter()
+            .map(|project| Self::attach(project, &mut children))
+            .collect();
+
+        // Projects left over are part of a cycle, which can only occur
+        // with stale cache entries. They are displayed as roots instead.
+        while let Some(&id) = children.keys().next() {
+            for project in children.remove(&id).unwrap_or_default() {
+                trees.push(Self::attach(project, &mut children));
+            }
+        }
+
+        trees
+    }
+
+    /// Total amount of projects within the tree, including the root.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        1 + self.children.iter().map(ProjectTree::count).sum::<usize>()
+    }
+
+    fn attach(
+        project: MetadataPreview,
+        children: &mut HashMap<Uuid, Vec<MetadataPreview>>,
+    ) -> ProjectTree {
+        let own_children = children.remove(&project.id).unwrap_or_default();
+
+        ProjectTree {
+            project,
+            children: own_children
+                .into_iter()
+                .map(|child| Self::attach(child, children))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn preview(title: &str, parent: Option<Uuid>) -> MetadataPreview {
+        MetadataPreview {
+            id: Uuid::new_v4(),
+            parent,
+            title: title.to_string(),
+            description: None,
+            categories: vec![],
+            languages: vec![],
+            created: Utc::now(),
+            updated: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_nested_tree() {
+        let root = preview("root", None);
+        let child = preview("child", Some(root.id));
+        let grandchild = preview("grandchild", Some(child.id));
+        let orphan = preview("orphan", Some(Uuid::new_v4()));
+
+        let trees = ProjectTree::build(vec![grandchild, child, orphan, root]);
+
+        assert_eq!(trees.len(), 2);
+        assert_eq!(trees[0].project.title, "orphan");
+        assert_eq!(trees[1].project.title, "root");
+        assert_eq!(trees[1].count(), 3);
+        assert_eq!(trees[1].children[0].children[0].project.title, "grandchild");
+    }
+
+    #[test]
+    fn test_build_with_cycle() {
+        let mut first = preview("first", None);
+        let second = preview("second", Some(first.id));
+        first.parent = Some(second.id);
+
+        let trees = ProjectTree::build(vec![first, second]);
+
+        assert_eq!(trees.iter().map(ProjectTree::count).sum::<usize>(), 2);
+    }
+}
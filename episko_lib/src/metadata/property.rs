@@ -1,7 +1,74 @@
 //! # Advanced Property trait and macro
-use serde::{de::DeserializeOwned, Serialize};
+//!
+//! Properties are compared ignoring case, versions are compared
+//! semantically, see [`super::version`].
+//! Aliases such as `TS` for `TypeScript` are replaced by their canonical
+//! names when reading or building metadata, using the configured
+//! [`Aliases`], see [`Property::normalise`].
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::hash::Hash;
+use std::{cmp::Ordering, collections::HashSet, fmt::Display, hash::Hash, str::FromStr};
+
+use crate::config::Aliases;
+
+use super::version::{canonical_version, compare_versions};
+
+/// The different kinds of advanced properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyKind {
+    Category,
+    Language,
+    BuildSystem,
+    Ide,
+    License,
+    Framework,
+    Person,
+}
+
+impl PropertyKind {
+    pub const ALL: [PropertyKind; 7] = [
+        PropertyKind::Category,
+        PropertyKind::Language,
+        PropertyKind::BuildSystem,
+        PropertyKind::Ide,
+        PropertyKind::License,
+        PropertyKind::Framework,
+        PropertyKind::Person,
+    ];
+}
+
+impl FromStr for PropertyKind {
+    type Err = super::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "category" => Ok(PropertyKind::Category),
+            "language" => Ok(PropertyKind::Language),
+            "build_system" => Ok(PropertyKind::BuildSystem),
+            "ide" => Ok(PropertyKind::Ide),
+            "license" => Ok(PropertyKind::License),
+            "framework" => Ok(PropertyKind::Framework),
+            "person" | "people" => Ok(PropertyKind::Person),
+            _ => Err(super::Error::UnknownProperty(s.to_string())),
+        }
+    }
+}
+
+impl Display for PropertyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PropertyKind::Category => "category",
+            PropertyKind::Language => "language",
+            PropertyKind::BuildSystem => "build system",
+            PropertyKind::Ide => "ide",
+            PropertyKind::License => "license",
+            PropertyKind::Framework => "framework",
+            PropertyKind::Person => "person",
+        };
+        write!(f, "{name}")
+    }
+}
 
 /// The Property trait is implemented by advanced properties.
 ///
@@ -20,7 +87,31 @@ use std::hash::Hash;
 /// // for comparisons and id generation
 /// category1.canonical(); // -> "example"
 /// ```
+///
+/// Versions are compared semantically, properties are sorted by
+/// name and version:
+/// ```
+/// use episko_lib::metadata::Language;
+///
+/// Language::with_version("Rust", "1.84") == Language::with_version("Rust", "v1.84.0"); // -> true
+/// Language::with_version("Rust", "1.9") < Language::with_version("Rust", "1.84"); // -> true
+/// ```
+///
+/// Aliases are replaced by their canonical names when normalising:
+/// ```
+/// use episko_lib::config::Aliases;
+/// use episko_lib::metadata::Language;
+/// use episko_lib::metadata::property::Property as _;
+///
+/// let mut language = Language::new("TS");
+///
+/// language.normalise(&Aliases::default());
+/// language.name; // -> "TypeScript"
+/// ```
 pub trait Property: Serialize + DeserializeOwned + PartialEq + Eq + Hash {
+    /// The kind of property, used to resolve aliases.
+    const KIND: PropertyKind;
+
     #[must_use]
     fn new(name: &str) -> Self;
 
@@ -30,7 +121,36 @@ pub trait Property: Serialize + DeserializeOwned + PartialEq + Eq + Hash {
         None
     }
 
+    /// Change the name of the property, keeping its version.
+    fn rename(&mut self, name: &str);
+
     fn canonical(&self) -> String {
+        format!(
+            "{}{}",
+            self.name().to_lowercase(),
+            self.version().map(canonical_version).unwrap_or_default()
+        )
+    }
+
+    /// Replace the name with its canonical name, if it is one of the
+    /// given aliases.
+    ///
+    /// Returns `true` if the name has been changed.
+    fn normalise(&mut self, aliases: &Aliases) -> bool {
+        let name = aliases.canonical_name(Self::KIND, self.name());
+        if name == self.name() {
+            return false;
+        }
+
+        self.rename(&name);
+        true
+    }
+
+    /// Key used to generate the id of the property.
+    ///
+    /// Unlike [`Property::canonical`] the version is only lowercased,
+    /// so ids only depend on the stored fields.
+    fn id_key(&self) -> String {
         format!(
             "{}{}",
             self.name().to_lowercase(),
@@ -40,7 +160,7 @@ pub trait Property: Serialize + DeserializeOwned + PartialEq + Eq + Hash {
 
     fn generate_id(&self) -> [u8; 32] {
         let mut hasher = Sha256::new();
-        hasher.update(self.canonical());
+        hasher.update(self.id_key());
         hasher.finalize().into()
     }
 
@@ -57,12 +177,28 @@ macro_rules! impl_property_traits {
 
         impl ::std::cmp::Eq for $type {}
 
+        impl ::std::cmp::PartialOrd for $type {
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl ::std::cmp::Ord for $type {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                crate::metadata::property::compare(self, other)
+            }
+        }
+
         impl ::std::hash::Hash for $type {
             fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
                 self.canonical().hash(state)
             }
         }
+    };
+}
 
+macro_rules! impl_from_str {
+    ($type:ty) => {
         impl ::std::str::FromStr for $type {
             type Err = crate::metadata::Error;
 
@@ -118,5 +254,82 @@ macro_rules! impl_try_from_tuple {
     };
 }
 
+/// Normalise all given properties using the given aliases, removing
+/// duplicates which are the same afterwards.
+///
+/// Returns `true` if any of the properties has been changed.
+pub fn normalise_all<T: Property>(properties: &mut Vec<T>, aliases: &Aliases) -> bool {
+    let mut changed = false;
+    let mut seen = HashSet::new();
+
+    properties.retain_mut(|el| {
+        changed |= el.normalise(aliases);
+        let unique = seen.insert(el.canonical());
+        changed |= !unique;
+        unique
+    });
+
+    changed
+}
+
+/// Rename all properties named `from` to `to`, removing duplicates
+/// which are the same afterwards.
+///
+/// Names are compared ignoring case and the given aliases.
+///
+/// Returns `true` if any of the properties has been renamed.
+pub fn rename_all<T: Property>(
+    properties: &mut Vec<T>,
+    from: &str,
+    to: &str,
+    aliases: &Aliases,
+) -> bool {
+    let mut renamed = false;
+    for el in properties.iter_mut() {
+        if same_name::<T>(el.name(), from, aliases) {
+            el.rename(to);
+            renamed = true;
+        }
+    }
+
+    if renamed {
+        let mut seen = HashSet::new();
+        properties.retain(|el| seen.insert(el.canonical()));
+    }
+
+    renamed
+}
+
+/// Compare two properties by their name, ignoring case, and their version.
+///
+/// Properties without a version are sorted before versioned ones.
+/// Properties which only differ in other fields are ordered by their
+/// canonical representation, keeping the order consistent with [`Eq`].
+#[must_use]
+pub fn compare<T: Property>(a: &T, b: &T) -> Ordering {
+    let name = |el: &T| el.name().to_lowercase();
+    let versions = (
+        a.version().filter(|el| !el.is_empty()),
+        b.version().filter(|el| !el.is_empty()),
+    );
+
+    name(a)
+        .cmp(&name(b))
+        .then_with(|| match versions {
+            (Some(a), Some(b)) => compare_versions(a, b),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        })
+        .then_with(|| a.canonical().cmp(&b.canonical()))
+}
+
+/// Check whether both names refer to the same property of the given type,
+/// ignoring case and the given aliases.
+#[must_use]
+pub fn same_name<T: Property>(a: &str, b: &str, aliases: &Aliases) -> bool {
+    aliases.canonical_name(T::KIND, a).to_lowercase()
+        == aliases.canonical_name(T::KIND, b).to_lowercase()
+}
+
+pub(crate) use impl_from_str;
 pub(crate) use impl_property_traits;
 pub(crate) use impl_try_from_tuple;
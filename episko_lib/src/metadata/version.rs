@@ -0,0 +1,216 @@
+//! # Versions of properties
+//!
+//! Versions of languages and build systems are stored as free strings, which
+//! are rarely complete semantic versions (e.g. `1.84` or `20`).
+//! They are therefore parsed leniently by [`parse_version`], which allows them
+//! to be compared and sorted numerically.
+//!
+//! Versions which can't be parsed (e.g. `nightly`) keep working as plain
+//! strings, compared ignoring their case.
+//!
+//! Projects can be filtered by a [`VersionConstraint`]:
+//! ```
+//! use episko_lib::metadata::version::VersionConstraint;
+//!
+//! let constraint: VersionConstraint = ">=1.80".parse().unwrap();
+//!
+//! constraint.matches("1.84"); // -> true
+//! constraint.matches("1.70"); // -> false
+//! constraint.matches("nightly"); // -> false
+//! ```
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+
+/// Characters a version requirement can start with.
+const OPERATORS: [char; 6] = ['<', '>', '=', '^', '~', '*'];
+
+/// Parse a version, filling in missing minor and patch components.
+///
+/// A leading `v` as well as surrounding whitespace is ignored,
+/// so `v1.84`, `1.84` and `1.84.0` are all equal.
+#[must_use]
+pub fn parse_version(version: &str) -> Option<Version> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+
+    // Split off pre-release and build metadata, which are kept as is.
+    let end = version.find(['-', '+']).unwrap_or(version.len());
+    let (core, rest) = version.split_at(end);
+
+    let components = core.split('.').count();
+    if components > 3 {
+        return None;
+    }
+    let padding = ".0".repeat(3 - components);
+
+    Version::parse(&format!("{core}{padding}{rest}")).ok()
+}
+
+/// Compare two versions semantically.
+///
+/// Versions which can't be parsed are compared textually, ignoring case,
+/// and sorted after all valid versions.
+#[must_use]
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    match (parse_version(a), parse_version(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Representation of a version used for comparisons.
+///
+/// Equal versions such as `1.84` and `v1.84.0` share the same representation,
+/// versions which can't be parsed are converted to lowercase.
+#[must_use]
+pub fn canonical_version(version: &str) -> String {
+    parse_version(version).map_or_else(|| version.to_lowercase(), |el| el.to_string())
+}
+
+/// Constraint on the version of a language or build system.
+///
+/// Requirements follow the syntax used by Cargo, e.g. `>=1.80`, `^3.10` or
+/// `>=1.70, <2`. A plain version such as `1.80` is treated as `^1.80`.
+///
+/// Constraints which are neither a requirement nor a version, such as
+/// `nightly`, only match the exact same version, ignoring case.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum VersionConstraint {
+    Requirement(VersionReq),
+    Exact(String),
+}
+
+impl VersionConstraint {
+    /// Check whether the given version satisfies the constraint.
+    ///
+    /// Versions which can't be parsed never satisfy a requirement.
+    #[must_use]
+    pub fn matches(&self, version: &str) -> bool {
+        match self {
+            VersionConstraint::Requirement(requirement) => {
+                parse_version(version).is_some_and(|el| requirement.matches(&el))
+            }
+            VersionConstraint::Exact(exact) => exact.to_lowercase() == version.to_lowercase(),
+        }
+    }
+}
+
+impl FromStr for VersionConstraint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let constraint = s.trim();
+        if constraint.is_empty() {
+            return Err(Error::InvalidVersionConstraint(s.to_string()));
+        }
+
+        if constraint.starts_with(OPERATORS) || constraint.contains(',') {
+            return VersionReq::parse(constraint)
+                .map(VersionConstraint::Requirement)
+                .map_err(|_| Error::InvalidVersionConstraint(s.to_string()));
+        }
+
+        let version = constraint.strip_prefix(['v', 'V']).unwrap_or(constraint);
+        match (parse_version(version), VersionReq::parse(version)) {
+            (Some(_), Ok(requirement)) => Ok(VersionConstraint::Requirement(requirement)),
+            _ => Ok(VersionConstraint::Exact(constraint.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for VersionConstraint {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<VersionConstraint> for String {
+    fn from(value: VersionConstraint) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for VersionConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionConstraint::Requirement(requirement) => write!(f, "{requirement}"),
+            VersionConstraint::Exact(exact) => write!(f, "{exact}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("1.84"), Some(Version::new(1, 84, 0)));
+        assert_eq!(parse_version("v20"), Some(Version::new(20, 0, 0)));
+        assert_eq!(parse_version("3.13-beta1").unwrap().pre.as_str(), "beta1");
+        assert_eq!(parse_version("latest"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1.9", "1.84"), Ordering::Less);
+        assert_eq!(compare_versions("v1.84", "1.84.0"), Ordering::Equal);
+        assert_eq!(compare_versions("nightly", "1.84"), Ordering::Greater);
+        assert_eq!(compare_versions("Nightly", "nightly"), Ordering::Equal);
+        assert_eq!(canonical_version("v1.84"), canonical_version("1.84.0"));
+    }
+
+    #[test]
+    fn test_version_keeps_id() {
+        use crate::metadata::{property::Property as _, Language};
+
+        let short = Language::with_version("Rust", "1.84");
+        let long = Language::with_version("Rust", "1.84.0");
+        assert_eq!(short, long);
+        assert_ne!(short.generate_id(), long.generate_id());
+        assert_eq!(
+            short.generate_id(),
+            Language::with_version("rust", "1.84").generate_id()
+        );
+    }
+
+    #[test]
+    fn test_version_constraint() {
+        let at_least: VersionConstraint = ">=1.80".parse().unwrap();
+        assert!(at_least.matches("1.80"));
+        assert!(at_least.matches("v1.84.1"));
+        assert!(!at_least.matches("1.79"));
+        assert!(!at_least.matches("stable"));
+
+        let caret: VersionConstraint = "^3.10".parse().unwrap();
+        assert!(caret.matches("3.12"));
+        assert!(!caret.matches("4.0"));
+
+        let plain: VersionConstraint = "v3".parse().unwrap();
+        assert!(plain.matches("3.12"));
+
+        let exact: VersionConstraint = "Nightly".parse().unwrap();
+        assert_eq!(exact, VersionConstraint::Exact("Nightly".to_string()));
+        assert!(exact.matches("nightly"));
+
+        assert!(">=one".parse::<VersionConstraint>().is_err());
+        assert!(" ".parse::<VersionConstraint>().is_err());
+    }
+
+    #[test]
+    fn test_version_constraint_serde() {
+        let constraint: VersionConstraint = serde_json::from_str(r#"">=1.80""#).unwrap();
+        assert_eq!(serde_json::to_string(&constraint).unwrap(), r#"">=1.80""#);
+        assert!(serde_json::from_str::<VersionConstraint>(r#""<""#).is_err());
+    }
+}
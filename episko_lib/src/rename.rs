@@ -0,0 +1,343 @@
+//! # Renaming and merging properties
+//!
+//! This module renames a category, language, build system or IDE across all
+//! cached projects, rewriting both their manifests and the cache.
+//!
+//! Merging is done by renaming several properties to the same name,
+//! duplicates within a project are removed in the process.
+//!
+//! ## Consistency
+//! All affected manifests are read before anything is written. The cache is
+//! updated within a single transaction. If writing a manifest or updating
+//! the cache fails, all manifests written so far are restored to their
+//! previous content.
+//! Using [`RenameHandler::preview`] the affected projects can be determined
+//! without changing anything.
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{
+    config::{Aliases, Discovery},
+    database::{self, DatabaseHandler},
+    metadata::{property::PropertyKind, Metadata},
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Rename of properties of one kind, merging all given names into one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rename {
+    pub kind: PropertyKind,
+    /// Names to rename, compared ignoring case and aliases.
+    pub from: Vec<String>,
+    /// The new name.
+    pub to: String,
+}
+
+impl Rename {
+    /// Rename of a single property.
+    #[must_use]
+    pub fn new(kind: PropertyKind, from: &str, to: &str) -> Self {
+        Self {
+            kind,
+            from: vec![from.to_string()],
+            to: to.to_string(),
+        }
+    }
+
+    /// Apply the rename to the given metadata, resolving the given aliases
+    /// when comparing names.
+    ///
+    /// Returns `true` if any property has been renamed.
+    pub fn apply_to(&self, metadata: &mut Metadata, aliases: &Aliases) -> bool {
+        let mut renamed = false;
+        for from in &self.from {
+            renamed |= metadata.rename_property(self.kind, from, &self.to, aliases);
+        }
+        renamed
+    }
+}
+
+/// A project affected by a [`Rename`].
+#[derive(Debug, Clone, Serialize)]
+pub struct RenamedProject {
+    pub id: Uuid,
+    pub title: String,
+    pub manifest: PathBuf,
+    /// Whether the manifest itself is changed, which is not the case
+    /// if the property is only inherited from a parent.
+    pub manifest_changed: bool,
+}
+
+pub struct RenameHandler;
+
+impl RenameHandler {
+    /// Determine all cached projects affected by the rename,
+    /// without changing anything.
+    ///
+    /// # Errors
+    /// - [`Error::EmptyName`] if the new name is empty
+    /// - if the cached projects or their manifests cannot be read
+    pub async fn preview(
+        rename: &Rename,
+        discovery: &Discovery,
+        aliases: &Aliases,
+        db: &DatabaseHandler,
+    ) -> Result<Vec<RenamedProject>> {
+        Ok(Self::plan(rename, discovery, aliases, db)
+            .await?
+            .into_iter()
+            .map(|el| el.project)
+            .collect())
+    }
+
+    /// Rename the properties within all affected manifests and the cache.
+    ///
+    /// # Errors
+    /// - [`Error::EmptyName`] if the new name is empty
+    /// - if the cached projects or their manifests cannot be read
+    /// - if a manifest cannot be written or the cache cannot be updated,
+    ///   in which case all manifests are restored
+    pub async fn apply(
+        rename: &Rename,
+        discovery: &Discovery,
+        aliases: &Aliases,
+        db: &DatabaseHandler,
+    ) -> Result<Vec<RenamedProject>> {
+        let plan = Self::plan(rename, discovery, aliases, db).await?;
+
+        let mut written: Vec<&PlannedChange> = vec![];
+        for change in &plan {
+            let result = match &change.manifest {
+                Some(manifest) => {
+                    manifest.write_file_with(&change.project.manifest, discovery, aliases)
+                }
+                None => Ok(()),
+            };
+
+            if let Err(err) = result {
+                Self::restore(&written);
+                return Err(err.into());
+            }
+            written.push(change);
+        }
+
+        if let Err(err) = Self::update_cache(&plan, db).await {
+            Self::restore(&written);
+            return Err(err.into());
+        }
+
+        Ok(plan.into_iter().map(|el| el.project).collect())
+    }
+
+    /// Update the cached metadata of all planned changes within a single
+    /// transaction, so either all or none of them are updated.
+    async fn update_cache(plan: &[PlannedChange], db: &DatabaseHandler) -> database::Result<()> {
+        let mut transaction = db.conn().begin().await?;
+        for change in plan {
+            change.cached.update_with(&mut transaction).await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    /// Read all affected projects and apply the rename to them in memory.
+    ///
+    /// Manifests are located using the given [`Discovery`] settings, names
+    /// are compared resolving the given aliases.
+    async fn plan(
+        rename: &Rename,
+        discovery: &Discovery,
+        aliases: &Aliases,
+        db: &DatabaseHandler,
+    ) -> Result<Vec<PlannedChange>> {
+        if rename.to.trim().is_empty() {
+            return Err(Error::EmptyName);
+        }
+
+        let mut plan = vec![];
+        for mut cached in Metadata::all_from_db(None, db).await? {
+            if !rename.apply_to(&mut cached, aliases) {
+                continue;
+            }
+
+            let path = cached.directory().to_path_buf();
+            let original = fs::read(&path)?;
+            let mut manifest = Metadata::read_manifest(&path, discovery)?;
+            let manifest_changed = rename.apply_to(&mut manifest, aliases);
+
+            plan.push(PlannedChange {
+                project: RenamedProject {
+                    id: cached.id,
+                    title: cached.title.clone(),
+                    manifest: path,
+                    manifest_changed,
+                },
+                manifest: manifest_changed.then_some(manifest),
+                cached,
+                original,
+            });
+        }
+
+        Ok(plan)
+    }
+
+    /// Restore the original content of the given manifests.
+    ///
+    /// Errors are ignored, as the manifests are already in an inconsistent state.
+    fn restore(changes: &[&PlannedChange]) {
+        for change in changes {
+            let _ = fs::write(&change.project.manifest, &change.original);
+        }
+    }
+}
+
+/// Change of a single project, prepared before anything is written.
+struct PlannedChange {
+    project: RenamedProject,
+    /// The renamed manifest, if the manifest itself changes.
+    manifest: Option<Metadata>,
+    /// The renamed cached metadata, including inherited properties.
+    cached: Metadata,
+    /// The content of the manifest before the rename.
+    original: Vec<u8>,
+}
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("name cant be empty")]
+    EmptyName,
+
+    #[error("io error")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to access manifest")]
+    File(#[from] crate::files::Error),
+
+    #[error("failed to access cache")]
+    Db(#[from] crate::database::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use sqlx::SqlitePool;
+
+    use super::*;
+    use crate::{
+        files::{files_test::temp_dir, File as _},
+        metadata::{property::Property as _, Category},
+    };
+
+    async fn write_project(dir: &Path, title: &str, categories: &[&str], db: &DatabaseHandler) {
+        fs::create_dir_all(dir).unwrap();
+        let metadata = Metadata::builder()
+            .title(title)
+            .directory_path(dir)
+            .categories(categories.iter().map(|el| Category::new(el)).collect())
+            .build()
+            .unwrap();
+        metadata.write_file(metadata.directory()).unwrap();
+        metadata.write_to_db(db).await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_merge(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        let dir = temp_dir();
+        write_project(&dir.join("a"), "A", &["Uni", "Web"], &db).await;
+        write_project(&dir.join("b"), "B", &["university"], &db).await;
+        write_project(&dir.join("c"), "C", &["CLI"], &db).await;
+
+        let rename = Rename {
+            kind: PropertyKind::Category,
+            from: vec!["uni".to_string(), "University".to_string()],
+            to: "Studies".to_string(),
+        };
+
+        let preview =
+            RenameHandler::preview(&rename, &Discovery::default(), &Aliases::default(), &db)
+                .await
+                .unwrap();
+        assert_eq!(preview.len(), 2);
+        let untouched = Metadata::from_file(&dir.join("a/manifest.toml")).unwrap();
+        assert!(untouched.categories.iter().any(|el| el.name == "Uni"));
+
+        let applied =
+            RenameHandler::apply(&rename, &Discovery::default(), &Aliases::default(), &db)
+                .await
+                .unwrap();
+        assert_eq!(applied.len(), 2);
+        assert!(applied.iter().all(|el| el.manifest_changed));
+
+        let a = Metadata::from_file(&dir.join("a/manifest.toml")).unwrap();
+        let mut names: Vec<_> = a.categories.iter().map(|el| el.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["Studies", "Web"]);
+
+        let cached = Metadata::from_db(&db, applied[0].id).await.unwrap();
+        assert!(cached.categories.iter().any(|el| el.name == "Studies"));
+        assert!(
+            RenameHandler::preview(&rename, &Discovery::default(), &Aliases::default(), &db)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_failed_cache_update_changes_nothing(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        let dir = temp_dir();
+        write_project(&dir.join("a"), "A", &["Uni"], &db).await;
+        write_project(&dir.join("b"), "B", &["Uni"], &db).await;
+        let original = fs::read_to_string(dir.join("a/manifest.toml")).unwrap();
+
+        sqlx::query(
+            "CREATE TRIGGER fail_update BEFORE UPDATE ON metadata WHEN NEW.title = 'B'
+             BEGIN SELECT RAISE(ABORT, 'update failed'); END;",
+        )
+        .execute(db.conn())
+        .await
+        .unwrap();
+
+        let rename = Rename::new(PropertyKind::Category, "Uni", "Studies");
+        assert!(
+            RenameHandler::apply(&rename, &Discovery::default(), &Aliases::default(), &db)
+                .await
+                .is_err()
+        );
+
+        assert_eq!(
+            fs::read_to_string(dir.join("a/manifest.toml")).unwrap(),
+            original
+        );
+        assert_eq!(
+            RenameHandler::preview(&rename, &Discovery::default(), &Aliases::default(), &db)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_empty_name(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+
+        let rename = Rename::new(PropertyKind::Language, "Rust", " ");
+
+        assert!(matches!(
+            RenameHandler::preview(&rename, &Discovery::default(), &Aliases::default(), &db).await,
+            Err(Error::EmptyName)
+        ));
+    }
+}
@@ -0,0 +1,446 @@
+//! # Static site of the project catalogue
+//!
+//! This module generates a self-contained static HTML site from all
+//! cached projects, which can be published without running the GUI.
+//!
+//! ## Structure
+//! - `index.html`: All projects with client-side filtering and statistics
+//! - `projects/<id>.html`: One page per project with all of its properties
+//!
+//! Styles and scripts are inlined into every page, so the site doesn't
+//! depend on any other files or network access.
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write as _,
+    fs,
+    path::Path,
+};
+
+use thiserror::Error;
+
+use crate::{
+    database::DatabaseHandler,
+    metadata::{property::Property, Link, Metadata},
+    statistics::{statistic_handler::StatisticHandler, Statistic},
+};
+
+static STYLE: &str = include_str!("site/style.css");
+static FILTER_SCRIPT: &str = include_str!("site/filter.js");
+
+/// Directory within the site containing the project pages.
+pub static PROJECTS_DIR: &str = "projects";
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub struct SiteHandler;
+
+impl SiteHandler {
+    /// Generate the site for all cached projects into the given directory.
+    ///
+    /// Existing pages are overwritten, other files within the directory are kept.
+    /// Returns the amount of generated project pages.
+    ///
+    /// # Errors
+    /// - if the projects or statistics cannot be retrieved
+    /// - if any of the files cannot be written
+    pub async fn generate(out: &Path, db: &DatabaseHandler) -> Result<usize> {
+        let projects = Metadata::all_from_db(None, db).await?;
+        let statistic = StatisticHandler::generate_statistics(db).await?;
+
+        let projects_dir = out.join(PROJECTS_DIR);
+        fs::create_dir_all(&projects_dir)?;
+
+        fs::write(out.join("index.html"), render_index(&projects, &statistic))?;
+        for metadata in &projects {
+            fs::write(
+                projects_dir.join(format!("{}.html", metadata.id)),
+                render_project(metadata),
+            )?;
+        }
+
+        Ok(projects.len())
+    }
+}
+
+/// Render the index page listing all given projects.
+#[must_use]
+pub fn render_index(projects: &[Metadata], statistic: &Statistic) -> String {
+    let categories: BTreeSet<&str> = projects
+        .iter()
+        .flat_map(|el| el.categories.iter().map(Property::name))
+        .collect();
+    let languages: BTreeSet<&str> = projects
+        .iter()
+        .flat_map(|el| el.languages.iter().map(Property::name))
+        .collect();
+
+    let mut body = String::new();
+    let _ = write!(
+        body,
+        r#"<header>
+<h1>Projects</h1>
+<p class="muted"><span id="count">{}</span> of {} projects</p>
+</header>
+<div class="filters">
+<input id="query" type="search" placeholder="Search projects" aria-label="Search projects">
+{}
+{}
+</div>
+<main class="projects">
+"#,
+        projects.len(),
+        projects.len(),
+        render_select("category", "All categories", &categories),
+        render_select("language", "All languages", &languages),
+    );
+
+    for metadata in projects {
+        render_card(&mut body, metadata);
+    }
+    body.push_str("</main>\n");
+
+    render_statistics(&mut body, statistic);
+    let _ = write!(body, "<script>\n{FILTER_SCRIPT}</script>\n");
+
+    render_page("Projects", &body)
+}
+
+/// Render the page of a single project.
+#[must_use]
+pub fn render_project(metadata: &Metadata) -> String {
+    let mut body = String::new();
+    let _ = write!(
+        body,
+        r#"<header>
+<p><a href="../index.html">&larr; All projects</a></p>
+<h1>{}</h1>
+<p class="muted">{}</p>
+</header>
+<dl>
+"#,
+        escape(&metadata.title),
+        escape(metadata.description.as_deref().unwrap_or_default()),
+    );
+
+    let mut row = |term: &str, value: String| {
+        if !value.is_empty() {
+            let _ = writeln!(body, "<dt>{term}</dt><dd>{value}</dd>");
+        }
+    };
+
+    row("Categories", render_tags(&metadata.categories));
+    row("Languages", render_tags(&metadata.languages));
+    row("Build systems", render_tags(&metadata.build_systems));
+    row("Frameworks", render_tags(&metadata.frameworks));
+    row("Licenses", render_tags(&metadata.licenses));
+    row(
+        "People",
+        render_names(metadata.people.iter().map(|person| match &person.role {
+            Some(role) => format!("{} ({role})", person.name),
+            None => person.name.clone(),
+        })),
+    );
+    row(
+        "Preferred IDE",
+        metadata
+            .preferred_ide
+            .as_ref()
+            .map(|el| escape(el.name()))
+            .unwrap_or_default(),
+    );
+    row("Links", render_links(&metadata.links));
+    row("Created", metadata.created.format("%Y-%m-%d").to_string());
+    row("Updated", metadata.updated.format("%Y-%m-%d").to_string());
+
+    body.push_str("</dl>\n");
+
+    render_page(&metadata.title, &body)
+}
+
+fn render_page(title: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{}</title>
+<style>
+{STYLE}</style>
+</head>
+<body>
+{body}</body>
+</html>
+"#,
+        escape(title)
+    )
+}
+
+fn render_card(output: &mut String, metadata: &Metadata) {
+    let search = format!(
+        "{} {}",
+        metadata.title,
+        metadata.description.as_deref().unwrap_or_default()
+    )
+    .to_lowercase();
+
+    let _ = write!(
+        output,
+        r#"<article class="card" data-search="{}" data-categories="{}" data-languages="{}">
+<h2><a href="{PROJECTS_DIR}/{}.html">{}</a></h2>
+"#,
+        escape(&search),
+        escape(&join_names(&metadata.categories)),
+        escape(&join_names(&metadata.languages)),
+        metadata.id,
+        escape(&metadata.title),
+    );
+
+    if let Some(description) = &metadata.description {
+        let _ = writeln!(output, "<p>{}</p>", escape(description));
+    }
+
+    let _ = writeln!(
+        output,
+        "{}\n</article>",
+        render_names(
+            metadata
+                .categories
+                .iter()
+                .map(Property::name)
+                .chain(metadata.languages.iter().map(Property::name))
+                .map(ToString::to_string)
+        )
+    );
+}
+
+fn render_statistics(output: &mut String, statistic: &Statistic) {
+    output.push_str("<section class=\"statistics\">\n");
+
+    for (title, counts) in [
+        ("Languages", &statistic.projects_by_language),
+        ("Categories", &statistic.projects_by_category),
+        ("Build systems", &statistic.projects_by_build_system),
+        ("IDEs", &statistic.projects_by_ide),
+        ("Licenses", &statistic.projects_by_license),
+        ("Frameworks", &statistic.projects_by_framework),
+        ("Maintainers", &statistic.projects_by_maintainer),
+    ] {
+        let _ = writeln!(output, "<div>\n<h3>{title}</h3>\n<table>");
+        for (name, count) in sorted_counts(counts) {
+            let _ = writeln!(output, "<tr><td>{}</td><td>{count}</td></tr>", escape(name));
+        }
+        output.push_str("</table>\n</div>\n");
+    }
+
+    output.push_str("</section>\n");
+}
+
+fn render_select(id: &str, label: &str, options: &BTreeSet<&str>) -> String {
+    let mut select =
+        format!(r#"<select id="{id}" aria-label="{label}"><option value="">{label}</option>"#);
+    for option in options {
+        let option = escape(option);
+        let _ = write!(select, r#"<option value="{option}">{option}</option>"#);
+    }
+    select.push_str("</select>");
+    select
+}
+
+fn render_tags<T: Property>(properties: &[T]) -> String {
+    render_names(properties.iter().map(|property| match property.version() {
+        Some(version) if !version.is_empty() => format!("{} {version}", property.name()),
+        _ => property.name().to_string(),
+    }))
+}
+
+fn render_links(links: &[Link]) -> String {
+    if links.is_empty() {
+        return String::new();
+    }
+
+    let mut list = String::from("<ul>");
+    for link in links {
+        // Only link web urls, to prevent e.g. javascript urls
+        if link.is_web() {
+            let _ = write!(
+                list,
+                r#"<li>{}: <a href="{1}">{1}</a></li>"#,
+                escape(link.title()),
+                escape(&link.url)
+            );
+        } else {
+            let _ = write!(
+                list,
+                "<li>{}: {}</li>",
+                escape(link.title()),
+                escape(&link.url)
+            );
+        }
+    }
+    list.push_str("</ul>");
+    list
+}
+
+fn render_names(names: impl Iterator<Item = String>) -> String {
+    let mut names = names.peekable();
+    if names.peek().is_none() {
+        return String::new();
+    }
+
+    let mut tags = String::from("<ul class=\"tags\">");
+    for name in names {
+        let _ = write!(tags, "<li>{}</li>", escape(&name));
+    }
+    tags.push_str("</ul>");
+    tags
+}
+
+/// Names joined by newlines, which can't be part of a name in the frontend.
+fn join_names<T: Property>(properties: &[T]) -> String {
+    properties
+        .iter()
+        .map(Property::name)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Counts sorted by descending count and name.
+fn sorted_counts(counts: &HashMap<String, u32>) -> Vec<(&String, &u32)> {
+    let mut counts: Vec<_> = counts.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    counts
+}
+
+/// Escape text for use within html content and attributes.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("io error")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to retrieve projects")]
+    Db(#[from] crate::database::Error),
+
+    #[error(transparent)]
+    Statistics(#[from] crate::statistics::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use super::*;
+    use crate::{
+        database::db_test::fill_db,
+        files::files_test::temp_dir,
+        metadata::{Category, Language, LinkKind},
+        statistics::{Bucket, Timeline},
+    };
+
+    fn statistic() -> Statistic {
+        Statistic {
+            projects_by_language: HashMap::from([("Rust".to_string(), 2), ("Go".to_string(), 3)]),
+            projects_by_ide: HashMap::new(),
+            projects_by_category: HashMap::new(),
+            projects_by_build_system: HashMap::new(),
+            projects_by_license: HashMap::new(),
+            projects_by_framework: HashMap::new(),
+            projects_by_maintainer: HashMap::new(),
+            number_of_projects: 5,
+            number_of_languages: 2,
+            timeline: Timeline {
+                bucket: Bucket::Month,
+                created: vec![],
+                updated: vec![],
+                language_adoption: vec![],
+                stale_projects: vec![],
+            },
+            language_versions: vec![],
+            build_system_versions: vec![],
+            below_minimum_version: vec![],
+            languages_used_together: vec![],
+            ide_per_language: vec![],
+            build_systems_per_category: vec![],
+        }
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_render_index() {
+        let metadata = Metadata::builder()
+            .title("<Episko>")
+            .directory("/")
+            .categories(vec![Category::new("Web")])
+            .languages(vec![Language::with_version("Rust", "1.84")])
+            .build()
+            .unwrap();
+
+        let index = render_index(&[metadata], &statistic());
+
+        assert!(index.contains("<h2><a href=\"projects/"));
+        assert!(index.contains("&lt;Episko&gt;</a></h2>"));
+        assert!(index.contains(r#"data-categories="Web" data-languages="Rust""#));
+        assert!(index.contains(r#"<option value="Web">Web</option>"#));
+        assert!(index.contains("<li>Rust</li>"));
+        // Sorted by count
+        assert!(index.find("<td>Go</td>").unwrap() < index.find("<td>Rust</td>").unwrap());
+    }
+
+    #[test]
+    fn test_render_project() {
+        let metadata = Metadata::builder()
+            .title("Episko")
+            .directory("/")
+            .languages(vec![Language::with_version("Rust", "1.84")])
+            .repository_url("https://example.com/episko")
+            .add_link(Link::new(LinkKind::Other, "ssh://example.com/episko"))
+            .build()
+            .unwrap();
+
+        let page = render_project(&metadata);
+
+        assert!(page.contains("<title>Episko</title>"));
+        assert!(page.contains("<li>Rust 1.84</li>"));
+        assert!(page.contains(r#"<li>Repository: <a href="https://example.com/episko">"#));
+        assert!(page.contains("<li>Link: ssh://example.com/episko</li>"));
+        assert!(!page.contains("<dt>Preferred IDE</dt>"));
+    }
+
+    #[sqlx::test]
+    async fn test_generate(conn: SqlitePool) {
+        let db = DatabaseHandler::with_conn(conn);
+        fill_db(3, &db).await;
+        let out = temp_dir();
+
+        let amount = SiteHandler::generate(&out, &db).await.unwrap();
+
+        assert_eq!(amount, 3);
+        assert!(out.join("index.html").is_file());
+        assert_eq!(fs::read_dir(out.join(PROJECTS_DIR)).unwrap().count(), 3);
+
+        fs::remove_dir_all(out).unwrap();
+    }
+}
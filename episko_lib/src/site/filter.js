@@ -0,0 +1,31 @@
+(function () {
+  const query = document.getElementById("query");
+  const category = document.getElementById("category");
+  const language = document.getElementById("language");
+  const count = document.getElementById("count");
+  const cards = Array.from(document.querySelectorAll(".card"));
+
+  function matches(card) {
+    const text = query.value.trim().toLowerCase();
+    const categories = card.dataset.categories.split("\n");
+    const languages = card.dataset.languages.split("\n");
+
+    return (!text || card.dataset.search.includes(text))
+      && (!category.value || categories.includes(category.value))
+      && (!language.value || languages.includes(language.value));
+  }
+
+  function update() {
+    let visible = 0;
+    for (const card of cards) {
+      const show = matches(card);
+      card.hidden = !show;
+      visible += show ? 1 : 0;
+    }
+    count.textContent = visible;
+  }
+
+  for (const input of [query, category, language]) {
+    input.addEventListener("input", update);
+  }
+})();
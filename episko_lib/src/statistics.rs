@@ -1,11 +1,25 @@
 //! # Statistics about all projects
+//!
+//! Besides point-in-time counts, metrics over time are available
+//! using a [`Timeline`] and the distribution of language and build system
+//! versions is checked against the configured [`VersionPolicy`].
+//!
+//! Which properties are used together is described by [`CoOccurrence`]s,
+//! e.g. languages used within the same project or the preferred IDE per language.
 
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::{config::VersionPolicy, database::Filter};
+
 pub mod statistic_handler;
+pub mod timeline;
+pub mod versions;
+
+pub use timeline::{Bucket, Timeline, TimelineOptions};
+pub use versions::{OutdatedProject, VersionCount};
 
 /// Statistic structure containing different metrics about all projects.
 #[derive(Debug, Serialize)]
@@ -14,12 +28,48 @@ pub struct Statistic {
     pub projects_by_ide: HashMap<String, u32>,
     pub projects_by_category: HashMap<String, u32>,
     pub projects_by_build_system: HashMap<String, u32>,
+    pub projects_by_license: HashMap<String, u32>,
+    pub projects_by_framework: HashMap<String, u32>,
+    pub projects_by_maintainer: HashMap<String, u32>,
     pub number_of_projects: u32,
     pub number_of_languages: u32,
+    pub timeline: Timeline,
+    pub language_versions: Vec<VersionCount>,
+    pub build_system_versions: Vec<VersionCount>,
+    pub below_minimum_version: Vec<OutdatedProject>,
+    /// Pairs of languages used within the same project, the first
+    /// being the lexicographically smaller name.
+    pub languages_used_together: Vec<CoOccurrence>,
+    /// Preferred IDEs (second) per language (first).
+    pub ide_per_language: Vec<CoOccurrence>,
+    /// Build systems (second) per category (first).
+    pub build_systems_per_category: Vec<CoOccurrence>,
+}
+
+/// Amount of projects using both of the given properties.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct CoOccurrence {
+    pub first: String,
+    pub second: String,
+    pub count: u32,
+}
+
+/// Options used when generating a [`Statistic`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticOptions {
+    pub timeline: TimelineOptions,
+    pub version_policy: VersionPolicy,
+    /// Only projects matching the filter are included,
+    /// the same way as when listing projects.
+    pub filter: Filter,
 }
 
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed to generate statistics")]
     Db(#[from] crate::database::Error),
+
+    #[error("unknown bucket: {0}")]
+    UnknownBucket(String),
 }
@@ -1,24 +1,77 @@
 //! # Statistic handling
 
-use crate::database::DatabaseHandler;
+use crate::database::{retrieve_metrics::Condition, DatabaseHandler};
 
-use super::{Error, Statistic};
+use super::{Error, Statistic, StatisticOptions, Timeline, TimelineOptions};
 
 pub struct StatisticHandler;
 
 impl StatisticHandler {
-    /// Generate the newest statistics
+    /// Generate the newest statistics, using the default [`StatisticOptions`].
     ///
     /// # Errors
     /// - if any of the database queries fail
     pub async fn generate_statistics(db: &DatabaseHandler) -> Result<Statistic, Error> {
+        Self::generate_statistics_with(db, &StatisticOptions::default()).await
+    }
+
+    /// Generate the newest statistics, using the given [`StatisticOptions`].
+    ///
+    /// The [`Condition`] for the filter of the options is built once
+    /// and used for all metrics.
+    ///
+    /// # Errors
+    /// - if any of the database queries fail
+    pub async fn generate_statistics_with(
+        db: &DatabaseHandler,
+        options: &StatisticOptions,
+    ) -> Result<Statistic, Error> {
+        let condition = &Condition::new(db, &options.filter).await?;
+
         Ok(Statistic {
-            projects_by_language: Statistic::projects_by_language(db).await?,
-            projects_by_ide: Statistic::projects_by_ide(db).await?,
-            projects_by_category: Statistic::projects_by_category(db).await?,
-            projects_by_build_system: Statistic::projects_by_build_system(db).await?,
-            number_of_projects: Statistic::number_of_projects(db).await?,
-            number_of_languages: Statistic::number_of_languages(db).await?,
+            projects_by_language: Statistic::projects_by_language(db, condition).await?,
+            projects_by_ide: Statistic::projects_by_ide(db, condition).await?,
+            projects_by_category: Statistic::projects_by_category(db, condition).await?,
+            projects_by_build_system: Statistic::projects_by_build_system(db, condition).await?,
+            projects_by_license: Statistic::projects_by_license(db, condition).await?,
+            projects_by_framework: Statistic::projects_by_framework(db, condition).await?,
+            projects_by_maintainer: Statistic::projects_by_maintainer(db, condition).await?,
+            number_of_projects: Statistic::number_of_projects(db, condition).await?,
+            number_of_languages: Statistic::number_of_languages(db, condition).await?,
+            timeline: Self::generate_timeline(db, options.timeline, condition).await?,
+            language_versions: Statistic::language_versions(db, condition).await?,
+            build_system_versions: Statistic::build_system_versions(db, condition).await?,
+            below_minimum_version: Statistic::projects_below_minimum(
+                db,
+                &options.version_policy,
+                condition,
+            )
+            .await?,
+            languages_used_together: Statistic::languages_used_together(db, condition).await?,
+            ide_per_language: Statistic::ide_per_language(db, condition).await?,
+            build_systems_per_category: Statistic::build_systems_per_category(db, condition)
+                .await?,
+        })
+    }
+
+    /// Generate the metrics over time of all projects matching the condition.
+    ///
+    /// # Errors
+    /// - if any of the database queries fail
+    pub async fn generate_timeline(
+        db: &DatabaseHandler,
+        options: TimelineOptions,
+        condition: &Condition,
+    ) -> Result<Timeline, Error> {
+        let bucket = options.bucket;
+
+        Ok(Timeline {
+            bucket,
+            created: Statistic::projects_created_per_period(db, bucket, condition).await?,
+            updated: Statistic::projects_updated_per_period(db, bucket, condition).await?,
+            language_adoption: Statistic::language_adoption(db, bucket, condition).await?,
+            stale_projects: Statistic::stale_projects(db, options.stale_after_days, condition)
+                .await?,
         })
     }
 }
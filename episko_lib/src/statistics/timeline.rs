@@ -0,0 +1,129 @@
+//! # Statistics over time
+//!
+//! The [`Timeline`] groups projects into periods of a configurable [`Bucket`]
+//! size, based on their creation and last update.
+//!
+//! As only the last update of a project is cached, a project is only counted
+//! once within [`Timeline::updated`], in the period of its last update.
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Error;
+
+/// Size of the periods projects are grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bucket {
+    Day,
+    Week,
+    #[default]
+    Month,
+    Year,
+}
+
+impl Bucket {
+    /// Format used with sqlite's `strftime` to name the period of a date.
+    #[must_use]
+    pub fn strftime_format(self) -> &'static str {
+        match self {
+            Bucket::Day => "%Y-%m-%d",
+            Bucket::Week => "%Y-W%W",
+            Bucket::Month => "%Y-%m",
+            Bucket::Year => "%Y",
+        }
+    }
+}
+
+impl FromStr for Bucket {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "day" => Ok(Bucket::Day),
+            "week" => Ok(Bucket::Week),
+            "month" => Ok(Bucket::Month),
+            "year" => Ok(Bucket::Year),
+            _ => Err(Error::UnknownBucket(s.to_string())),
+        }
+    }
+}
+
+impl Display for Bucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Bucket::Day => "day",
+            Bucket::Week => "week",
+            Bucket::Month => "month",
+            Bucket::Year => "year",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Options used when generating a [`Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimelineOptions {
+    pub bucket: Bucket,
+    /// Projects not updated for at least this many days are considered stale.
+    pub stale_after_days: u32,
+}
+
+impl Default for TimelineOptions {
+    fn default() -> Self {
+        Self {
+            bucket: Bucket::default(),
+            stale_after_days: 180,
+        }
+    }
+}
+
+/// Metrics about all projects over time, ordered by period.
+#[derive(Debug, Serialize)]
+pub struct Timeline {
+    pub bucket: Bucket,
+    /// Projects created per period.
+    pub created: Vec<PeriodCount>,
+    /// Projects last updated per period.
+    pub updated: Vec<PeriodCount>,
+    /// Projects created per period and language.
+    pub language_adoption: Vec<LanguageAdoption>,
+    /// Projects not updated within the configured amount of days,
+    /// the least recently updated first.
+    pub stale_projects: Vec<StaleProject>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct PeriodCount {
+    pub period: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct LanguageAdoption {
+    pub period: String,
+    pub language: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StaleProject {
+    pub id: Uuid,
+    pub title: String,
+    pub updated: DateTime<Utc>,
+    pub days_since_update: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_from_str() {
+        assert_eq!(Bucket::from_str("Week").unwrap(), Bucket::Week);
+        assert!(Bucket::from_str("decade").is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! # Version distribution
+//!
+//! Groups projects by name and version of their languages and build systems
+//! and checks them against a [`VersionPolicy`].
+//!
+//! Versions are sorted and compared semantically, see [`crate::metadata::version`].
+use std::collections::HashMap;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    config::VersionPolicy,
+    metadata::version::{canonical_version, compare_versions, parse_version},
+};
+
+/// Amount of projects using a specific version of a language or build system.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct VersionCount {
+    pub name: String,
+    /// `None` if no version was recorded.
+    pub version: Option<String>,
+    pub count: u32,
+}
+
+/// Kind of property a minimum version is required for.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionedProperty {
+    Language,
+    BuildSystem,
+}
+
+/// A project using a version below the one required by the [`VersionPolicy`].
+#[derive(Debug, Serialize)]
+pub struct OutdatedProject {
+    pub id: Uuid,
+    pub title: String,
+    pub property: VersionedProperty,
+    pub name: String,
+    pub version: String,
+    pub minimum: String,
+}
+
+/// Merge counts of the same name and an equal version, e.g. `1.84` and
+/// `v1.84.0`, see [`canonical_version`].
+///
+/// Names are compared ignoring case, the spelling of the first count
+/// is kept.
+#[must_use]
+pub fn merge_version_counts(counts: Vec<VersionCount>) -> Vec<VersionCount> {
+    let mut merged: Vec<VersionCount> = vec![];
+    let mut index: HashMap<(String, Option<String>), usize> = HashMap::new();
+
+    for count in counts {
+        let key = (
+            count.name.to_lowercase(),
+            count.version.as_deref().map(canonical_version),
+        );
+
+        if let Some(&i) = index.get(&key) {
+            merged[i].count += count.count;
+        } else {
+            index.insert(key, merged.len());
+            merged.push(count);
+        }
+    }
+
+    merged
+}
+
+/// Sort by name and version, projects without a version first.
+pub fn sort_version_counts(counts: &mut [VersionCount]) {
+    counts.sort_by(|a, b| {
+        a.name
+            .cmp(&b.name)
+            .then_with(|| match (&a.version, &b.version) {
+                (Some(a), Some(b)) => compare_versions(a, b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            })
+    });
+}
+
+/// Check whether the given version is below the required minimum.
+///
+/// Returns `false` if either version can't be parsed, as no
+/// meaningful comparison is possible.
+#[must_use]
+pub fn is_below_minimum(version: &str, minimum: &str) -> bool {
+    match (parse_version(version), parse_version(minimum)) {
+        (Some(version), Some(minimum)) => version < minimum,
+        _ => false,
+    }
+}
+
+impl VersionedProperty {
+    /// Minimum version required by the policy for the given name.
+    #[must_use]
+    pub fn minimum<'a>(self, policy: &'a VersionPolicy, name: &str) -> Option<&'a str> {
+        match self {
+            VersionedProperty::Language => policy.minimum_language(name),
+            VersionedProperty::BuildSystem => policy.minimum_build_system(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_version_counts() {
+        let count = |name: &str, version: Option<&str>| VersionCount {
+            name: name.to_string(),
+            version: version.map(ToString::to_string),
+            count: 1,
+        };
+        let mut counts = vec![
+            count("Rust", Some("1.84")),
+            count("Rust", Some("nightly")),
+            count("Rust", Some("1.9")),
+            count("Python", Some("3.12")),
+            count("Rust", None),
+            count("Python", Some("3.8")),
+        ];
+
+        sort_version_counts(&mut counts);
+
+        let sorted: Vec<_> = counts
+            .iter()
+            .map(|el| (el.name.as_str(), el.version.as_deref()))
+            .collect();
+        assert_eq!(
+            sorted,
+            vec![
+                ("Python", Some("3.8")),
+                ("Python", Some("3.12")),
+                ("Rust", None),
+                ("Rust", Some("1.9")),
+                ("Rust", Some("1.84")),
+                ("Rust", Some("nightly")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_below_minimum() {
+        assert!(is_below_minimum("1.70", "1.80"));
+        assert!(!is_below_minimum("1.84.1", "1.80"));
+        assert!(!is_below_minimum("1.80.0", "v1.80"));
+        assert!(!is_below_minimum("stable", "1.80"));
+    }
+}
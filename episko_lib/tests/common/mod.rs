@@ -38,7 +38,7 @@ pub fn generate_metadata(num: usize) -> Metadata {
         builder = builder.add_build_system(BuildSystem::new(BUILD_SYSTEMS[i]));
     }
 
-    if num % 2 == 0 {
+    if num.is_multiple_of(2) {
         builder = builder.description(&format!("This is test project {}", num + 1));
     }
 
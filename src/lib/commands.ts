@@ -1,13 +1,15 @@
-import { invoke } from '@tauri-apps/api/core';
+import { invoke as tauriInvoke, type InvokeArgs } from '@tauri-apps/api/core';
 import type {
 	Category,
 	Filter,
 	FormMetadata,
 	Language,
+	Link,
 	Metadata,
 	MetadataPreview,
 	PagedMetadataPreview,
 	Statistic,
+	TimelineOptions,
 	Uuid
 } from './types';
 import {
@@ -20,6 +22,23 @@ import { PagedMetadataPreviewSchema } from './schemas/pagedData';
 import { parseCategoryArray } from './schemas/category';
 import { parseLanguageArray } from './schemas/language';
 import { parseStatistics } from './schemas/statistics';
+import { parseCommandError } from './schemas/error';
+
+/** Invoke a backend command, turning its errors into a `CommandError`. */
+async function invoke<T>(cmd: string, args?: InvokeArgs): Promise<T> {
+	return tauriInvoke<T>(cmd, args).catch((err) => {
+		throw parseCommandError(err);
+	});
+}
+
+/** Replace empty filter values with `null`, as expected by the backend. */
+function sanitizeFilter(filter: Filter): Filter {
+	return {
+		query: filter.query === '' ? null : filter.query,
+		category: filter.category === '' ? null : filter.category,
+		language: filter.language === '' ? null : filter.language
+	};
+}
 
 export default {
 	async init_cache(): Promise<void> {
@@ -27,14 +46,8 @@ export default {
 	},
 
 	async get_all(pageNumber: number, filter: Filter): Promise<PagedMetadataPreview> {
-		let sanitizedFilter: Filter = {
-			query: filter.query === '' ? null : filter.query,
-			category: filter.category === '' ? null : filter.category,
-			language: filter.language === '' ? null : filter.language
-		};
-
-		return invoke('get_all', { pageNumber: pageNumber, filter: sanitizedFilter }).then((data) =>
-			PagedMetadataPreviewSchema.parse(data)
+		return invoke('get_all', { pageNumber: pageNumber, filter: sanitizeFilter(filter) }).then(
+			(data) => PagedMetadataPreviewSchema.parse(data)
 		);
 	},
 
@@ -50,8 +63,11 @@ export default {
 		return invoke('get_all_languages').then((data) => parseLanguageArray(data));
 	},
 
-	async get_statistics(): Promise<Statistic> {
-		return invoke('get_statistics').then((data) => parseStatistics(data));
+	async get_statistics(timeline?: TimelineOptions, filter?: Filter): Promise<Statistic> {
+		return invoke('get_statistics', {
+			timeline: timeline ?? null,
+			filter: filter ? sanitizeFilter(filter) : null
+		}).then((data) => parseStatistics(data));
 	},
 
 	async create_metadata(created: FormMetadata): Promise<Uuid> {
@@ -70,12 +86,15 @@ export default {
 		let metadataDto = {
 			build_systems: metadata.buildSystems,
 			preferred_ide: metadata.preferredIde,
-			repository_url: metadata.repositoryUrl,
 			...metadata
 		};
 		return invoke('delete_metadata', { metadata: metadataDto });
 	},
 
+	async open_link(link: Link): Promise<void> {
+		return invoke('open_link', { link: link });
+	},
+
 	async load_from_file(path: string): Promise<Uuid> {
 		return invoke('load_from_file', { path: path });
 	},
@@ -0,0 +1,64 @@
+import { z } from 'zod';
+import type { ParseError } from '$lib/types';
+
+export const ParseErrorLocationSchema = z.object({
+	line: z.number(),
+	column: z.number(),
+	span: z.object({ start: z.number(), end: z.number() }),
+	source_line: z.string()
+});
+
+export const ParseErrorSchema = z.object({
+	path: z.string(),
+	message: z.string(),
+	location: ParseErrorLocationSchema.nullable()
+});
+
+export const CommandErrorSchema = z.object({
+	message: z.string(),
+	parse: ParseErrorSchema.nullable(),
+	problems: z.array(z.string()).default([])
+});
+
+/**
+ * Error returned by a backend command.
+ *
+ * Errors caused by files which couldn't be parsed carry the location
+ * of the offending value within that file, errors caused by invalid
+ * metadata carry each of its problems.
+ */
+export class CommandError extends Error {
+	parse: ParseError | null;
+	problems: string[];
+
+	constructor(message: string, parse: ParseError | null = null, problems: string[] = []) {
+		super(message);
+		this.name = 'CommandError';
+		this.parse = parse;
+		this.problems = problems;
+	}
+
+	/** The single problems of the error, or else its message. */
+	messages(): string[] {
+		return this.problems.length > 0 ? this.problems : [this.toString()];
+	}
+
+	/** Message including the location of the parse error, if any. */
+	toString(): string {
+		if (!this.parse) {
+			return this.message;
+		}
+		const { path, message, location } = this.parse;
+		return location
+			? `${path}:${location.line}:${location.column}: ${message}`
+			: `${path}: ${message}`;
+	}
+}
+
+export function parseCommandError(err: unknown): CommandError {
+	const result = CommandErrorSchema.safeParse(err);
+	if (result.success) {
+		return new CommandError(result.data.message, result.data.parse, result.data.problems);
+	}
+	return new CommandError(String(err));
+}
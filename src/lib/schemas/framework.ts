@@ -0,0 +1,6 @@
+import { z } from 'zod';
+
+export const FrameworkSchema = z.object({
+	name: z.string().nonempty(),
+	version: z.string().optional().nullable()
+});
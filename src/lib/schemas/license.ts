@@ -0,0 +1,5 @@
+import { z } from 'zod';
+
+export const LicenseSchema = z.object({
+	name: z.string().nonempty()
+});
@@ -0,0 +1,17 @@
+import { z } from 'zod';
+
+export const LinkKindSchema = z.enum([
+	'repository',
+	'documentation',
+	'ci',
+	'issue_tracker',
+	'deployment',
+	'homepage',
+	'other'
+]);
+
+export const LinkSchema = z.object({
+	kind: LinkKindSchema,
+	url: z.string().url(),
+	label: z.string().optional().nullable()
+});
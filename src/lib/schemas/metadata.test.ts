@@ -13,7 +13,7 @@ describe('Metadata Functions', () => {
 				languages: [{ name: 'Language 1' }], // Example language
 				build_systems: [{ name: 'Build System 1' }], // Example build system
 				preferred_ide: null,
-				repository_url: null,
+				links: [{ kind: 'repository', url: 'https://example.com/project', label: null }],
 				created: '2023-01-01T00:00:00.000Z',
 				updated: '2023-01-02T00:00:00.000Z'
 			};
@@ -28,8 +28,11 @@ describe('Metadata Functions', () => {
 				categories: [{ name: 'Category 1' }],
 				languages: [{ name: 'Language 1' }],
 				buildSystems: [{ name: 'Build System 1' }],
+				licenses: [],
+				frameworks: [],
+				people: [],
 				preferredIde: undefined,
-				repositoryUrl: undefined,
+				links: [{ kind: 'repository', url: 'https://example.com/project', label: null }],
 				created: new Date('2023-01-01T00:00:00.000Z'),
 				updated: new Date('2023-01-02T00:00:00.000Z')
 			});
@@ -3,6 +3,10 @@ import { CategorySchema } from './category';
 import { LanguageSchema } from './language';
 import { BuildSystemSchema } from './buildSystem';
 import { IdeSchema } from './ide';
+import { LicenseSchema } from './license';
+import { FrameworkSchema } from './framework';
+import { PersonSchema } from './person';
+import { LinkSchema } from './link';
 import type { FormMetadata, Metadata, MetadataDco, MetadataPreview } from '$lib/types';
 
 export const UuidSchema = z.string().uuid();
@@ -15,8 +19,11 @@ export const MetadataDtoSchema = z.object({
 	categories: z.array(CategorySchema),
 	languages: z.array(LanguageSchema),
 	build_systems: z.array(BuildSystemSchema),
+	licenses: z.array(LicenseSchema).default([]),
+	frameworks: z.array(FrameworkSchema).default([]),
+	people: z.array(PersonSchema).default([]),
 	preferred_ide: z.optional(IdeSchema).nullable(),
-	repository_url: z.string().optional().nullable(),
+	links: z.array(LinkSchema).default([]),
 	created: z.string(),
 	updated: z.string()
 });
@@ -39,8 +46,11 @@ export const MetadataSchema = MetadataDtoSchema.transform((data) => ({
 	categories: data.categories,
 	languages: data.languages,
 	buildSystems: data.build_systems,
+	licenses: data.licenses,
+	frameworks: data.frameworks,
+	people: data.people,
 	preferredIde: data.preferred_ide ?? undefined,
-	repositoryUrl: data.repository_url ?? undefined,
+	links: data.links,
 	created: new Date(data.created),
 	updated: new Date(data.updated)
 }));
@@ -62,8 +72,11 @@ export const MetadataFormSchema = z.object({
 	categories: z.array(CategorySchema),
 	languages: z.array(LanguageSchema),
 	buildSystems: z.array(BuildSystemSchema).default([]),
+	licenses: z.array(LicenseSchema).default([]),
+	frameworks: z.array(FrameworkSchema).default([]),
+	people: z.array(PersonSchema).default([]),
 	preferredIde: z.optional(IdeSchema),
-	repositoryUrl: z.string().optional()
+	links: z.array(LinkSchema).default([])
 });
 
 export const MetadataDcoSchema = MetadataFormSchema.transform((data) => ({
@@ -73,8 +86,11 @@ export const MetadataDcoSchema = MetadataFormSchema.transform((data) => ({
 	categories: data.categories,
 	languages: data.languages,
 	build_systems: data.buildSystems,
+	licenses: data.licenses,
+	frameworks: data.frameworks,
+	people: data.people,
 	preferred_ide: data.preferredIde,
-	repository_url: data.repositoryUrl
+	links: data.links
 }));
 
 export function parseMetadata(data: unknown): Metadata {
@@ -93,8 +109,11 @@ export function parseFormData(metadata: Metadata): FormMetadata {
 		categories: metadata.categories,
 		languages: metadata.languages,
 		buildSystems: metadata.buildSystems,
+		licenses: metadata.licenses,
+		frameworks: metadata.frameworks,
+		people: metadata.people,
 		preferredIde: metadata.preferredIde,
-		repositoryUrl: metadata.repositoryUrl
+		links: metadata.links
 	};
 }
 
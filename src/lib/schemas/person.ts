@@ -0,0 +1,7 @@
+import { z } from 'zod';
+
+export const PersonSchema = z.object({
+	name: z.string().nonempty(),
+	email: z.string().optional().nullable(),
+	role: z.string().optional().nullable()
+});
@@ -1,13 +1,72 @@
 import type { Statistic } from '$lib/types';
 import { z } from 'zod';
 
+const CoOccurrenceSchema = z.object({
+	first: z.string(),
+	second: z.string(),
+	count: z.number()
+});
+
+export const BucketSchema = z.enum(['day', 'week', 'month', 'year']);
+
+const PeriodCountSchema = z.object({
+	period: z.string(),
+	count: z.number()
+});
+
+const TimelineDtoSchema = z.object({
+	bucket: BucketSchema,
+	created: z.array(PeriodCountSchema),
+	updated: z.array(PeriodCountSchema),
+	language_adoption: z.array(
+		z.object({
+			period: z.string(),
+			language: z.string(),
+			count: z.number()
+		})
+	),
+	stale_projects: z.array(
+		z.object({
+			id: z.string().uuid(),
+			title: z.string(),
+			updated: z.string(),
+			days_since_update: z.number()
+		})
+	)
+});
+
+const VersionCountSchema = z.object({
+	name: z.string(),
+	version: z.string().nullable(),
+	count: z.number()
+});
+
+const OutdatedProjectSchema = z.object({
+	id: z.string().uuid(),
+	title: z.string(),
+	property: z.enum(['language', 'build_system']),
+	name: z.string(),
+	version: z.string(),
+	minimum: z.string()
+});
+
 export const StatisticDtoSchema = z.object({
 	projects_by_language: z.record(z.number()),
 	projects_by_ide: z.record(z.number()),
 	projects_by_category: z.record(z.number()),
 	projects_by_build_system: z.record(z.number()),
+	projects_by_license: z.record(z.number()),
+	projects_by_framework: z.record(z.number()),
+	projects_by_maintainer: z.record(z.number()),
 	number_of_projects: z.number(),
-	number_of_languages: z.number()
+	number_of_languages: z.number(),
+	timeline: TimelineDtoSchema,
+	language_versions: z.array(VersionCountSchema),
+	build_system_versions: z.array(VersionCountSchema),
+	below_minimum_version: z.array(OutdatedProjectSchema),
+	languages_used_together: z.array(CoOccurrenceSchema),
+	ide_per_language: z.array(CoOccurrenceSchema),
+	build_systems_per_category: z.array(CoOccurrenceSchema)
 });
 
 export const StatisticSchema = StatisticDtoSchema.transform((data) => ({
@@ -29,8 +88,40 @@ export const StatisticSchema = StatisticDtoSchema.transform((data) => ({
 			projects
 		})
 	),
+	projectsByLicense: Object.entries(data.projects_by_license).map(([license, projects]) => ({
+		license,
+		projects
+	})),
+	projectsByFramework: Object.entries(data.projects_by_framework).map(([framework, projects]) => ({
+		framework,
+		projects
+	})),
+	projectsByMaintainer: Object.entries(data.projects_by_maintainer).map(
+		([maintainer, projects]) => ({
+			maintainer,
+			projects
+		})
+	),
 	numberOfProjects: data.number_of_projects,
-	numberOfLanguages: data.number_of_languages
+	numberOfLanguages: data.number_of_languages,
+	timeline: {
+		bucket: data.timeline.bucket,
+		created: data.timeline.created,
+		updated: data.timeline.updated,
+		languageAdoption: data.timeline.language_adoption,
+		staleProjects: data.timeline.stale_projects.map((project) => ({
+			id: project.id,
+			title: project.title,
+			updated: new Date(project.updated),
+			daysSinceUpdate: project.days_since_update
+		}))
+	},
+	languageVersions: data.language_versions,
+	buildSystemVersions: data.build_system_versions,
+	belowMinimumVersion: data.below_minimum_version,
+	languagesUsedTogether: data.languages_used_together,
+	idePerLanguage: data.ide_per_language,
+	buildSystemsPerCategory: data.build_systems_per_category
 }));
 
 export function parseStatistics(data: unknown): Statistic {
@@ -10,7 +10,12 @@ import type { PagedMetadataPreviewSchema } from './schemas/pagedData.ts';
 import type { LanguageSchema } from './schemas/language';
 import type { CategorySchema } from './schemas/category';
 import type { BuildSystemSchema } from './schemas/buildSystem';
-import type { StatisticSchema } from './schemas/statistics';
+import type { LicenseSchema } from './schemas/license';
+import type { FrameworkSchema } from './schemas/framework';
+import type { PersonSchema } from './schemas/person';
+import type { LinkKindSchema, LinkSchema } from './schemas/link';
+import type { BucketSchema, StatisticSchema } from './schemas/statistics';
+import type { ParseErrorSchema } from './schemas/error';
 
 export interface Filter {
 	query: string | null;
@@ -18,6 +23,11 @@ export interface Filter {
 	language: string | null;
 }
 
+export interface TimelineOptions {
+	bucket: Bucket;
+	stale_after_days: number;
+}
+
 export type Metadata = z.infer<typeof MetadataSchema>;
 
 export type MetadataPreview = z.infer<typeof MetadataPreviewSchema>;
@@ -34,6 +44,20 @@ export type Category = z.infer<typeof CategorySchema>;
 
 export type BuildSystem = z.infer<typeof BuildSystemSchema>;
 
+export type License = z.infer<typeof LicenseSchema>;
+
+export type Framework = z.infer<typeof FrameworkSchema>;
+
+export type Person = z.infer<typeof PersonSchema>;
+
+export type LinkKind = z.infer<typeof LinkKindSchema>;
+
+export type Link = z.infer<typeof LinkSchema>;
+
 export type Uuid = z.infer<typeof UuidSchema>;
 
+export type Bucket = z.infer<typeof BucketSchema>;
+
 export type Statistic = z.infer<typeof StatisticSchema>;
+
+export type ParseError = z.infer<typeof ParseErrorSchema>;